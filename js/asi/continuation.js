@@ -0,0 +1,6 @@
+const a = 1
+let b = a
+  + 2
+console.log(a, b)
+const message = `b is ${b}`
+console.log(message)
@@ -0,0 +1,28 @@
+function f() {
+    return
+    42
+}
+console.log(f())
+let a = 1
+let b = 1
+a
+++b
+console.log(a, b)
+outer: for (let i = 0; i < 2; i++) {
+    for (let j = 0; j < 3; j++) {
+        if (j == 1) {
+            continue
+            outer
+        }
+        console.log(i, j)
+    }
+}
+done: for (let i = 0; i < 2; i++) {
+    for (let j = 0; j < 3; j++) {
+        if (j == 1) {
+            break
+            done
+        }
+        console.log(i, j)
+    }
+}
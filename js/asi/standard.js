@@ -0,0 +1,17 @@
+// StandardJS style, no semicolons
+const a = 1
+let b = a
+  + 2
+const list = [
+  a,
+  b
+]
+console.log(a, b, list)
+
+function add (x, y) {
+  return x + y
+}
+console.log(add(a, b))
+
+if (b > a) console.log('bigger')
+else console.log('smaller')
@@ -0,0 +1,13 @@
+// the same program as standard.js, with semicolons
+const a = 1;
+let b = a + 2;
+const list = [a, b];
+console.log(a, b, list);
+
+function add (x, y) {
+  return x + y;
+}
+console.log(add(a, b));
+
+if (b > a) console.log('bigger');
+else console.log('smaller');
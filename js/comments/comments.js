@@ -0,0 +1,9 @@
+#!/usr/bin/env node
+// say hello
+let a = 1; // trailing
+/* block */ let b = 4 / 2;
+/**
+ * doc
+ */
+console.log(a /* inline */ + b);
+// end
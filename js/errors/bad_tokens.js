@@ -0,0 +1,7 @@
+let s = 'unterminated
+let n = 08
+let ok = 1
+let x = a # b
+let e = "\xZZ" + 1
+let b = 1_000_ + `\u{zz} ${ok}`
+console.log(ok) /* never closed
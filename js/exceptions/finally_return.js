@@ -0,0 +1,18 @@
+function f() {
+    try {
+        return 1
+    } finally {
+        return 2
+    }
+}
+console.log(f())
+function g() {
+    try {
+        throw "lost"
+    } catch (e) {
+        return "caught"
+    } finally {
+        return "finally"
+    }
+}
+console.log(g())
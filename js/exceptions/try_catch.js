@@ -0,0 +1,51 @@
+function fail(message) {
+    throw message
+}
+function outer() {
+    fail("deep")
+    console.log("never")
+}
+try {
+    outer()
+    console.log("never")
+} catch (e) {
+    console.log("caught", e)
+}
+try {
+    throw 1
+} catch {
+    console.log("no binding")
+} finally {
+    console.log("finally")
+}
+try {
+    try {
+        throw "inner"
+    } finally {
+        console.log("inner finally")
+    }
+} catch (e) {
+    console.log("outer", e)
+}
+let i = 0
+while (i < 2) {
+    i = i + 1
+    try {
+        continue
+    } finally {
+        console.log("cleanup", i)
+    }
+}
+while (true) {
+    try {
+        throw "lost"
+    } finally {
+        break
+    }
+}
+try {
+    missing()
+} catch (e) {
+    console.log(e.name, e.message)
+}
+console.log("done")
@@ -0,0 +1,3 @@
+console.log("before")
+throw "oops"
+console.log("after")
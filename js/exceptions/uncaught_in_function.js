@@ -0,0 +1,8 @@
+console.log("before")
+function f(a) {
+  if (a) {
+    throw "bad"
+  }
+  return 1
+}
+f(1)
@@ -0,0 +1,20 @@
+console.log(1 == 1, 1 == "1", 0 == false, null == undefined, null == 0, "a" == "a")
+console.log(1 != 2, 1 != "1", null != undefined, "a" != "b")
+console.log(1 === 1, 1 === "1", "a" === "a", null === undefined)
+console.log(1 !== 2, 1 !== 1, "1" !== 1)
+console.log(1 < 2, 2 < 1, "a" < "b", "10" < "9", 10 < 9)
+console.log(2 > 1, 1 > 2, "b" > "a", "1" > 0)
+console.log(3 <= 3, 4 <= 3, 3 >= 4, 3 >= 3)
+console.log(0 / 0 <= 1, 0 / 0 >= 1, undefined == 0)
+let o = {a: 1}
+let arr = [1, 2]
+console.log("a" in o, "b" in o, 1 in arr, 2 in arr, "length" in arr)
+function f() { let unused = 1 }
+console.log(o instanceof f, 1 instanceof f)
+try { 1 in 2 } catch (e) { console.log(e.name) }
+try { o instanceof o } catch (e) { console.log(e.name) }
+let a = 1
+if (a != 1) { console.log("wrong") } else { console.log("right") }
+let i = 0
+while (i <= 2) { i++ }
+console.log(i)
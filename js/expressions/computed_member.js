@@ -0,0 +1,21 @@
+let a = [1, 2, 3]
+let i = 1
+console.log(a[i], a[0] + a[2], a.length)
+a[i] = 20
+a[3] = 4
+console.log(a[1], a[3], a.length)
+let o = {x: 1, y: 2}
+let k = "y"
+console.log(o[k], o["x"], o["nope"])
+o[k] = 5
+console.log(o.y)
+o[k] += 10
+console.log(o.y)
+console.log(a[i]++, a[1], --a[0])
+console.log(a[0.5 + 0.5], a["2"])
+let method = "log"
+console[method]("hi", "bob")
+o["w"] ??= 8
+o[k] &&= 1
+o[k] ||= 99
+console.log(o.w, o.y, o[k] ||= 50)
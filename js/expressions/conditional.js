@@ -0,0 +1,15 @@
+let a = 1
+let b = a < 2 ? "small" : "big"
+console.log(b)
+console.log(a < 0 ? "negative" : 2 < a ? "big" : "one")
+function yes() {
+    console.log("yes")
+}
+function no() {
+    console.log("no")
+}
+a < 2 ? yes() : no()
+false ? yes() : no()
+let c = 0 ? 1 : a ? 2 : 3
+console.log(c)
+console.log("" ? "truthy" : "falsy")
@@ -0,0 +1,27 @@
+let o = null
+console.log(o && o.x)
+let p = {x: 3}
+console.log(p && p.x)
+console.log(0 || "fallback", "first" || "second")
+console.log(null ?? "default", 0 ?? "default", "" ?? "default")
+function side() {
+    console.log("side")
+}
+false && side()
+true || side()
+1 ?? side()
+true && side()
+let a = 0
+a ||= 5
+console.log(a)
+a &&= 7
+console.log(a)
+let n = null
+n ??= "set"
+console.log(n)
+n ??= "again"
+console.log(n)
+p.x &&= 10
+console.log(p.x)
+p.x ||= 20
+console.log(p.x)
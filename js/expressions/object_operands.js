@@ -0,0 +1,7 @@
+let a = true ? {a: 1} : null
+console.log(a.a)
+let b = null ?? {b: 2}
+console.log(b.b)
+console.log(typeof {})
+console.log(({a: 3}).a)
+console.log(({n: {m: 4}}).n.m)
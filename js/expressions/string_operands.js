@@ -0,0 +1,10 @@
+let s = "a"
+s += "b"
+console.log(s)
+let o = {s: "x"}
+o.s += "y"
+console.log(o.s)
+console.log("3" * "4", "6" - 2, "8" / "2", 1 + "2", "n" + 1, 1 + 2)
+let n = 5
+n += "!"
+console.log(n)
@@ -0,0 +1,6 @@
+let f = function() { return 1 }
+console.log(typeof f, typeof function() {})
+let o = {g: function() {}}
+console.log(typeof o.g, typeof {}, !{}, !f)
+const arrow = () => { return 1 }
+console.log(typeof arrow)
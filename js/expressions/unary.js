@@ -0,0 +1,16 @@
+let a = 5
+console.log(-a, +"3", +true, -(-a), +"0x10")
+console.log(!a, !0, !!"s")
+console.log(~5, ~-1, ~"7")
+console.log(typeof a, typeof "s", typeof true, typeof missing)
+function f() {
+    let z = 1
+}
+console.log(typeof f, typeof -"x")
+console.log(void a)
+let o = {x: 1, y: 2}
+let log = typeof console.log
+console.log(typeof o, typeof null, log)
+console.log(delete o.x)
+console.log(o.x, o.y)
+console.log(delete a)
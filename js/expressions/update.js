@@ -0,0 +1,41 @@
+let x = 5
+console.log(x++, x)
+console.log(++x, x)
+console.log(x--, x)
+console.log(--x, x)
+let s = "3"
+console.log(s++, s)
+let o = {n: 1}
+console.log(o.n++, o.n)
+console.log(++o.n, o.n)
+console.log(o.n--, --o.n)
+let a = 10
+a += 5
+console.log(a)
+a -= 3
+console.log(a)
+a *= 2
+console.log(a)
+a /= 4
+console.log(a)
+a %= 4
+console.log(a)
+a **= 3
+console.log(a)
+let b = 6
+b <<= 2
+console.log(b)
+b >>= 1
+console.log(b)
+b |= 1
+console.log(b)
+b &= 5
+console.log(b)
+b ^= 3
+console.log(b)
+let c = -8
+c >>>= 28
+console.log(c)
+console.log(o.n += 4, o.n)
+o.n **= 2
+console.log(o.n)
@@ -0,0 +1,19 @@
+function one() { return 1 }
+console.log(one())
+function fromTry() {
+    try {
+        return "try"
+    } finally {
+        console.log("finally")
+    }
+}
+console.log(fromTry())
+function sum(n) { if (n < 1) { return 0 } return n + sum(n - 1) }
+console.log(sum(4))
+function early() { for (let i = 0; i < 5; i++) { if (i == 2) return i } }
+console.log(early())
+function nothing() { let unused = 1 }
+console.log(nothing())
+let count = 1
+count++
+console.log(count)
@@ -0,0 +1,18 @@
+function pick(n) { if (n == 2) { return "two" } return "other" }
+console.log(pick(2), pick(3))
+function find() { for (let i = 0; i < 5; i++) { if (i == 2) return i } return -1 }
+console.log(find())
+function name(n) {
+    switch (n) {
+        case 1: return "one"
+        case 2: return "two"
+        default: return "many"
+    }
+}
+console.log(name(1), name(2), name(5))
+function count() { let i = 0; while (true) { i++; if (i == 3) { return i } } }
+console.log(count())
+function first() { return 1; console.log("after") }
+console.log(first())
+function labelled() { outer: for (let i = 0; i < 3; i++) { do { return i } while (false) } }
+console.log(labelled())
@@ -0,0 +1,13 @@
+for (let i = 0; i < 3; i++) { if (1 < i) break; console.log(i) }
+for (let j = 0; j < 3; j++) {
+    if (j == 1) continue
+    console.log(j)
+}
+let a = 2
+if (a == 1)
+    console.log("one")
+else if (a == 2)
+    console.log("two")
+else
+    console.log("many")
+console.log("done")
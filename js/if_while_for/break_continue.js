@@ -0,0 +1,42 @@
+let i = 0
+while (true) {
+    i = i + 1
+    if (i < 3) {
+        continue
+    }
+    console.log(i)
+    break
+}
+for (let j = 0; j < 4; j++) {
+    if (j < 2) {
+        continue
+    }
+    console.log(j)
+}
+outer:
+for (let a = 0; a < 3; a++) {
+    for (let b = 0; b < 3; b++) {
+        if (a < b) {
+            continue outer
+        }
+        if (1 < a) {
+            break outer
+        }
+        console.log(a, b)
+    }
+}
+let n = 0
+do {
+    n = n + 1
+    if (n < 2) {
+        continue
+    }
+    break
+} while (n < 5)
+console.log(n)
+block: {
+    console.log("in")
+    break block
+    console.log("never")
+}
+console.log("out")
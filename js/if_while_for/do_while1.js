@@ -0,0 +1,8 @@
+let n = 10
+do {
+    console.log(n)
+    n = n + 1
+} while (n < 3)
+let j = 0
+do j = j + 1; while (j < 4)
+console.log(j)
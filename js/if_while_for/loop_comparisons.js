@@ -0,0 +1,22 @@
+let i = 3
+do { i-- } while (i > 0)
+console.log(i)
+let a = 0
+while (a != 3) { a++ }
+console.log(a)
+let b = 0
+while (b <= 2) { b++ }
+console.log(b)
+let c = 5
+while (c >= 3) { c-- }
+console.log(c)
+let d = 0
+while (d === 0) { d = 1 }
+console.log(d)
+let e = 0
+do { e++ } while (e == 1)
+console.log(e)
+let f = 0
+while (f !== 2) { f++ }
+console.log(f)
+for (let g = 0; g < 2; g++) { console.log(g) }
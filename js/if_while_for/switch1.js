@@ -0,0 +1,42 @@
+let x = 2
+switch (x) {
+    case 1:
+        console.log("one")
+    case 2:
+        console.log("two")
+    case 3:
+        console.log("three")
+        break
+    case 4:
+        console.log("four")
+}
+switch ("b") {
+    default:
+        console.log("default")
+    case "a":
+        console.log("a")
+        break
+    case "b":
+        console.log("b")
+}
+switch (7) {
+    case 1:
+        console.log(1)
+        break
+    default:
+        console.log("none")
+    case 2:
+        console.log(2)
+}
+switch ("1") {
+    case 1:
+        console.log("loose")
+}
+for (let i = 0; i < 3; i++) {
+    switch (i) {
+        case 1:
+            continue
+        default:
+            console.log(i)
+    }
+}
@@ -0,0 +1,11 @@
+let i = 0
+while (i < 3) {
+    console.log(i)
+    i = i + 1
+}
+let count = 2
+while (count) count = count - 1
+console.log(count)
+while (false) {
+    console.log("never")
+}
@@ -0,0 +1,10 @@
+let hex = 0x1F;
+let octal = 0o17;
+let binary = 0b1010;
+let small = 1e-7;
+let half = .5;
+let five = 5.;
+let million = 1_000_000;
+let big = 123n;
+let big_hex = 0xFFFFFFFFFFFFFFFFFFn;
+let exponent = 2.5E+3;
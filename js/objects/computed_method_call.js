@@ -0,0 +1,4 @@
+let o = {name: "o", g: function(a) { console.log(this.name, a); return a + 1 }}
+let k = "g"
+console.log(o[k](1))
+o.g(2)
@@ -0,0 +1,3 @@
+let o = {b: 1, 2: 1, a: 1, 1: 1}
+o.c = 1
+console.log(Object.keys(o))
@@ -0,0 +1,7 @@
+function f(x) { return x.a }
+console.log(f({a: 4}))
+let o = {}
+o.k = {c: 3}
+console.log(o.k.c)
+let p = {n: {m: 5}}
+console.log(p.n.m)
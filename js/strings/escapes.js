@@ -0,0 +1,6 @@
+let a = 'single';
+let b = "tab\tnew\nline";
+let c = 'it\'s \\ "quoted"';
+let d = "\x41\u0042\u{43}\u{1F600}\101";
+let e = "line \
+continues";
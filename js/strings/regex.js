@@ -0,0 +1,7 @@
+let re = /ab+c/gi;
+console.log(re);
+console.log(re.source);
+let half = 10 / 2 / 5;
+console.log(half);
+let slashes = /[/]\//m;
+console.log(slashes.flags);
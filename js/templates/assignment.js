@@ -0,0 +1,3 @@
+let n = 4;
+let s = `n is ${n * 2}`;
+console.log(s);
@@ -0,0 +1,3 @@
+let a = 2;
+let b = 3;
+console.log(`${a} + ${b} = ${a + b}`);
@@ -0,0 +1,3 @@
+let first = `line one
+line two`;
+console.log(first);
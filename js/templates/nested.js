@@ -0,0 +1,2 @@
+let inner = "in";
+console.log(`out ${`and ${inner}`} done`);
@@ -0,0 +1,2 @@
+let name = "world";
+console.log(`hello ${name}!`);
@@ -0,0 +1,4 @@
+let a = 1
+let b = 2
+a = b = 5
+console.log(a, b)
@@ -0,0 +1,6 @@
+let café = 1;
+let $el = 2;
+let _private = 3;
+let π = 4;
+console.log(cafe\u0301 + \u0024el + _private + \u{3c0});
+console.log(café);
@@ -1,4 +1,4 @@
-use crate::parser::symbols::JSItem;
+use crate::parser::symbols::{JSItem, Operator, number_binop, compare_values};
 
 
 pub(crate) fn bin_add(a: JSItem, b: JSItem) -> Result<JSItem, ()> {
@@ -76,16 +76,51 @@ pub(crate) fn bin_div(a: JSItem, b: JSItem) -> Result<JSItem, ()> {
     }
 }
 
-pub(crate) fn bin_less(a: JSItem, b: JSItem) -> Result<JSItem, ()> {
-    match a {
-        JSItem::Number { value: v1 } => {
-            match b {
-                JSItem::Number { value: v2 } => {
-                    Ok(JSItem::Bool { value: v1 < v2 })
-                }
-                _ => Err(())
+/// `a op b` with both operands already evaluated, `Err` is the message of the TypeError
+/// `in` and `instanceof` throw.
+#[allow(dead_code)]
+pub(crate) fn bin_op(op: &Operator, a: JSItem, b: JSItem) -> Result<JSItem, String> {
+    let number = |a: &JSItem, b: &JSItem| JSItem::Number { value: number_binop(op, a.to_number(), b.to_number()) };
+    let out = match op {
+        Operator::Add => bin_add(a.clone(), b.clone()).unwrap_or_else(|_| number(&a, &b)),
+        Operator::Sub => bin_sub(a.clone(), b.clone()).unwrap_or_else(|_| number(&a, &b)),
+        Operator::Mult => bin_mul(a.clone(), b.clone()).unwrap_or_else(|_| number(&a, &b)),
+        Operator::Div => bin_div(a.clone(), b.clone()).unwrap_or_else(|_| number(&a, &b)),
+        Operator::Mod | Operator::Exp | Operator::LShift | Operator::RShift | Operator::URShift
+        | Operator::BitAnd | Operator::BitOr | Operator::BitXor | Operator::FloorDiv => number(&a, &b),
+        Operator::Less | Operator::Greater | Operator::LessEqual | Operator::GreaterEqual => {
+            JSItem::Bool { value: compare_values(op, &a, &b) }
+        }
+        Operator::EqEq => JSItem::Bool { value: a.loosely_equals(&b) },
+        Operator::NotEq => JSItem::Bool { value: !a.loosely_equals(&b) },
+        Operator::EqEqEq => JSItem::Bool { value: a.strictly_equals(&b) },
+        Operator::NotEqEq => JSItem::Bool { value: !a.strictly_equals(&b) },
+        Operator::In => {
+            let key = a.to_property_key();
+            match b.has_property(&key) {
+                Some(found) => JSItem::Bool { value: found },
+                None => return Err(format!("Cannot use 'in' operator to search for '{}' in {}", key, b.to_js_string()))
             }
         }
-        _ => Err(())
+        Operator::InstanceOf => match a.instance_of(&b) {
+            Some(value) => JSItem::Bool { value },
+            None => return Err("Right-hand side of 'instanceof' is not callable".to_string())
+        },
+        // both sides are evaluated here, so this is the value the short circuit would pick
+        Operator::And | Operator::Or | Operator::Nullish => if short_circuits(op, &a) { a } else { b },
+        Operator::None => JSItem::Undefined
+    };
+    Ok(out)
+}
+
+/// Whether `&&`, `||` or `??` is decided by its left operand alone, the right one is then
+/// never evaluated.
+#[allow(dead_code)]
+pub(crate) fn short_circuits(op: &Operator, left: &JSItem) -> bool {
+    match op {
+        Operator::And => !left.is_truthy(),
+        Operator::Or => left.is_truthy(),
+        Operator::Nullish => !left.is_nullish(),
+        _ => false
     }
 }
@@ -0,0 +1,71 @@
+use crate::parser::property_map::PropertyMap;
+use std::fmt;
+use crate::lexer::span::Span;
+use crate::parser::symbols::{JSItem, Expression};
+
+/// How a statement finished. `Break` and `Continue` are passed up through the statements
+/// around them until the loop or label they are aimed at takes them, `Return` until the
+/// function body it is in. A thrown value travels separately as the `Err` of a
+/// `Result<Completion, Exception>`.
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Completion {
+    Normal(JSItem),
+    Break(Option<String>),
+    Continue(Option<String>),
+    Return(JSItem)
+}
+
+impl Completion {
+    /// What a loop known by `labels` does once its body finishes: `None` goes round again,
+    /// otherwise the loop ends with the completion returned.
+    #[allow(dead_code)]
+    pub(crate) fn after_iteration(self, labels: &[String]) -> Option<Completion> {
+        match self {
+            Completion::Normal(_) | Completion::Continue(None) => None,
+            Completion::Continue(Some(label)) if labels.contains(&label) => None,
+            Completion::Break(None) => Some(Completion::Normal(JSItem::Undefined)),
+            Completion::Break(Some(label)) if labels.contains(&label) => Some(Completion::Normal(JSItem::Undefined)),
+            completion => Some(completion)
+        }
+    }
+}
+
+/// A thrown value on its way up to the nearest `catch`, with where it was thrown.
+#[allow(dead_code)]
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct Exception {
+    pub(crate) value: JSItem,
+    pub(crate) span: Span
+}
+
+#[allow(dead_code)]
+impl Exception {
+    /// An error raised by the interpreter itself, an object with the `name` and `message` of
+    /// the built in error it stands for.
+    pub(crate) fn error(name: &str, message: String, span: Span) -> Exception {
+        let text = |value: String| JSItem::Ex {
+            expression: Box::new(Expression::Literal { value, span: Span::default() }),
+            span: Span::default()
+        };
+        let mut properties = PropertyMap::new();
+        properties.insert("name".to_string(), text(name.to_string()));
+        properties.insert("message".to_string(), text(message));
+        Exception { value: JSItem::Object { mutable: true, properties }, span }
+    }
+}
+
+/// The uncaught exception report, `Uncaught TypeError: f is not a function` and where it was
+/// thrown.
+impl fmt::Display for Exception {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match &self.value {
+            JSItem::Object { properties, .. } => match (properties.get("name"), properties.get("message")) {
+                (Some(name), Some(message)) => format!("{}: {}", name.to_js_string(), message.to_js_string()),
+                _ => self.value.to_js_string()
+            },
+            value => value.to_js_string()
+        };
+        write!(f, "Uncaught {}\n    at {}", value, self.span)
+    }
+}
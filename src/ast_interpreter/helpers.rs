@@ -1,3 +1,4 @@
+use crate::lexer::span::Span;
 use crate::parser::symbols::{JSItem, Expression, AssignOp};
 use crate::ast_interpreter::interpreter::Interpreter;
 
@@ -10,52 +11,77 @@ pub(crate) fn o_to_v(js_out: JSItem, assign_op: AssignOp) -> JSItem {
         JSItem::Array { items, properties } => {
             JSItem::Variable {
                 mutable,
-                value: Expression::ArrayExpression {items, properties}
+                value: Expression::ArrayExpression {items, properties, span: Span::default()}
             }
         }
         JSItem::Object { mutable, properties } => {
             JSItem::Variable {
                 mutable,
-                value: Expression::Object { mutable, properties }
+                value: Expression::Object { mutable, properties, span: Span::default() }
             }
         }
         JSItem::String { value } => {
             JSItem::Variable {
                 mutable,
-                value: Expression::String { value },
+                value: Expression::String { value, span: Span::default() },
             }
         }
         JSItem::Number { value } => {
             JSItem::Variable {
                 mutable,
-                value: Expression::Number { value },
+                value: Expression::Number { value, span: Span::default() },
             }
         }
         JSItem::Null => {
             JSItem::Variable {
                 mutable,
-                value: Expression::Null,
+                value: Expression::Null { span: Span::default() },
+            }
+        }
+        JSItem::RegExp { pattern, flags } => {
+            JSItem::Variable {
+                mutable,
+                value: Expression::RegExp { pattern, flags, span: Span::default() },
             }
         }
         JSItem::Bool { value } => {
             if value {
                 JSItem::Variable {
                     mutable,
-                    value: Expression::True,
+                    value: Expression::True { span: Span::default() },
                 }
             } else {
                 JSItem::Variable {
                     mutable,
-                    value: Expression::False,
+                    value: Expression::False { span: Span::default() },
                 }
             }
         }
+        function @ JSItem::Function { .. } => function,
         _ => {
             JSItem::Null
         }
     };
 }
 
+/// The value a variable made by `o_to_v` holds, anything else as it is.
+#[allow(dead_code)]
+pub(crate) fn v_to_o(item: JSItem) -> JSItem {
+    match item {
+        JSItem::Variable { value, .. } => match value {
+            Expression::ArrayExpression { items, properties, .. } => JSItem::Array { items, properties },
+            Expression::Object { mutable, properties, .. } => JSItem::Object { mutable, properties },
+            Expression::String { value, .. } => JSItem::String { value },
+            Expression::Number { value, .. } => JSItem::Number { value },
+            Expression::RegExp { pattern, flags, .. } => JSItem::RegExp { pattern, flags },
+            Expression::True { .. } => JSItem::Bool { value: true },
+            Expression::False { .. } => JSItem::Bool { value: false },
+            _ => JSItem::Null
+        },
+        item => item
+    }
+}
+
 pub(crate) fn find_object_scope<'a>(interpreter: &'a Interpreter, name: &String) -> Result<(usize, &'a Interpreter), ()> {
     for i in (0..=interpreter.scope).rev() {
         let objects = interpreter.scopes.get(i).unwrap();
@@ -79,7 +105,7 @@ pub(crate) fn find_o_r(interpreter: &Interpreter, mut scope_num: usize, mut path
         match current {
             JSItem::Variable { mutable:_, value } => {
                 match value {
-                    Expression::Object { mutable:_, properties } => {
+                    Expression::Object { mutable:_, properties, .. } => {
                         let p_item = properties.get(&key);
                         if let Some(item) = p_item {
                             current = item;
@@ -87,7 +113,7 @@ pub(crate) fn find_o_r(interpreter: &Interpreter, mut scope_num: usize, mut path
                             return Err(())
                         }
                     }
-                    Expression::ArrayExpression { items:_, properties } => {
+                    Expression::ArrayExpression { items:_, properties, .. } => {
                         let item = properties.get(&key);
                         match item {
                             Some(i) => {
@@ -172,25 +198,33 @@ pub(crate) fn find_object_from_reference(int: &Interpreter, mut path: Vec<String
     }
 }
 
+/// The path to the property a member expression names. A computed key only counts once it has
+/// been evaluated into a literal.
 pub(crate) fn find_reference_from_member_expression(exp: Expression) -> Vec<String> {
     let mut full = vec![];
-    if let Expression::MemberExpression { object, property } = exp {
-        match *object {
-            Expression::Identifier {name} => {
-                full.push(name)
-            }
-            Expression::MemberExpression {object, property} => {
-                let mut n = find_reference_from_member_expression(Expression::MemberExpression {object, property});
-                full.append(&mut n);
-            }
-            _ => {}
+    let (object, property) = match exp {
+        Expression::MemberExpression { object, property, .. } => match *property {
+            Expression::Identifier { name, .. } => (object, Some(name)),
+            _ => (object, None)
+        },
+        Expression::ComputedMemberExpression { object, property, .. } => match *property {
+            Expression::Literal { value, .. } => (object, Some(value)),
+            _ => (object, None)
+        },
+        _ => return full
+    };
+    match *object {
+        Expression::Identifier {name, ..} => {
+            full.push(name)
         }
-        match *property {
-            Expression::Identifier {name} => {
-                full.push(name)
-            }
-            _ => {}
+        object @ Expression::MemberExpression { .. } | object @ Expression::ComputedMemberExpression { .. } => {
+            let mut n = find_reference_from_member_expression(object);
+            full.append(&mut n);
         }
+        _ => {}
+    }
+    if let Some(name) = property {
+        full.push(name)
     }
     return full;
 }
@@ -1,22 +1,31 @@
-use crate::parser::symbols::{JSItem, Operator, Statement, StdFun, AssignOp};
+use crate::parser::symbols::{JSItem, Operator, Statement, StdFun, AssignOp, regexp_property, SwitchCase, CatchClause, UnaryOperator, to_int32, number_binop, array_property};
 use crate::parser::symbols::Expression;
-use crate::ast_interpreter::bin_op::{bin_add, bin_mul, bin_sub, bin_div, bin_less};
+use crate::ast_interpreter::bin_op::{bin_op, short_circuits};
 use std::collections::HashMap;
+use crate::parser::property_map::PropertyMap;
 use crate::lexer::js_token::Tok;
+use crate::lexer::span::Span;
 use crate::ast_interpreter::std::{create_std_objects};
 use crate::ast_interpreter::std::console::std_log;
 use crate::ast_interpreter::std::function::std_fun_apply;
 use crate::ast_interpreter::helpers::{o_to_v, find_object_from_reference, find_reference_from_member_expression};
-use crate::ast_interpreter::scope::insert::{set_object};
+use crate::ast_interpreter::scope::insert::{set_object, delete_object};
 use crate::ast_interpreter::std::array::std_array_push;
 use crate::ast_interpreter::std::inherit::inherit;
+use crate::ast_interpreter::completion::{Completion, Exception};
 
 pub(crate) struct Interpreter {
     pub(crate) scopes: Vec<HashMap<String, JSItem>>,
     pub(crate) scope: usize,
     pub(crate) function_scope: Vec<usize>,
+    // the statement being run, engine errors are reported from here
+    location: Span,
+    // set once an exception goes uncaught, nothing more is run
+    halted: bool,
     #[cfg(test)]
-    pub(crate) captured_output: Vec<Vec<JSItem>>
+    pub(crate) captured_output: Vec<Vec<JSItem>>,
+    #[cfg(test)]
+    pub(crate) captured_errors: Vec<String>
 }
 
 
@@ -27,8 +36,12 @@ impl Interpreter {
             scopes: vec![HashMap::new()],
             scope: 0,
             function_scope: vec![],
+            location: Span::default(),
+            halted: false,
+            #[cfg(test)]
+            captured_output: vec![],
             #[cfg(test)]
-            captured_output: vec![]
+            captured_errors: vec![]
         };
         create_std_objects(int)
     }
@@ -61,7 +74,16 @@ impl Interpreter {
         }
     }
 
-    fn call_identifier(&mut self, name: String, arguments: Vec<JSItem>) -> Result<JSItem, ()>{
+    /// An exception for an error the engine runs into, thrown from the current statement.
+    fn error(&self, name: &str, message: String) -> Exception {
+        Exception::error(name, message, self.location.clone())
+    }
+
+    fn not_a_function(&self, name: &str) -> Exception {
+        self.error("TypeError", format!("{} is not a function", name))
+    }
+
+    fn call_identifier(&mut self, name: String, arguments: Vec<JSItem>) -> Result<JSItem, Exception> {
         let func = self.get_object(&name);
         match func {
             Ok(f) => {
@@ -75,28 +97,28 @@ impl Interpreter {
                             .insert(name, JSItem::Function {
                                 mutable, params, properties, body
                             });
-                        //create a new scope
-                        self.create_new_scope();
-                        let args = self.make_params(params_clone, arguments);
-                        self.add_params_to_scope(args.0, args.1);
-
-                        let mut out = JSItem::Undefined;
-                        for item in body_clone {
-                            out = self.interpret(item);
-                        }
-
-                        return Ok(out);
+                        return self.call_function(None, params_clone, arguments, body_clone);
                     }
-                    _ => {
-                        Err(())
+                    object => {
+                        self.replace_object(f.1, object, name.clone());
+                        Err(self.not_a_function(&name))
                     }
                 }
             }
-            Err(_) => Err(())
+            Err(_) => Err(self.error("ReferenceError", format!("{} is not defined", name)))
         }
     }
 
-    fn make_params(&mut self, mut params: Vec<Tok>, mut arguments: Vec<JSItem>) -> (Vec<Tok>, Vec<JSItem>) {
+    /// Runs a function body until a `return`, the call gives back its value or undefined when
+    /// the body runs off the end.
+    fn run_function_body(&mut self, body: Vec<JSItem>) -> Result<JSItem, Exception> {
+        match self.run_statements(body)? {
+            Completion::Return(value) => Ok(value),
+            _ => Ok(JSItem::Undefined)
+        }
+    }
+
+    fn make_params(&mut self, mut params: Vec<Tok>, mut arguments: Vec<JSItem>) -> Result<(Vec<Tok>, Vec<JSItem>), Exception> {
         arguments.reverse();
 
         let mut new_params = vec![];
@@ -114,32 +136,37 @@ impl Interpreter {
         let mut items = vec![];
         while !arguments.is_empty() {
             if let Some(arg) = arguments.pop() {
-                let out = self.visit(arg);
+                let out = self.visit(arg)?;
                 names.push(new_params.pop().unwrap_or(Tok::Name {name: "extra".to_string()}));
                 items.push(out);
             }
         }
 
-        return (names, items);
+        return Ok((names, items));
     }
 
-    fn call_function(&mut self, params: Vec<Tok>, arguments: Vec<JSItem>, body: Vec<JSItem>) -> Result<JSItem, ()> {
+    /// Calls a function written in JavaScript, a method call passes the object it was read from
+    /// as `this`.
+    fn call_function(&mut self, this: Option<JSItem>, params: Vec<Tok>, arguments: Vec<JSItem>, body: Vec<JSItem>) -> Result<JSItem, Exception> {
         //create a new scope
         self.create_new_scope();
         self.function_scope.push(self.scope.clone());
-        let args = self.make_params(params, arguments);
-        self.add_params_to_scope(args.0, args.1);
-
-        let mut out = JSItem::Undefined;
-        for item in body {
-            out = self.interpret(item);
+        if let Some(this) = this {
+            self.scopes.get_mut(self.scope).unwrap().insert("this".to_string(), this);
         }
+        let out = match self.make_params(params, arguments) {
+            Ok(args) => {
+                self.add_params_to_scope(args.0, args.1);
+                self.run_function_body(body)
+            }
+            Err(e) => Err(e)
+        };
         self.function_scope.pop();
         self.remove_current_scope();
-        return Ok(out);
+        return out;
     }
 
-    fn call_std(&mut self, this_path: Vec<String>, func: StdFun, params: Vec<Tok>, arguments: Vec<JSItem>) -> Result<JSItem, ()> {
+    fn call_std(&mut self, this_path: Vec<String>, func: StdFun, params: Vec<Tok>, arguments: Vec<JSItem>) -> Result<JSItem, Exception> {
         //create a new scope
         self.create_new_scope();
         self.function_scope.push(self.scope.clone());
@@ -147,7 +174,7 @@ impl Interpreter {
         match func {
             #[allow(unreachable_code)]
             StdFun::ConsoleLog => {
-                let args = self.make_params(params, arguments);
+                let args = self.make_params(params, arguments)?;
                 #[cfg(test)]{
                     self.captured_output.push(args.1 );
                     self.function_scope.pop();
@@ -160,118 +187,130 @@ impl Interpreter {
                 return Ok(JSItem::Undefined)
             }
             StdFun::ObjectKeys => {
+                let args = self.make_params(params, arguments)?;
                 self.function_scope.pop();
                 self.remove_current_scope();
-                return Err(());
+                return args.1.first().unwrap_or(&JSItem::Undefined).own_keys()
+                    .ok_or_else(|| self.error("TypeError", "Cannot convert undefined or null to object".to_string()));
             }
             StdFun::FunctionApply => {
-                let args = self.make_params(params, arguments);
+                let args = self.make_params(params, arguments)?;
                 let out = std_fun_apply(self, this_path, args);
                 self.function_scope.pop();
                 self.remove_current_scope();
-                return out;
+                return out.map_err(|_| self.error("TypeError", "Function.prototype.apply was called on an unsupported function".to_string()));
             }
             StdFun::ArrayMap => {
                 self.function_scope.pop();
                 self.remove_current_scope();
-                return Err(());
+                return Err(self.not_a_function("Array.prototype.map"));
             }
             StdFun::ArrayConstructor => {
                 self.function_scope.pop();
                 self.remove_current_scope();
-                return Err(());
+                return Err(self.not_a_function("Array"));
             }
             StdFun::ArrayPush => {
-                let mut args = self.make_params(params, arguments);
+                let mut args = self.make_params(params, arguments)?;
                 if let Ok(()) = std_array_push(self, this_path, args) {
                     return Ok(JSItem::Undefined);
                 }
-                return Err(())
+                return Err(self.error("TypeError", "push was called on something that isn't an array".to_string()))
             }
         }
     }
 
-    fn call_func_ex(&mut self, ex: Expression, _this_path: Vec<String>, arguments: Vec<JSItem>) -> Result<JSItem, ()> {
+    fn call_func_ex(&mut self, ex: Expression, this_path: Vec<String>, arguments: Vec<JSItem>) -> Result<JSItem, Exception> {
         match ex {
-            Expression::FuncEx { params, body } => {
-                return self.call_function(params.clone(), arguments, body.clone());
+            Expression::FuncEx { params, body, .. } => {
+                let this = self.this_object(&this_path)?;
+                return self.call_function(this, params.clone(), arguments, body.clone());
             }
             _ => {
-                return Err(())
+                return Err(self.not_a_function(&this_path.join(".")))
             }
         }
     }
 
-    fn call_object_reference(&mut self, this_path: Vec<String>, reference: Vec<String>, arguments: Vec<JSItem>) -> Result<JSItem, ()> {
+    fn call_object_reference(&mut self, this_path: Vec<String>, reference: Vec<String>, arguments: Vec<JSItem>) -> Result<JSItem, Exception> {
         let mut path = reference.clone();
         let function = find_object_from_reference(self, path.clone());
 
         match function {
             Ok(function) => {
                 match function {
-                    JSItem::Ex {expression} => {
+                    JSItem::Ex {expression, ..} => {
                         return self.call_func_ex(*expression, this_path, arguments);
                     }
                     JSItem::ObjectReference { path } => {
                         return self.call_object_reference( this_path, path.clone(), arguments);
                     }
                     JSItem::Function { mutable: _, params, properties: _, body } => {
-                        #[allow(mutable_borrow_reservation_conflict)]
-                            return self.call_function(params.clone(), arguments, body.clone());
+                        let this = self.this_object(&this_path)?;
+                        return self.call_function(this, params.clone(), arguments, body.clone());
                     }
                     JSItem::Std { params, func } => {
                         #[allow(mutable_borrow_reservation_conflict)]
                             return self.call_std(this_path, func.clone(), params.clone(), arguments);
                     }
                     _ => {
-                        return Err(())
+                        return Err(self.not_a_function(&reference.join(".")))
                     }
                 }
             }
-            Err(e) => return Err(e)
+            Err(_) => return Err(self.not_a_function(&reference.join(".")))
+        }
+    }
+
+    /// The object a method was read from, none for a function called on its own.
+    fn this_object(&mut self, this_path: &Vec<String>) -> Result<Option<JSItem>, Exception> {
+        if this_path.is_empty() {
+            return Ok(None);
+        }
+        match find_object_from_reference(self, this_path.clone()) {
+            Ok(object) => Ok(Some(self.visit(object)?)),
+            Err(_) => Ok(None)
         }
     }
 
-    fn call_member_ex(&mut self, object: Box<Expression>, property: Box<Expression>, arguments: Vec<JSItem>) -> Result<JSItem, ()>{
-        let mut path = find_reference_from_member_expression(Expression::MemberExpression {object, property});
+    /// Calls a method, `this` is the object the member expression reads it from.
+    fn call_member_ex(&mut self, callee: Expression, arguments: Vec<JSItem>) -> Result<JSItem, Exception> {
+        let callee = self.resolve_keys(callee)?;
+        let mut path = find_reference_from_member_expression(callee);
         let mut this_path = path.clone();
         this_path.pop();
         self.call_object_reference(this_path, path, arguments)
     }
 
-    fn visit_binop(&mut self, a: Box<Expression>, op: Operator, b: Box<Expression>) -> JSItem {
-        match op {
-            Operator::Add => {
-                bin_add(self.visit_ex(a), self.visit_ex(b)).unwrap()
-            }
-            Operator::Sub => {
-                bin_sub(self.visit_ex(a), self.visit_ex(b)).unwrap()
-            }
-            Operator::Mult => {
-                bin_mul(self.visit_ex(a), self.visit_ex(b)).unwrap()
-            }
-            Operator::Div => {
-                bin_div(self.visit_ex(a), self.visit_ex(b)).unwrap()
-            }
-            Operator::Less => {
-                bin_less(self.visit_ex(a), self.visit_ex(b)).unwrap()
+    fn visit_binop(&mut self, a: Box<Expression>, op: Operator, b: Box<Expression>) -> Result<JSItem, Exception> {
+        let out = match op {
+            Operator::And | Operator::Or | Operator::Nullish => {
+                let left = self.visit_ex(a)?;
+                if short_circuits(&op, &left) {
+                    left
+                } else {
+                    self.visit_ex(b)?
+                }
             }
             _ => {
-                JSItem::Undefined
+                let left = self.visit_ex(a)?;
+                let right = self.visit_ex(b)?;
+                bin_op(&op, left, right).map_err(|message| self.error("TypeError", message))?
             }
-        }
+        };
+        Ok(out)
     }
 
-    fn visit_call_ex(&mut self, callee: Box<Expression>, arguments: Vec<JSItem>) -> JSItem {
+    fn visit_call_ex(&mut self, callee: Box<Expression>, arguments: Vec<JSItem>) -> Result<JSItem, Exception> {
         match *callee {
-            Expression::MemberExpression { object, property } => {
-                self.call_member_ex(object, property, arguments).unwrap()
+            callee @ Expression::MemberExpression { .. } | callee @ Expression::ComputedMemberExpression { .. } => {
+                self.call_member_ex(callee, arguments)
             }
-            Expression::Identifier { name } => {
-                self.call_identifier(name, arguments).unwrap()
+            Expression::Identifier { name, .. } => {
+                self.call_identifier(name, arguments)
             }
             _ => {
-                JSItem::Undefined
+                Ok(JSItem::Undefined)
             }
         }
     }
@@ -283,38 +322,49 @@ impl Interpreter {
                 match obj.0 {
                     JSItem::Variable { mutable, value } => {
                         match value {
-                            Expression::ArrayExpression { items, properties } => {
+                            Expression::ArrayExpression { items, properties, span } => {
                                 let out = JSItem::Array { items: items.clone(), properties: properties.clone() };
                                 self.replace_object(obj.1, JSItem::Variable {
                                     mutable,
-                                    value: Expression::ArrayExpression {items, properties}
+                                    value: Expression::ArrayExpression {items, properties, span}
                                 }, name);
                                 return out;
                             }
-                            Expression::Object { mutable: om, properties } => {
+                            Expression::Object { mutable: om, properties, span } => {
                                 let out = JSItem::Object {mutable: om, properties: properties.clone()};
                                 self.replace_object(obj.1, JSItem::Variable {
                                     mutable,
-                                    value: Expression::Object {mutable: om, properties}
+                                    value: Expression::Object {mutable: om, properties, span}
                                 }, name);
                                 return out;
                             }
-                            Expression::String {value} => {
+                            Expression::String {value, span} => {
                                 let out = JSItem::String {value: value.clone()};
                                 self.replace_object(obj.1, JSItem::Variable {
                                     mutable,
-                                    value: Expression::String {value}
+                                    value: Expression::String {value, span}
                                 }, name);
                                 return out;
                             }
-                            Expression::Number {value} => {
+                            Expression::Number {value, span} => {
                                 self.replace_object(obj.1, JSItem::Variable {
                                     mutable,
-                                    value: Expression::Number {value}
+                                    value: Expression::Number {value, span}
                                 }, name);
                                 return JSItem::Number {value: value.clone()};
                             }
-                            _ => {}
+                            Expression::RegExp {pattern, flags, span} => {
+                                let out = JSItem::RegExp {pattern: pattern.clone(), flags: flags.clone()};
+                                self.replace_object(obj.1, JSItem::Variable {
+                                    mutable,
+                                    value: Expression::RegExp {pattern, flags, span}
+                                }, name);
+                                return out;
+                            }
+                            value => {
+                                self.replace_object(obj.1, JSItem::Variable { mutable, value: value.clone() }, name);
+                                return self.visit_ex(Box::new(value)).unwrap_or(JSItem::Undefined);
+                            }
                         }
                     }
                     JSItem::Number {value} => {
@@ -326,7 +376,10 @@ impl Interpreter {
                         self.replace_object(obj.1, JSItem::String {value}, name);
                         return out;
                     }
-                    _ => {}
+                    object => {
+                        self.replace_object(obj.1, object.clone(), name);
+                        return object;
+                    }
                 }
             },
             Err(_) => {}
@@ -334,49 +387,141 @@ impl Interpreter {
         JSItem::Undefined
     }
 
-    fn visit_ex_up(&mut self, ex: Box<Expression>) -> JSItem {
-        if let Expression::Identifier {name} = *ex {
-            if let Ok(obj) = self.get_object(&name) {
-                if let JSItem::Variable {mutable, value} = obj.0 {
-                    match value {
-                        Expression::String {value:_} => {
-                            self.replace_object(obj.1, JSItem::NaN, name);
-                        }
-                        Expression::Number {value} => {
-                            self.replace_object(obj.1, JSItem::Variable {
-                                mutable,
-                                value: Expression::Number {value: value + 1.}
-                            }, name);
-                        }
-                        _ => {}
-                    }
+    /// `++` and `--` store the operand plus or minus one, giving back the new value when
+    /// written before the operand and the old one, as a number, when written after.
+    fn visit_update(&mut self, operator: Operator, prefix: bool, expression: Box<Expression>) -> Result<JSItem, Exception> {
+        let expression = self.resolve_keys(*expression)?;
+        let old = self.visit_ex(Box::new(expression.clone()))?.to_number();
+        let new = JSItem::Number { value: number_binop(&operator, old, 1.) };
+        self.store_to(expression, new.clone());
+        if prefix {
+            Ok(new)
+        } else {
+            Ok(JSItem::Number { value: old })
+        }
+    }
+
+    /// `delete` and `typeof` look at the operand before it is evaluated, `typeof` of a name
+    /// that was never declared is "undefined" rather than a ReferenceError.
+    fn visit_unary(&mut self, op: UnaryOperator, argument: Box<Expression>) -> Result<JSItem, Exception> {
+        let value = match (op, *argument) {
+            (UnaryOperator::Delete, member @ Expression::MemberExpression { .. })
+            | (UnaryOperator::Delete, member @ Expression::ComputedMemberExpression { .. }) => {
+                let path = find_reference_from_member_expression(self.resolve_keys(member)?);
+                JSItem::Bool { value: delete_object(self, path) }
+            }
+            (UnaryOperator::Delete, Expression::Identifier { .. }) => JSItem::Bool { value: false },
+            (UnaryOperator::TypeOf, Expression::Identifier { name, .. }) => {
+                let declared = self.scopes.iter().any(|scope| scope.contains_key(&name));
+                let value = if declared { self.visit_ident(name) } else { JSItem::Undefined };
+                JSItem::String { value: value.type_of().to_string() }
+            }
+            (op, argument) => {
+                let value = self.visit_ex(Box::new(argument))?;
+                match op {
+                    UnaryOperator::Not => JSItem::Bool { value: !value.is_truthy() },
+                    UnaryOperator::Minus => JSItem::Number { value: -value.to_number() },
+                    UnaryOperator::Plus => JSItem::Number { value: value.to_number() },
+                    UnaryOperator::BitNot => JSItem::Number { value: !to_int32(value.to_number()) as f64 },
+                    UnaryOperator::TypeOf => JSItem::String { value: value.type_of().to_string() },
+                    UnaryOperator::Void => JSItem::Undefined,
+                    UnaryOperator::Delete => JSItem::Bool { value: true }
                 }
             }
-        }
-        JSItem::Undefined
+        };
+        Ok(value)
     }
 
-    fn visit_member_expression(&mut self, object: Box<Expression>, property: Box<Expression>) -> JSItem {
-        let object_out = self.visit_ex(object);
+    /// An assignment used as a value, gives back what was assigned. `&&=`, `||=` and `??=`
+    /// give back the current value without assigning when it decides the result, the other
+    /// compound operators assign the current value combined with the right hand side.
+    fn visit_assign(&mut self, operator: Operator, left: Box<Expression>, right: Box<Expression>) -> Result<JSItem, Exception> {
+        let left = Box::new(self.resolve_keys(*left)?);
+        let value = match operator {
+            Operator::None => self.visit_ex(right)?,
+            Operator::And | Operator::Or | Operator::Nullish => {
+                let current = self.visit_ex(left.clone())?;
+                if short_circuits(&operator, &current) {
+                    return Ok(current);
+                }
+                self.visit_ex(right)?
+            }
+            operator => {
+                let current = self.visit_ex(left.clone())?;
+                let right = self.visit_ex(right)?;
+                bin_op(&operator, current, right).map_err(|message| self.error("TypeError", message))?
+            }
+        };
+        self.store_to(*left, value.clone());
+        Ok(value)
+    }
+
+    /// Writes `value` to the variable or property `target` names, computed keys already
+    /// resolved.
+    fn store_to(&mut self, target: Expression, value: JSItem) {
+        let path = match target {
+            Expression::Identifier { name, .. } => vec![name],
+            member => find_reference_from_member_expression(member)
+        };
+        let _ = set_object(self, path, o_to_v(value, AssignOp::None));
+    }
+
+    /// Reads a property, `object[key]` evaluates the object before the key.
+    fn visit_member_expression(&mut self, object: Box<Expression>, property: Box<Expression>, computed: bool) -> Result<JSItem, Exception> {
+        let object_out = self.visit_ex(object)?;
+        let name = match *property {
+            property if computed => self.visit_ex(Box::new(property))?.to_property_key(),
+            Expression::Identifier { name, .. } => name,
+            _ => String::new()
+        };
         match object_out {
             JSItem::Object { mutable:_, properties } => {
-                if let Expression::Identifier {name} = *property {
-                    if let Some(item) = properties.get(&name) {
-                        return self.visit(item.clone());
-                    }
+                if let Some(item) = properties.get(&name) {
+                    return self.visit(item.clone());
                 }
             }
+            JSItem::Array { items, properties } => {
+                return match array_property(&items, &properties, &name) {
+                    item @ JSItem::Variable { .. } | item @ JSItem::Ex { .. } => self.visit(item),
+                    item => Ok(item)
+                };
+            }
+            JSItem::RegExp { pattern, flags } => {
+                return Ok(regexp_property(&pattern, &flags, &name));
+            }
+            JSItem::Undefined | JSItem::Null => {
+                return Err(self.error("TypeError", format!("Cannot read properties of {} (reading '{}')", object_out.to_js_string(), name)));
+            }
             _ => {
-                return JSItem::Undefined;
+                return Ok(JSItem::Undefined);
             }
         }
-        JSItem::Undefined
+        Ok(JSItem::Undefined)
     }
 
-    fn visit_array_expression(&mut self, items: Vec<JSItem>, _properties: HashMap<String, JSItem>) -> JSItem {
+    /// `target` with every computed key evaluated into a literal, so the property it names can
+    /// be read and then written without running the key twice.
+    fn resolve_keys(&mut self, target: Expression) -> Result<Expression, Exception> {
+        let resolved = match target {
+            Expression::MemberExpression { object, property, span } => {
+                Expression::MemberExpression { object: Box::new(self.resolve_keys(*object)?), property, span }
+            }
+            Expression::ComputedMemberExpression { object, property, span } => {
+                let object = self.resolve_keys(*object)?;
+                let key_span = property.span();
+                let key = self.visit_ex(property)?.to_property_key();
+                let property = Box::new(Expression::Literal { value: key, span: key_span });
+                Expression::ComputedMemberExpression { object: Box::new(object), property, span }
+            }
+            target => target
+        };
+        Ok(resolved)
+    }
+
+    fn visit_array_expression(&mut self, items: Vec<JSItem>, _properties: PropertyMap) -> Result<JSItem, Exception> {
         let mut new_items = vec![];
         for mut item in items {
-            new_items.push(self.visit(item));
+            new_items.push(self.visit(item)?);
         }
         let array = inherit(&self, JSItem::ObjectReference {
             path: vec!["Array".to_string()]
@@ -384,50 +529,100 @@ impl Interpreter {
             path: vec!["Array".to_string()]
         });
 
-        JSItem::Array {items: new_items, properties: match array {
+        Ok(JSItem::Array {items: new_items, properties: match array {
             JSItem::Object { mutable:_, properties } => {
                 properties
             }
-            _ => HashMap::new()
-        }}
+            _ => PropertyMap::new()
+        }})
     }
 
-    fn visit_ex(&mut self, ex: Box<Expression>) -> JSItem {
-        match *ex {
-            Expression::ArrayExpression { items, properties } => {
-                self.visit_array_expression(items, properties)
+    fn visit_ex(&mut self, ex: Box<Expression>) -> Result<JSItem, Exception> {
+        let out = match *ex {
+            Expression::ArrayExpression { items, properties, .. } => {
+                return self.visit_array_expression(items, properties);
+            }
+            Expression::Object { mutable, properties, .. } => {
+                JSItem::Object { mutable, properties }
+            }
+            Expression::MemberExpression { object, property, .. } => {
+                return self.visit_member_expression(object, property, false);
             }
-            Expression::MemberExpression { object, property } => {
-                self.visit_member_expression(object, property)
+            Expression::ComputedMemberExpression { object, property, .. } => {
+                return self.visit_member_expression(object, property, true);
             }
-            Expression::UpdateExpression {expression} => {
-                self.visit_ex_up(expression)
+            Expression::UpdateExpression { operator, prefix, expression, .. } => {
+                return self.visit_update(operator, prefix, expression);
             }
-            Expression::Identifier {name} => {
+            Expression::Assign { operator, left, right, .. } => {
+                return self.visit_assign(operator, left, right);
+            }
+            Expression::Identifier {name, ..} => {
                 self.visit_ident(name)
             }
-            Expression::Literal { value } => {
+            Expression::Literal { value, .. } => {
                 JSItem::String { value }
             }
-            Expression::Number { value } => {
+            Expression::Number { value, .. } => {
                 JSItem::Number { value }
             }
-            Expression::Binop { a, op, b } => {
-                self.visit_binop(a, op, b)
+            Expression::True { .. } => {
+                JSItem::Bool { value: true }
             }
-            Expression::SubExpression { expression } => {
-                self.visit_ex(expression)
+            Expression::False { .. } => {
+                JSItem::Bool { value: false }
+            }
+            Expression::Null { .. } => {
+                JSItem::Null
             }
-            Expression::CallExpression { callee, arguments } => {
-                self.visit_call_ex(callee, arguments)
+            Expression::Binop { a, op, b, .. } => {
+                return self.visit_binop(a, op, b);
             }
-            Expression::String {value} => {
+            Expression::Unary { op, argument, .. } => {
+                return self.visit_unary(op, argument);
+            }
+            Expression::Conditional { test, consequent, alternate, .. } => {
+                return if self.visit_ex(test)?.is_truthy() {
+                    self.visit_ex(consequent)
+                } else {
+                    self.visit_ex(alternate)
+                };
+            }
+            Expression::SubExpression { expression, .. } => {
+                return self.visit_ex(expression);
+            }
+            Expression::CallExpression { callee, arguments, .. } => {
+                return self.visit_call_ex(callee, arguments);
+            }
+            Expression::String {value, ..} => {
                 JSItem::String {value}
             }
+            Expression::TemplateLiteral { cooked, raw: _, expressions, .. } => {
+                return self.visit_template_literal(cooked, expressions);
+            }
+            Expression::RegExp { pattern, flags, .. } => {
+                JSItem::RegExp { pattern, flags }
+            }
+            Expression::FuncEx { params, body, .. } => {
+                JSItem::Function { mutable: true, params, properties: PropertyMap::new(), body }
+            }
             _ => {
                 JSItem::Undefined
             }
+        };
+        Ok(out)
+    }
+
+    fn visit_template_literal(&mut self, cooked: Vec<String>, expressions: Vec<JSItem>) -> Result<JSItem, Exception> {
+        let mut value = String::new();
+        let mut expressions = expressions.into_iter();
+        for chunk in cooked {
+            value.push_str(&chunk);
+            if let Some(expression) = expressions.next() {
+                value.push_str(&self.visit(expression)?.to_js_string());
+            }
         }
+        Ok(JSItem::String { value })
     }
 
     fn create_new_scope(&mut self) {
@@ -442,35 +637,203 @@ impl Interpreter {
         self.scope -= 1;
     }
 
-    fn visit_for_statement(&mut self, init: JSItem, test: JSItem, update: JSItem, body: Vec<JSItem>) -> JSItem {
+    fn visit_for_statement(&mut self, init: JSItem, test: JSItem, update: JSItem, body: Vec<JSItem>, labels: &[String]) -> Result<Completion, Exception> {
         self.create_new_scope();
-        self.visit(init);
+        self.visit(init)?;
+        let mut completion = Completion::Normal(JSItem::Undefined);
         loop {
             let cloned_test = test.clone();
-            let test_out = self.visit(cloned_test);
+            let test_out = self.visit(cloned_test)?;
             if let JSItem::Bool {value} = test_out {
                 if !value {
                     break;
                 }
             }
 
-            for item in body.clone() {
-                self.interpret(item);
+            if let Some(done) = self.run_statements(body.clone())?.after_iteration(labels) {
+                completion = done;
+                break;
             }
 
-            self.visit(update.clone());
+            self.visit(update.clone())?;
         }
         self.remove_current_scope();
-        JSItem::Undefined
+        Ok(completion)
+    }
+
+    fn run_loop_body(&mut self, body: &Vec<JSItem>) -> Result<Completion, Exception> {
+        self.create_new_scope();
+        let completion = self.run_statements(body.clone())?;
+        self.remove_current_scope();
+        Ok(completion)
+    }
+
+    fn visit_while_statement(&mut self, test: JSItem, body: Vec<JSItem>, labels: &[String]) -> Result<Completion, Exception> {
+        while self.visit(test.clone())?.is_truthy() {
+            if let Some(completion) = self.run_loop_body(&body)?.after_iteration(labels) {
+                return Ok(completion);
+            }
+        }
+        Ok(Completion::Normal(JSItem::Undefined))
+    }
+
+    fn visit_do_while_statement(&mut self, body: Vec<JSItem>, test: JSItem, labels: &[String]) -> Result<Completion, Exception> {
+        loop {
+            if let Some(completion) = self.run_loop_body(&body)?.after_iteration(labels) {
+                return Ok(completion);
+            }
+            if !self.visit(test.clone())?.is_truthy() {
+                break;
+            }
+        }
+        Ok(Completion::Normal(JSItem::Undefined))
+    }
+
+    fn visit_if_statement(&mut self, test: JSItem, consequent: Vec<JSItem>, alternate: JSItem) -> Result<Completion, Exception> {
+        if self.visit(test)?.is_truthy() {
+            self.run_loop_body(&consequent)
+        } else {
+            self.execute(alternate)
+        }
+    }
+
+    /// Runs the cases from the first one whose test is strictly equal to the discriminant, or
+    /// from `default` when none is, falling through until a `break`.
+    fn visit_switch_statement(&mut self, discriminant: JSItem, cases: Vec<SwitchCase>, labels: &[String]) -> Result<Completion, Exception> {
+        let value = self.visit(discriminant)?;
+        let mut start = None;
+        for (i, case) in cases.iter().enumerate() {
+            if let Some(test) = &case.test {
+                if self.visit(test.clone())?.strictly_equals(&value) {
+                    start = Some(i);
+                    break;
+                }
+            }
+        }
+        let start = match start.or_else(|| cases.iter().position(|case| case.test.is_none())) {
+            Some(start) => start,
+            None => return Ok(Completion::Normal(JSItem::Undefined))
+        };
+
+        let body = cases.into_iter().skip(start).flat_map(|case| case.consequent).collect();
+        let completion = match self.run_loop_body(&body)? {
+            Completion::Break(None) => Completion::Normal(JSItem::Undefined),
+            Completion::Break(Some(label)) if labels.contains(&label) => Completion::Normal(JSItem::Undefined),
+            completion => completion
+        };
+        Ok(completion)
+    }
+
+    /// Runs the block, then the handler if the block threw, then the finalizer. A finalizer
+    /// that breaks, continues, returns or throws replaces how the rest finished.
+    fn visit_try_statement(&mut self, block: Vec<JSItem>, handler: Option<CatchClause>, finalizer: Option<Vec<JSItem>>) -> Result<Completion, Exception> {
+        let scopes = self.scopes.len();
+        let function_scopes = self.function_scope.len();
+
+        let mut result = self.run_loop_body(&block);
+        if let Err(exception) = &result {
+            self.unwind(scopes, function_scopes);
+            if let Some(CatchClause { param, body }) = handler {
+                self.create_new_scope();
+                if let Some(param) = param {
+                    self.scopes.get_mut(self.scope).unwrap().insert(param, o_to_v(exception.value.clone(), AssignOp::Let));
+                }
+                result = self.run_statements(body);
+                self.unwind(scopes, function_scopes);
+            }
+        }
+
+        if let Some(finalizer) = finalizer {
+            match self.run_loop_body(&finalizer) {
+                Ok(Completion::Normal(_)) => {}
+                abrupt => {
+                    self.unwind(scopes, function_scopes);
+                    return abrupt;
+                }
+            }
+        }
+        result
+    }
+
+    /// Drops the scopes left behind by statements an exception jumped out of.
+    fn unwind(&mut self, scopes: usize, function_scopes: usize) {
+        while self.scopes.len() > scopes {
+            self.remove_current_scope();
+        }
+        self.function_scope.truncate(function_scopes);
+    }
+
+    /// `labels` are the labels written in front of the statement, a `break` naming one of
+    /// them ends here.
+    fn visit_labelled_statement(&mut self, label: String, mut body: Vec<JSItem>, mut labels: Vec<String>) -> Result<Completion, Exception> {
+        labels.push(label.clone());
+        let completion = if body.len() == 1 {
+            self.execute_labelled(body.pop().unwrap(), labels)?
+        } else {
+            self.run_statements(body)?
+        };
+        match completion {
+            Completion::Break(Some(target)) if target == label => Ok(Completion::Normal(JSItem::Undefined)),
+            completion => Ok(completion)
+        }
+    }
+
+    /// Runs statements in order until one of them breaks, continues, returns or throws.
+    fn run_statements(&mut self, items: Vec<JSItem>) -> Result<Completion, Exception> {
+        let mut completion = Completion::Normal(JSItem::Undefined);
+        for item in items {
+            completion = self.execute(item)?;
+            if let Completion::Break(_) | Completion::Continue(_) | Completion::Return(_) = completion {
+                break;
+            }
+        }
+        Ok(completion)
+    }
+
+    fn execute(&mut self, item: JSItem) -> Result<Completion, Exception> {
+        self.execute_labelled(item, vec![])
+    }
+
+    /// Runs a statement for its completion, loops are told the labels in front of them so
+    /// they take a `continue` aimed at them.
+    fn execute_labelled(&mut self, item: JSItem, labels: Vec<String>) -> Result<Completion, Exception> {
+        let (statement, span) = match item {
+            JSItem::St { statement, span } => (*statement, span),
+            JSItem::Ex { expression, span } => {
+                self.location = span;
+                return Ok(Completion::Normal(self.visit_ex(expression)?));
+            }
+            item => return Ok(Completion::Normal(self.visit(item)?))
+        };
+        self.location = span.clone();
+        match statement {
+            Statement::Break { label } => Ok(Completion::Break(label)),
+            Statement::Continue { label } => Ok(Completion::Continue(label)),
+            Statement::Return { value: Some(value) } => Ok(Completion::Return(self.visit(*value)?)),
+            Statement::Return { value: None } => Ok(Completion::Return(JSItem::Undefined)),
+            Statement::Throw { value } => Err(Exception { value: self.visit(*value)?, span }),
+            Statement::Try { block, handler, finalizer } => self.visit_try_statement(block, handler, finalizer),
+            Statement::Labelled { label, body } => self.visit_labelled_statement(label, body, labels),
+            Statement::ForStatement { init, test, update, body } => {
+                self.visit_for_statement(init, test, update, body, &labels)
+            }
+            Statement::While { test, body } => self.visit_while_statement(test, body, &labels),
+            Statement::DoWhile { body, test } => self.visit_do_while_statement(body, test, &labels),
+            Statement::If { test, consequent, alternate } => self.visit_if_statement(test, consequent, alternate),
+            Statement::Switch { discriminant, cases } => self.visit_switch_statement(discriminant, cases, &labels),
+            statement => Ok(Completion::Normal(self.visit_st(Box::new(statement))?))
+        }
     }
 
     fn declare_function_in_scope(&mut self, mutable: bool, name: String, params: Vec<Tok>, body: Vec<JSItem>) {
-        let mut properties = HashMap::new();
+        let mut properties = PropertyMap::new();
         properties.insert("prototype".to_string(), JSItem::Ex {
-            expression: Box::new(Expression::String { value: name.clone() })
+            expression: Box::new(Expression::String { value: name.clone(), span: Span::default() }),
+            span: Span::default()
         });
         properties.insert("name".to_string(), JSItem::Ex {
-            expression: Box::new(Expression::Literal { value: name.clone() })
+            expression: Box::new(Expression::Literal { value: name.clone(), span: Span::default() }),
+            span: Span::default()
         });
         self.scopes.get_mut(self.scope)
             .unwrap()
@@ -482,19 +845,18 @@ impl Interpreter {
             });
     }
 
-    fn assign_variable(&mut self, operator: AssignOp, left: JSItem, right: JSItem) -> Result<(), ()> {
+    fn assign_variable(&mut self, operator: AssignOp, left: JSItem, right_out: JSItem) -> Result<(), ()> {
         let mut path = vec![];
-        if let JSItem::Ex {expression} = left {
-            if let Expression::MemberExpression { object, property } = *expression {
-                path = find_reference_from_member_expression(Expression::MemberExpression {object, property})
-            } else if let Expression::String {value} = *expression {
+        if let JSItem::Ex {expression, ..} = left {
+            if let Expression::MemberExpression { .. } | Expression::ComputedMemberExpression { .. } = *expression {
+                path = find_reference_from_member_expression(*expression)
+            } else if let Expression::String {value, ..} = *expression {
                 path = vec![value];
-            } else if let Expression::Literal {value} = *expression {
+            } else if let Expression::Literal {value, ..} = *expression {
                 path = vec![value];
             }
         }
 
-        let right_out = self.visit(right);
         let exp = o_to_v(right_out, operator.clone());
 
         match operator {
@@ -537,18 +899,12 @@ impl Interpreter {
         }
     }
 
-    fn visit_st(&mut self, st: Box<Statement>) -> JSItem {
-        match *st {
-            Statement::Return { value } => {
-                return self.visit(*value);
-            }
+    fn visit_st(&mut self, st: Box<Statement>) -> Result<JSItem, Exception> {
+        let out = match *st {
             Statement::AssignObject { .. } => {
                 JSItem::Undefined
             }
-            Statement::ForStatement { init, test, update, body } => {
-                return self.visit_for_statement(init, test, update, body);
-            }
-            Statement::AssignArrowFunction { mutable, function } => {
+            Statement::AssignArrowFunction { mutable, function } | Statement::AssignFunction { mutable, function } => {
                 match *function {
                     Statement::FunctionDef { name, params, body } => {
                         self.declare_function_in_scope(mutable, name, params, body);
@@ -561,7 +917,12 @@ impl Interpreter {
             }
             #[allow(unused_must_use)]
             Statement::AssignmentExpression { operator, left, right } => {
-                self.assign_variable(operator, left, right);
+                let left = match left {
+                    JSItem::Ex { expression, span } => JSItem::Ex { expression: Box::new(self.resolve_keys(*expression)?), span },
+                    left => left
+                };
+                let value = self.visit(right)?;
+                self.assign_variable(operator, left, value);
                 JSItem::Undefined
             }
             Statement::FunctionDef { name, params, body } => {
@@ -571,30 +932,52 @@ impl Interpreter {
             _ => {
                 JSItem::Undefined
             }
-        }
+        };
+        Ok(out)
     }
 
-    fn visit(&mut self, tree: JSItem) -> JSItem {
+    fn visit(&mut self, tree: JSItem) -> Result<JSItem, Exception> {
         match tree {
             JSItem::Variable { mutable:_, value } => {
                 self.visit_ex(Box::from(value))
             }
-            JSItem::Ex { expression } => {
+            JSItem::Ex { expression, .. } => {
                 self.visit_ex(expression)
             }
-            JSItem::St { statement } => {
+            JSItem::St { statement, .. } => {
                 self.visit_st(statement)
             }
             JSItem::Object { mutable, properties } => {
-                JSItem::Object {mutable, properties}
+                Ok(JSItem::Object {mutable, properties})
+            }
+            JSItem::Std { .. } | JSItem::Function { .. } => {
+                Ok(tree)
             }
             _ => {
-                JSItem::Undefined
+                Ok(JSItem::Undefined)
             }
         }
     }
 
+    /// Runs one top level statement. An exception nothing caught is reported and stops the
+    /// program, later statements are skipped.
+    #[allow(unreachable_code)]
     pub(crate) fn interpret(&mut self, js_item: JSItem) -> JSItem {
-        self.visit(js_item)
+        if self.halted {
+            return JSItem::Undefined;
+        }
+        match self.execute(js_item) {
+            Ok(Completion::Normal(value)) => value,
+            Ok(_) => JSItem::Undefined,
+            Err(exception) => {
+                self.halted = true;
+                #[cfg(test)]{
+                    self.captured_errors.push(exception.to_string());
+                    return JSItem::Undefined;
+                }
+                eprintln!("{}", exception);
+                JSItem::Undefined
+            }
+        }
     }
 }
\ No newline at end of file
@@ -3,4 +3,5 @@ mod tests;
 mod bin_op;
 mod std;
 mod helpers;
+mod completion;
 mod scope;
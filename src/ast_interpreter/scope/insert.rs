@@ -1,6 +1,8 @@
 use std::collections::HashMap;
-use crate::parser::symbols::{JSItem, Expression};
+use crate::parser::property_map::PropertyMap;
+use crate::parser::symbols::{JSItem, Expression, array_index};
 use crate::ast_interpreter::interpreter::Interpreter;
+use crate::ast_interpreter::helpers::v_to_o;
 
 pub(crate) enum InsertResult {
     Ref {
@@ -9,7 +11,7 @@ pub(crate) enum InsertResult {
     Success,
 }
 
-fn insert_o_r_o(object: &mut HashMap<String, JSItem>, mut path: Vec<String>, new_item: JSItem) -> Result<InsertResult, ()> {
+fn insert_o_r_o(object: &mut PropertyMap, mut path: Vec<String>, new_item: JSItem) -> Result<InsertResult, ()> {
     path.reverse();
 
 
@@ -87,7 +89,7 @@ fn insert_o_r(scopes: &mut Vec<HashMap<String, JSItem>>, mut path: Vec<String>,
             match i {
                 JSItem::Variable { mutable:_, value } => {
                     match value {
-                        Expression::Object { mutable:_, properties } => {
+                        Expression::Object { mutable:_, properties, .. } => {
                             if path.len() == 1 {
                                 properties.insert(path.pop().unwrap(), new_item);
                                 return Ok(InsertResult::Success);
@@ -112,6 +114,22 @@ fn insert_o_r(scopes: &mut Vec<HashMap<String, JSItem>>, mut path: Vec<String>,
                                 Err(..) => return Err(())
                             }
                         }
+                        // only a direct element or property, arr[i] = v
+                        Expression::ArrayExpression { items, properties, .. } if path.len() == 1 => {
+                            let key = path.pop().unwrap();
+                            match array_index(&key) {
+                                Some(index) => {
+                                    if index >= items.len() {
+                                        items.resize(index + 1, JSItem::Undefined);
+                                    }
+                                    items[index] = v_to_o(new_item);
+                                }
+                                None => {
+                                    properties.insert(key, new_item);
+                                }
+                            }
+                            return Ok(InsertResult::Success);
+                        }
                         _ => {
                             return Err(())
                         }
@@ -173,4 +191,30 @@ pub(crate) fn set_object(int: &mut Interpreter, mut path: Vec<String>, obj: JSIt
         }
         Err(_) => Err(())
     }
-}
\ No newline at end of file
+}
+/// Removes the property at the end of `path`, false when `path` is a variable on its own.
+#[allow(dead_code)]
+pub(crate) fn delete_object(int: &mut Interpreter, path: Vec<String>) -> bool {
+    let (name, keys) = match path.split_first() {
+        Some((name, keys)) if !keys.is_empty() => (name, keys),
+        _ => return false
+    };
+    let scope = match int.scopes.iter_mut().rev().find(|scope| scope.contains_key(name)) {
+        Some(scope) => scope,
+        None => return true
+    };
+    let mut properties = match scope.get_mut(name) {
+        Some(JSItem::Variable { value: Expression::Object { properties, .. }, .. })
+        | Some(JSItem::Object { properties, .. }) => properties,
+        _ => return true
+    };
+    let (last, keys) = keys.split_last().unwrap();
+    for key in keys {
+        properties = match properties.get_mut(key) {
+            Some(JSItem::Object { properties, .. }) => properties,
+            _ => return true
+        };
+    }
+    properties.remove(last);
+    true
+}
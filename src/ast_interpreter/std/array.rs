@@ -1,3 +1,5 @@
+use crate::lexer::span::Span;
+use crate::parser::property_map::PropertyMap;
 use crate::parser::symbols::{JSItem, StdFun, Expression};
 use crate::lexer::js_token::Tok;
 use crate::ast_interpreter::interpreter::Interpreter;
@@ -15,9 +17,9 @@ pub(crate) fn array_constructor(mut arguments: Vec<JSItem>) -> Result<JSItem, ()
             match length {
                 Some(length) => {
                     match length {
-                        JSItem::Ex {expression} => {
+                        JSItem::Ex {expression, ..} => {
                             match **expression {
-                                Expression::Number {value} => {
+                                Expression::Number {value, ..} => {
                                     let mut items = vec![];
                                     let len = value.clone() as i64;
                                     for _ in 0..len {
@@ -26,9 +28,7 @@ pub(crate) fn array_constructor(mut arguments: Vec<JSItem>) -> Result<JSItem, ()
 
                                     Ok(JSItem::Array {
                                         items,
-                                        properties: hashmap!{
-                                            "length".to_string() => JSItem::Number {value: len as f64}
-                                        }
+                                        properties: PropertyMap::from(vec![("length".to_string(), JSItem::Number {value: len as f64})])
                                     })
                                 }
                                 _ => {
@@ -61,7 +61,7 @@ pub(crate) fn std_array_push(interpreter: &mut Interpreter, this_path: Vec<Strin
         match object {
             JSItem::Variable { mutable, value } => {
                 match value {
-                    Expression::ArrayExpression { mut items, properties } => {
+                    Expression::ArrayExpression { mut items, properties, .. } => {
                         let mut tmp = vec![];
                         while !args.1.is_empty() {
                             tmp.push(args.1.pop().unwrap());
@@ -71,7 +71,7 @@ pub(crate) fn std_array_push(interpreter: &mut Interpreter, this_path: Vec<Strin
                         }
                         if let Ok(..) = set_object(interpreter, this_path, JSItem::Variable {
                             mutable,
-                            value: Expression::ArrayExpression { items, properties }
+                            value: Expression::ArrayExpression { items, properties, span: Span::default() }
                         }) {
                             return Ok(());
                         }
@@ -1,6 +1,6 @@
 use crate::parser::symbols::{JSItem, StdFun};
 use std::fmt::{Display, Formatter, Result};
-use std::collections::HashMap;
+use crate::parser::property_map::PropertyMap;
 use crate::lexer::js_token::Tok;
 use crate::ast_interpreter::interpreter::Interpreter;
 use crate::ast_interpreter::scope::insert::set_object;
@@ -23,7 +23,7 @@ pub(crate) fn std_log(params: Vec<JSItem>) {
 }
 
 pub(crate) fn create_console(mut int: Interpreter) -> Interpreter {
-    let mut p = HashMap::new();
+    let mut p = PropertyMap::new();
     let log = JSItem::Std {
         params: vec![Tok::Name {name: "objs".to_string()}],
         func: StdFun::ConsoleLog
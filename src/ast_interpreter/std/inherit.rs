@@ -1,11 +1,11 @@
 use crate::parser::symbols::JSItem;
-use std::collections::HashMap;
+use crate::parser::property_map::PropertyMap;
 use crate::ast_interpreter::interpreter::Interpreter;
 use crate::ast_interpreter::helpers::{find_object_from_reference};
 
 pub(crate) fn inherit(int: &Interpreter, from: JSItem, to: JSItem) -> JSItem {
 
-    let mut new_prototype = HashMap::new();
+    let mut new_prototype = PropertyMap::new();
     new_prototype.insert("constructor".to_string(), to.clone());
 
     let mut from_vec: Vec<String> = vec![];
@@ -59,7 +59,7 @@ pub(crate) fn inherit(int: &Interpreter, from: JSItem, to: JSItem) -> JSItem {
             }
             _ => {}
         }
-        let mut new_properties = HashMap::new();
+        let mut new_properties = PropertyMap::new();
         new_properties.insert("prototype".to_string(), JSItem::Object {
             mutable: false,
             properties: new_prototype
@@ -1,19 +1,24 @@
-use std::collections::HashMap;
-use crate::parser::symbols::{JSItem};
+use crate::parser::property_map::PropertyMap;
+use crate::parser::symbols::{JSItem, StdFun};
+use crate::lexer::js_token::Tok;
 use crate::ast_interpreter::interpreter::Interpreter;
 use crate::ast_interpreter::scope::insert::set_object;
 
 pub(crate) fn create_object(mut int: Interpreter) -> Interpreter {
-    let mut object_prototype = HashMap::new();
+    let mut object_prototype = PropertyMap::new();
     object_prototype.insert("constructor".to_string(), JSItem::ObjectReference {path: vec!["Object".to_string()]});
 
-    let mut object_properties = HashMap::new();
+    let mut object_properties = PropertyMap::new();
     object_properties.insert("prototype".to_string(), JSItem::Object {
         mutable: false,
         properties: object_prototype
     });
 
     object_properties.insert("__proto__".to_string(), JSItem::ObjectReference {path: vec!["Object".to_string()]});
+    object_properties.insert("keys".to_string(), JSItem::Std {
+        params: vec![Tok::Name {name: "obj".to_string()}],
+        func: StdFun::ObjectKeys
+    });
 
     if let Ok(..) = set_object(&mut int, vec!["Object".to_string()], JSItem::Object {
         mutable: false,
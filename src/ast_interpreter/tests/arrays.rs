@@ -3,7 +3,7 @@ use std::fs;
 use crate::lexer::lexer::Lexer;
 use crate::ast_interpreter::interpreter::Interpreter;
 use crate::parser::symbols::JSItem;
-use std::collections::HashMap;
+use crate::parser::property_map::PropertyMap;
 
 #[test]
 fn test_array_apply() {
@@ -11,8 +11,8 @@ fn test_array_apply() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -24,9 +24,7 @@ fn test_array_apply() {
             JSItem::Undefined,
             JSItem::Undefined
         ],
-        properties: hashmap!{
-            "length".to_string() => JSItem::Number {value: 5. }
-        }
+        properties: PropertyMap::from(vec![("length".to_string(), JSItem::Number {value: 5. })])
     });
 }
 
@@ -36,8 +34,8 @@ fn test_array_push() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let mut out = JSItem::Undefined;
@@ -0,0 +1,25 @@
+use crate::parser::symbols::JSItem;
+use crate::ast_interpreter::tests::interpret_file;
+
+#[test]
+fn test_no_semicolons() {
+    assert_eq!(interpret_file("js/asi/continuation.js").captured_output, vec![
+        vec![JSItem::Number {value: 1.}, JSItem::Number {value: 3.}],
+        vec![JSItem::String {value: String::from("b is 3")}]
+    ]);
+}
+
+#[test]
+fn test_restricted_productions() {
+    let number = |value: f64| JSItem::Number { value };
+    assert_eq!(interpret_file("js/asi/restricted.js").captured_output, vec![
+        vec![JSItem::Undefined],
+        vec![number(1.), number(2.)],
+        vec![number(0.), number(0.)],
+        vec![number(0.), number(2.)],
+        vec![number(1.), number(0.)],
+        vec![number(1.), number(2.)],
+        vec![number(0.), number(0.)],
+        vec![number(1.), number(0.)]
+    ]);
+}
@@ -0,0 +1,9 @@
+use crate::parser::symbols::JSItem;
+use crate::ast_interpreter::tests::interpret_file;
+
+#[test]
+fn test_chained_assignment() {
+    assert_eq!(interpret_file("js/variables/chained_assignment.js").captured_output, vec![
+        vec![JSItem::Number { value: 5. }, JSItem::Number { value: 5. }]
+    ]);
+}
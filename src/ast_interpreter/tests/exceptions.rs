@@ -0,0 +1,40 @@
+use crate::parser::symbols::JSItem;
+use crate::ast_interpreter::tests::{interpret_file, string};
+
+#[test]
+fn test_try_catch_finally() {
+    let int = interpret_file("js/exceptions/try_catch.js");
+    assert_eq!(int.captured_output, vec![
+        vec![string("caught"), string("deep")],
+        vec![string("no binding")],
+        vec![string("finally")],
+        vec![string("inner finally")],
+        vec![string("outer"), string("inner")],
+        vec![string("cleanup"), JSItem::Number { value: 1. }],
+        vec![string("cleanup"), JSItem::Number { value: 2. }],
+        vec![string("ReferenceError"), string("missing is not defined")],
+        vec![string("done")]
+    ]);
+    assert!(int.captured_errors.is_empty());
+}
+
+#[test]
+fn test_uncaught_exception() {
+    let int = interpret_file("js/exceptions/uncaught.js");
+    assert_eq!(int.captured_output, vec![vec![string("before")]]);
+    assert_eq!(int.captured_errors, vec!["Uncaught oops\n    at <anonymous>:2:1".to_string()]);
+}
+
+#[test]
+fn test_uncaught_in_function() {
+    let int = interpret_file("js/exceptions/uncaught_in_function.js");
+    assert_eq!(int.captured_output, vec![vec![string("before")]]);
+    assert_eq!(int.captured_errors, vec!["Uncaught bad\n    at <anonymous>:4:5".to_string()]);
+}
+
+#[test]
+fn test_finally_return_overrides() {
+    let int = interpret_file("js/exceptions/finally_return.js");
+    assert_eq!(int.captured_output, vec![vec![JSItem::Number { value: 2. }], vec![string("finally")]]);
+    assert!(int.captured_errors.is_empty());
+}
@@ -0,0 +1,160 @@
+use crate::parser::symbols::JSItem;
+use crate::ast_interpreter::tests::interpret_file;
+
+#[test]
+fn test_conditional() {
+    assert_eq!(interpret_file("js/expressions/conditional.js").captured_output, vec![
+        vec![JSItem::String { value: "small".to_string() }],
+        vec![JSItem::String { value: "one".to_string() }],
+        vec![JSItem::String { value: "yes".to_string() }],
+        vec![JSItem::String { value: "no".to_string() }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::String { value: "falsy".to_string() }]
+    ]);
+}
+
+#[test]
+fn test_unary() {
+    assert_eq!(interpret_file("js/expressions/unary.js").captured_output, vec![
+        vec![JSItem::Number { value: -5. }, JSItem::Number { value: 3. }, JSItem::Number { value: 1. }, JSItem::Number { value: 5. }, JSItem::Number { value: 16. }],
+        vec![JSItem::Bool { value: false }, JSItem::Bool { value: true }, JSItem::Bool { value: true }],
+        vec![JSItem::Number { value: -6. }, JSItem::Number { value: 0. }, JSItem::Number { value: -8. }],
+        vec![
+            JSItem::String { value: "number".to_string() },
+            JSItem::String { value: "string".to_string() },
+            JSItem::String { value: "boolean".to_string() },
+            JSItem::String { value: "undefined".to_string() }
+        ],
+        vec![JSItem::String { value: "function".to_string() }, JSItem::String { value: "number".to_string() }],
+        vec![JSItem::Undefined],
+        vec![
+            JSItem::String { value: "object".to_string() },
+            JSItem::String { value: "object".to_string() },
+            JSItem::String { value: "function".to_string() }
+        ],
+        vec![JSItem::Bool { value: true }],
+        vec![JSItem::Undefined, JSItem::Number { value: 2. }],
+        vec![JSItem::Bool { value: false }]
+    ]);
+}
+
+#[test]
+fn test_logical() {
+    assert_eq!(interpret_file("js/expressions/logical.js").captured_output, vec![
+        vec![JSItem::Null],
+        vec![JSItem::Number { value: 3. }],
+        vec![JSItem::String { value: "fallback".to_string() }, JSItem::String { value: "first".to_string() }],
+        vec![JSItem::String { value: "default".to_string() }, JSItem::Number { value: 0. }, JSItem::String { value: "".to_string() }],
+        vec![JSItem::String { value: "side".to_string() }],
+        vec![JSItem::Number { value: 5. }],
+        vec![JSItem::Number { value: 7. }],
+        vec![JSItem::String { value: "set".to_string() }],
+        vec![JSItem::String { value: "set".to_string() }],
+        vec![JSItem::Number { value: 10. }],
+        vec![JSItem::Number { value: 10. }]
+    ]);
+}
+
+#[test]
+fn test_update() {
+    assert_eq!(interpret_file("js/expressions/update.js").captured_output, vec![
+        vec![JSItem::Number { value: 5. }, JSItem::Number { value: 6. }],
+        vec![JSItem::Number { value: 7. }, JSItem::Number { value: 7. }],
+        vec![JSItem::Number { value: 7. }, JSItem::Number { value: 6. }],
+        vec![JSItem::Number { value: 5. }, JSItem::Number { value: 5. }],
+        vec![JSItem::Number { value: 3. }, JSItem::Number { value: 4. }],
+        vec![JSItem::Number { value: 1. }, JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 3. }, JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 3. }, JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 15. }],
+        vec![JSItem::Number { value: 12. }],
+        vec![JSItem::Number { value: 24. }],
+        vec![JSItem::Number { value: 6. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 8. }],
+        vec![JSItem::Number { value: 24. }],
+        vec![JSItem::Number { value: 12. }],
+        vec![JSItem::Number { value: 13. }],
+        vec![JSItem::Number { value: 5. }],
+        vec![JSItem::Number { value: 6. }],
+        vec![JSItem::Number { value: 15. }],
+        vec![JSItem::Number { value: 5. }, JSItem::Number { value: 5. }],
+        vec![JSItem::Number { value: 25. }]
+    ]);
+}
+
+#[test]
+fn test_computed_member() {
+    assert_eq!(interpret_file("js/expressions/computed_member.js").captured_output, vec![
+        vec![JSItem::Number { value: 2. }, JSItem::Number { value: 4. }, JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 20. }, JSItem::Number { value: 4. }, JSItem::Number { value: 4. }],
+        vec![JSItem::Number { value: 2. }, JSItem::Number { value: 1. }, JSItem::Undefined],
+        vec![JSItem::Number { value: 5. }],
+        vec![JSItem::Number { value: 15. }],
+        vec![JSItem::Number { value: 20. }, JSItem::Number { value: 21. }, JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 21. }, JSItem::Number { value: 3. }],
+        vec![JSItem::String { value: "hi".to_string() }, JSItem::String { value: "bob".to_string() }],
+        vec![JSItem::Number { value: 8. }, JSItem::Number { value: 1. }, JSItem::Number { value: 1. }]
+    ]);
+}
+
+
+#[test]
+fn test_comparison() {
+    let t = JSItem::Bool { value: true };
+    let f = JSItem::Bool { value: false };
+    let type_error = vec![JSItem::String { value: "TypeError".to_string() }];
+    assert_eq!(interpret_file("js/expressions/comparison.js").captured_output, vec![
+        vec![t.clone(), t.clone(), t.clone(), t.clone(), f.clone(), t.clone()],
+        vec![t.clone(), f.clone(), f.clone(), t.clone()],
+        vec![t.clone(), f.clone(), t.clone(), f.clone()],
+        vec![t.clone(), f.clone(), t.clone()],
+        vec![t.clone(), f.clone(), t.clone(), t.clone(), f.clone()],
+        vec![t.clone(), f.clone(), t.clone(), t.clone()],
+        vec![t.clone(), f.clone(), f.clone(), t.clone()],
+        vec![f.clone(), f.clone(), f.clone()],
+        vec![t.clone(), f.clone(), t.clone(), f.clone(), t.clone()],
+        vec![f.clone(), f.clone()],
+        type_error.clone(),
+        type_error,
+        vec![JSItem::String { value: "right".to_string() }],
+        vec![JSItem::Number { value: 3. }]
+    ]);
+}
+
+#[test]
+fn test_object_operands() {
+    assert_eq!(interpret_file("js/expressions/object_operands.js").captured_output, vec![
+        vec![JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::String { value: "object".to_string() }],
+        vec![JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 4. }]
+    ]);
+}
+
+#[test]
+fn test_type_of_values() {
+    let int = interpret_file("js/expressions/type_of_values.js");
+    assert_eq!(int.captured_errors, Vec::<String>::new());
+    assert_eq!(int.captured_output, vec![
+        vec![JSItem::String { value: "function".to_string() }, JSItem::String { value: "function".to_string() }],
+        vec![
+            JSItem::String { value: "function".to_string() }, JSItem::String { value: "object".to_string() },
+            JSItem::Bool { value: false }, JSItem::Bool { value: false }
+        ],
+        vec![JSItem::String { value: "function".to_string() }]
+    ]);
+}
+
+#[test]
+fn test_string_operands() {
+    let string = |value: &str| JSItem::String { value: value.to_string() };
+    let number = |value: f64| JSItem::Number { value };
+    assert_eq!(interpret_file("js/expressions/string_operands.js").captured_output, vec![
+        vec![string("ab")],
+        vec![string("xy")],
+        vec![number(12.), number(4.), number(4.), string("12"), string("n1"), number(3.)],
+        vec![string("5!")]
+    ]);
+}
@@ -11,8 +11,8 @@ fn test_simple_for() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -39,8 +39,8 @@ fn test_nested_for() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -82,8 +82,8 @@ fn test_function_nested_for() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let mut out = JSItem::Undefined;
@@ -129,8 +129,8 @@ fn test_function_for_scope() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let mut out = JSItem::Undefined;
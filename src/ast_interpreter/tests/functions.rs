@@ -0,0 +1,30 @@
+use crate::parser::symbols::JSItem;
+use crate::ast_interpreter::tests::{interpret_file, string};
+
+#[test]
+fn test_return() {
+    let int = interpret_file("js/functions/return.js");
+    assert_eq!(int.captured_output, vec![
+        vec![JSItem::Number { value: 1. }],
+        vec![string("finally")],
+        vec![string("try")],
+        vec![JSItem::Number { value: 10. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Undefined],
+        vec![JSItem::Number { value: 2. }]
+    ]);
+}
+
+#[test]
+fn test_return_ends_the_function() {
+    let int = interpret_file("js/functions/return_paths.js");
+    assert_eq!(int.captured_output, vec![
+        vec![string("two"), string("other")],
+        vec![JSItem::Number { value: 2. }],
+        vec![string("one"), string("two"), string("many")],
+        vec![JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 0. }]
+    ]);
+    assert!(int.captured_errors.is_empty());
+}
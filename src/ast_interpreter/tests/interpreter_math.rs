@@ -7,8 +7,8 @@ use crate::parser::symbols::JSItem;
 fn test_simple_add() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("1 + 2 + 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("1 + 2 + 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -19,8 +19,8 @@ fn test_simple_add() {
 fn test_simple_sub() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 - 2"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 - 2")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -31,8 +31,8 @@ fn test_simple_sub() {
 fn test_simple_mul() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 * 2"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 * 2")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -43,8 +43,8 @@ fn test_simple_mul() {
 fn test_simple_div() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 / 2"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 / 2")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -55,8 +55,8 @@ fn test_simple_div() {
 fn test_add_sub() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 + 2 - 1"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 + 2 - 1")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -67,8 +67,8 @@ fn test_add_sub() {
 fn test_add_sub_expression() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("(3 + 2) - 1"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("(3 + 2) - 1")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -79,8 +79,8 @@ fn test_add_sub_expression() {
 fn test_sub_add_expression() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 - (2 + 1)"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 - (2 + 1)")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -91,8 +91,8 @@ fn test_sub_add_expression() {
 fn test_add_mul() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 + 2 * 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 + 2 * 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -103,8 +103,8 @@ fn test_add_mul() {
 fn test_mul_add() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 * 2 + 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 * 2 + 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -115,8 +115,8 @@ fn test_mul_add() {
 fn test_mul_add_expression() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 * (2 + 3)"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 * (2 + 3)")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
@@ -127,8 +127,8 @@ fn test_mul_add_expression() {
 fn test_mul_expression_add() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("(3 * 2) + 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("(3 * 2) + 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
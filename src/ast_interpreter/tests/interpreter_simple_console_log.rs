@@ -7,12 +7,70 @@ use crate::parser::symbols::JSItem;
 fn test_simple_cdl() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("console.log(\"hi\");"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("console.log(\"hi\");")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let out = int.interpret(js_items.pop().unwrap());
     assert_eq!(out, JSItem::Undefined);
     let captured = int.captured_output;
     assert!(captured.eq(&vec![vec![JSItem::String {value: String::from("hi")}]]))
-}
\ No newline at end of file
+}
+#[test]
+fn test_commented_script() {
+    let file = std::fs::read_to_string("js/comments/comments.js");
+
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    let mut int = Interpreter::new();
+    for item in js_items {
+        int.interpret(item);
+    }
+    let captured = int.captured_output;
+    assert!(captured.eq(&vec![vec![JSItem::Number {value: 3.}]]))
+}
+
+#[test]
+fn test_unicode_names() {
+    let file = std::fs::read_to_string("js/variables/unicode_names.js");
+
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    let mut int = Interpreter::new();
+    for item in js_items {
+        int.interpret(item);
+    }
+    let captured = int.captured_output;
+    assert!(captured.eq(&vec![
+        vec![JSItem::Number {value: 10.}],
+        vec![JSItem::Number {value: 1.}]
+    ]))
+}
+
+#[test]
+fn test_regex_literals() {
+    let file = std::fs::read_to_string("js/strings/regex.js");
+
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    let mut int = Interpreter::new();
+    for item in js_items {
+        int.interpret(item);
+    }
+    let captured = int.captured_output;
+    assert_eq!(captured, vec![
+        vec![JSItem::RegExp {pattern: String::from("ab+c"), flags: String::from("gi")}],
+        vec![JSItem::String {value: String::from("ab+c")}],
+        vec![JSItem::Number {value: 1.}],
+        vec![JSItem::String {value: String::from("m")}]
+    ]);
+}
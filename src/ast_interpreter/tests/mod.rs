@@ -3,4 +3,42 @@ mod interpreter_math;
 mod interpreter_simple_console_log;
 mod for_loop;
 mod arrays;
-mod objects;
\ No newline at end of file
+mod objects;
+mod templates;
+mod asi;
+mod assignment;
+mod while_loop;
+mod switch_statement;
+mod exceptions;
+mod expressions;
+mod functions;
+
+
+use std::fs;
+use crate::lexer::lexer::Lexer;
+use crate::parser::parser::Parser;
+use crate::ast_interpreter::interpreter::Interpreter;
+use crate::parser::symbols::JSItem;
+
+/// Runs a script, the interpreter is handed back for what it logged and threw.
+#[allow(dead_code)]
+pub(crate) fn interpret_file(file_name: &str) -> Interpreter {
+    let file = fs::read_to_string(file_name);
+
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    let mut int = Interpreter::new();
+    for item in js_items {
+        int.interpret(item);
+    }
+    int
+}
+
+/// A string value, for comparing against what a script logged.
+#[allow(dead_code)]
+pub(crate) fn string(value: &str) -> JSItem {
+    JSItem::String { value: value.to_string() }
+}
@@ -1,9 +1,11 @@
 use crate::parser::parser::Parser;
 use std::fs;
 use crate::lexer::lexer::Lexer;
+use crate::lexer::span::Span;
 use crate::ast_interpreter::interpreter::Interpreter;
 use crate::parser::symbols::{JSItem, Expression};
-use std::collections::HashMap;
+use crate::parser::property_map::PropertyMap;
+use crate::ast_interpreter::tests::interpret_file;
 
 #[test]
 fn test_object_new_property() {
@@ -11,8 +13,8 @@ fn test_object_new_property() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let mut out = JSItem::Undefined;
@@ -23,16 +25,18 @@ fn test_object_new_property() {
     let captured = int.captured_output;
     assert_eq!(captured.len(), 1);
 
-    let mut properties = HashMap::new();
+    let mut properties = PropertyMap::new();
     properties.insert("a".to_string(), JSItem::Ex {
-        expression: Box::from(Expression::Number {value: 1.0})
+        expression: Box::from(Expression::Number {value: 1.0, span: Span::default()}),
+        span: Span::default()
+    });
+    properties.insert("b".to_string(), JSItem::Ex {
+        expression: Box::from(Expression::Number {value: 2.0, span: Span::default()}),
+        span: Span::default()
     });
     properties.insert("d".to_string(), JSItem::Variable {
         mutable: false,
-        value: Expression::String {value: "hello world".to_string()}
-    });
-    properties.insert("b".to_string(), JSItem::Ex {
-        expression: Box::from(Expression::Number {value: 2.0})
+        value: Expression::String {value: "hello world".to_string(), span: Span::default()}
     });
 
     assert!(captured.eq(&vec![
@@ -45,8 +49,8 @@ fn test_object_object_call_property() {
     let file = fs::read_to_string("js/objects/object_object_call_property.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let mut out = JSItem::Null;
@@ -62,8 +66,8 @@ fn test_log_object_object_call_property() {
     let file = fs::read_to_string("js/objects/log_object_object_call_property.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     let mut int = Interpreter::new();
     let mut out = JSItem::Undefined;
@@ -84,8 +88,8 @@ fn test_same_variable_different_scope() {
     let file = fs::read_to_string("js/objects/same_variable_different_scope.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 2);
 
@@ -118,8 +122,8 @@ fn test_edit_object_and_log_from_another_object() {
     let file = fs::read_to_string("js/objects/edit_object_and_log_from_another_object.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 3);
 
@@ -145,4 +149,26 @@ fn test_edit_object_and_log_from_another_object() {
         vec![JSItem::Number {value: 16.0}],
         vec![JSItem::Number {value: 18.0}]
     ]))
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_object_keys() {
+    let captured = interpret_file("js/objects/object_keys.js").captured_output;
+
+    // integer keys ascending, then the others in the order they were added
+    let keys = vec!["1", "2", "b", "a", "c"].into_iter()
+        .map(|key| JSItem::String { value: key.to_string() })
+        .collect();
+    assert_eq!(captured, vec![vec![JSItem::Array { items: keys, properties: PropertyMap::new() }]]);
+}
+
+#[test]
+fn test_computed_method_call() {
+    let int = interpret_file("js/objects/computed_method_call.js");
+    assert_eq!(int.captured_errors, Vec::<String>::new());
+    assert_eq!(int.captured_output, vec![
+        vec![JSItem::String { value: "o".to_string() }, JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::String { value: "o".to_string() }, JSItem::Number { value: 2. }]
+    ]);
+}
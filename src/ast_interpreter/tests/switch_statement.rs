@@ -0,0 +1,15 @@
+use crate::parser::symbols::JSItem;
+use crate::ast_interpreter::tests::interpret_file;
+
+#[test]
+fn test_switch() {
+    assert_eq!(interpret_file("js/if_while_for/switch1.js").captured_output, vec![
+        vec![JSItem::String { value: "two".to_string() }],
+        vec![JSItem::String { value: "three".to_string() }],
+        vec![JSItem::String { value: "b".to_string() }],
+        vec![JSItem::String { value: "none".to_string() }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 2. }]
+    ]);
+}
@@ -0,0 +1,30 @@
+use crate::parser::symbols::JSItem;
+use crate::ast_interpreter::tests::interpret_file;
+
+#[test]
+fn test_template_interpolation() {
+    assert_eq!(interpret_file("js/templates/simple.js").captured_output, vec![
+        vec![JSItem::String {value: String::from("hello world!")}]
+    ]);
+}
+
+#[test]
+fn test_template_expressions() {
+    assert_eq!(interpret_file("js/templates/expressions.js").captured_output, vec![
+        vec![JSItem::String {value: String::from("2 + 3 = 5")}]
+    ]);
+}
+
+#[test]
+fn test_nested_template() {
+    assert_eq!(interpret_file("js/templates/nested.js").captured_output, vec![
+        vec![JSItem::String {value: String::from("out and in done")}]
+    ]);
+}
+
+#[test]
+fn test_multi_line_template() {
+    assert_eq!(interpret_file("js/templates/multi_line.js").captured_output, vec![
+        vec![JSItem::String {value: String::from("line one\nline two")}]
+    ]);
+}
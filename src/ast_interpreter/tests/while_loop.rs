@@ -0,0 +1,62 @@
+use crate::parser::symbols::JSItem;
+use crate::ast_interpreter::tests::interpret_file;
+
+#[test]
+fn test_while() {
+    assert_eq!(interpret_file("js/if_while_for/while1.js").captured_output, vec![
+        vec![JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 0. }]
+    ]);
+}
+
+#[test]
+fn test_do_while() {
+    assert_eq!(interpret_file("js/if_while_for/do_while1.js").captured_output, vec![
+        vec![JSItem::Number { value: 10. }],
+        vec![JSItem::Number { value: 4. }]
+    ]);
+}
+
+#[test]
+fn test_break_continue() {
+    assert_eq!(interpret_file("js/if_while_for/break_continue.js").captured_output, vec![
+        vec![JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 0. }, JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 1. }, JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 1. }, JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::String { value: "in".to_string() }],
+        vec![JSItem::String { value: "out".to_string() }]
+    ]);
+}
+
+#[test]
+fn test_braceless_if() {
+    assert_eq!(interpret_file("js/if_while_for/braceless_if.js").captured_output, vec![
+        vec![JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::String { value: "two".to_string() }],
+        vec![JSItem::String { value: "done".to_string() }]
+    ]);
+}
+
+#[test]
+fn test_loop_comparisons() {
+    assert_eq!(interpret_file("js/if_while_for/loop_comparisons.js").captured_output, vec![
+        vec![JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 1. }]
+    ]);
+}
@@ -1,11 +1,107 @@
-use crate::parser::symbols::{JSItem, Expression, Operator, Statement};
+use crate::parser::symbols::{JSItem, Expression, Operator, Statement, AssignOp, SwitchCase, CatchClause, UnaryOperator};
 use crate::compiler::op_codes::Op;
 use crate::lexer::js_token::Tok;
-use std::collections::HashMap;
+use crate::parser::property_map::PropertyMap;
+use crate::lexer::span::Span;
 
+// where a finally block keeps the exception it has to throw again once it is done, not a name
+// a script can use
+const PENDING_EXCEPTION: &str = "%exception";
+
+
+/// A statement `break` or `continue` can leave, with the jumps still waiting for its address.
+struct JumpTarget {
+    labels: Vec<String>,
+    // loops take a plain break and continue, a switch only a break, a labelled block only a
+    // break naming it
+    breakable: bool,
+    continuable: bool,
+    // ran SetupLoop, leaving it from inside has to pop its scope
+    block: bool,
+    // inside a try, leaving it has to drop the handler and run the finally block
+    handler: bool,
+    finalizer: Option<Vec<JSItem>>,
+    breaks: Vec<usize>,
+    continues: Vec<usize>
+}
+
+impl JumpTarget {
+    fn new(labels: Vec<String>) -> JumpTarget {
+        JumpTarget {
+            labels,
+            breakable: false,
+            continuable: false,
+            block: false,
+            handler: false,
+            finalizer: None,
+            breaks: vec![],
+            continues: vec![]
+        }
+    }
+}
 
 pub(crate) struct Compiler {
-    pub(crate) bc_ins: Vec<Op>
+    pub(crate) bc_ins: Vec<Op>,
+    targets: Vec<JumpTarget>,
+    // labels in front of the loop about to be compiled
+    labels: Vec<String>,
+    // the last item compiled left its value on the stack as the program's result so far
+    has_result: bool,
+    // the statement each run of instructions was compiled from, by the instruction it starts at
+    pub(crate) locations: Vec<(usize, Span)>
+}
+
+/// The instruction for an operator that takes both operands off the stack.
+fn binary_op(op: &Operator) -> Op {
+    match op {
+        Operator::Add => Op::Add,
+        Operator::Sub => Op::Sub,
+        Operator::Mult => Op::Mul,
+        Operator::Div => Op::Div,
+        Operator::Mod => Op::Mod,
+        Operator::Exp => Op::Exp,
+        Operator::LShift => Op::LShift,
+        Operator::RShift => Op::RShift,
+        Operator::URShift => Op::URShift,
+        Operator::BitAnd => Op::BitAnd,
+        Operator::BitOr => Op::BitOr,
+        Operator::BitXor => Op::BitXor,
+        Operator::Less => Op::Less,
+        Operator::Greater => Op::Greater,
+        Operator::LessEqual => Op::LessEqual,
+        Operator::GreaterEqual => Op::GreaterEqual,
+        Operator::EqEq => Op::EqEq,
+        Operator::EqEqEq => Op::EqEqEq,
+        Operator::NotEq => Op::NotEq,
+        Operator::NotEqEq => Op::NotEqEq,
+        Operator::In => Op::In,
+        Operator::InstanceOf => Op::InstanceOf,
+        // `&&`, `||` and `??` are compiled as jumps, the parser never makes the other two
+        Operator::And | Operator::Or | Operator::Nullish | Operator::FloorDiv | Operator::None => {
+            unreachable!("{:?} has no instruction", op)
+        }
+    }
+}
+
+/// Whether running `item` leaves a value on the stack, statements don't.
+fn leaves_value(item: &JSItem) -> bool {
+    match item {
+        JSItem::Ex { expression, .. } => match **expression {
+            Expression::None => false,
+            _ => true
+        },
+        JSItem::Object { .. } => true,
+        _ => false
+    }
+}
+
+/// The jump `&&`, `||` or `??` takes to `to` when its left operand decides it.
+fn short_circuit(op: &Operator, to: usize) -> Op {
+    match op {
+        Operator::And => Op::JumpIfFalseOrPop { to },
+        Operator::Or => Op::JumpIfTrueOrPop { to },
+        _ => Op::JumpIfNotNullishOrPop { to }
+    }
 }
 
 
@@ -13,75 +109,614 @@ impl Compiler {
 
     pub(crate) fn new() -> Compiler {
         Compiler {
-            bc_ins: Vec::default()
+            bc_ins: Vec::default(),
+            targets: vec![],
+            labels: vec![],
+            has_result: false,
+            locations: vec![]
         }
     }
 
-    fn visit_binop(&mut self, a: Expression, op: Operator, b: Expression) {
-        let top = match op {
-            Operator::Add => Op::Add,
-            Operator::Sub => Op::Sub,
-            Operator::Mult => Op::Mul,
-            Operator::Div => Op::Div,
-            Operator::Less => Op::Less,
-            Operator::Greater => Op::Greater,
-            Operator::And => Op::And,
-            Operator::EqEq => Op::EqEq,
-            Operator::EqEqEq => Op::EqEqEq,
-            _ => Op::Add
+    /// Target for a loop or switch, which run in their own scope.
+    fn push_block_target(&mut self, continuable: bool) {
+        let labels = std::mem::take(&mut self.labels);
+        self.targets.push(JumpTarget { breakable: true, continuable, block: true, ..JumpTarget::new(labels) });
+    }
+
+    /// Closes the innermost target, pointing its breaks and continues at their addresses.
+    fn pop_target(&mut self, break_to: usize, continue_to: usize) {
+        let target = self.targets.pop().unwrap();
+        for i in target.breaks {
+            self.bc_ins[i] = Op::JumpAbsolute { to: break_to };
+        }
+        for i in target.continues {
+            self.bc_ins[i] = Op::JumpAbsolute { to: continue_to };
+        }
+    }
+
+    /// A `break` or `continue` pops the scope of every loop it jumps out of and runs the
+    /// finally block of every try, then jumps to an address filled in once its target is
+    /// compiled.
+    fn visit_jump(&mut self, label: Option<String>, is_break: bool) {
+        let found = self.targets.iter().rposition(|target| match &label {
+            Some(label) => target.labels.contains(label),
+            None if is_break => target.breakable,
+            None => target.continuable
+        });
+        let index = match found {
+            Some(index) => index,
+            None => return
+        };
+        for i in (index + 1..self.targets.len()).rev() {
+            if self.targets[i].block {
+                self.bc_ins.push(Op::PopBlock);
+            }
+            if self.targets[i].handler {
+                self.bc_ins.push(Op::PopTry);
+            }
+            if let Some(finalizer) = self.targets[i].finalizer.clone() {
+                // the finally block runs outside the statements being left
+                let inner = self.targets.split_off(i);
+                for item in finalizer {
+                    self.visit_statement(item);
+                }
+                self.targets.extend(inner);
+            }
+        }
+        let jump_i = self.bc_ins.len();
+        self.bc_ins.push(Op::JumpAbsolute { to: 0 });
+        if is_break {
+            self.targets[index].breaks.push(jump_i);
+        } else {
+            self.targets[index].continues.push(jump_i);
+        }
+    }
+
+    /// The value is worked out before the finally block of every try being left runs, it
+    /// stays on the stack under them. Return drops the handlers and scopes itself.
+    fn visit_return(&mut self, value: Option<Box<JSItem>>) {
+        match value {
+            Some(value) => self.visit(*value),
+            None => self.bc_ins.push(Op::LoadUndefined)
+        }
+        for i in (0..self.targets.len()).rev() {
+            if self.targets[i].handler {
+                self.bc_ins.push(Op::PopTry);
+            }
+            if let Some(finalizer) = self.targets[i].finalizer.clone() {
+                let inner = self.targets.split_off(i);
+                for item in finalizer {
+                    self.visit_statement(item);
+                }
+                self.targets.extend(inner);
+            }
+        }
+        self.bc_ins.push(Op::Return);
+    }
+
+    /// The discriminant stays on the stack while each case test is compared with it, a match
+    /// pops it and jumps into the case bodies, which run on into each other.
+    fn visit_switch(&mut self, discriminant: JSItem, cases: Vec<SwitchCase>) {
+        self.push_block_target(false);
+        self.bc_ins.push(Op::SetupLoop);
+        self.visit(discriminant);
+
+        let mut case_jumps = vec![];
+        for (i, case) in cases.iter().enumerate() {
+            if let Some(test) = &case.test {
+                self.bc_ins.push(Op::DupTop);
+                self.visit(test.clone());
+                self.bc_ins.push(Op::EqEqEq);
+                let pop_jump_i = self.bc_ins.len();
+                self.bc_ins.push(Op::PopJumpIfFalse { to: 0 });
+                self.bc_ins.push(Op::PopTop);
+                case_jumps.push((i, self.bc_ins.len()));
+                self.bc_ins.push(Op::JumpAbsolute { to: 0 });
+                self.bc_ins[pop_jump_i] = Op::PopJumpIfFalse { to: self.bc_ins.len() };
+            }
+        }
+        self.bc_ins.push(Op::PopTop);
+        let default = cases.iter().position(|case| case.test.is_none());
+        let default_jump_i = self.bc_ins.len();
+        self.bc_ins.push(Op::JumpAbsolute { to: 0 });
+
+        let mut starts = vec![];
+        for case in cases {
+            starts.push(self.bc_ins.len());
+            for item in case.consequent {
+                self.visit_statement(item);
+            }
+        }
+        let end = self.bc_ins.len();
+        for (i, jump_i) in case_jumps {
+            self.bc_ins[jump_i] = Op::JumpAbsolute { to: starts[i] };
+        }
+        self.bc_ins[default_jump_i] = Op::JumpAbsolute { to: default.map(|i| starts[i]).unwrap_or(end) };
+        self.pop_target(end, end);
+
+        self.bc_ins.push(Op::PopBlock);
+    }
+
+    /// The try block runs under a handler that jumps to the catch block, which gets the thrown
+    /// value on the stack. With a finally block the catch block has a handler too, and that
+    /// one runs the finally block before throwing again. The finally block is also compiled
+    /// after the normal way out and before every break or continue that leaves the try.
+    fn visit_try(&mut self, block: Vec<JSItem>, handler: Option<CatchClause>, finalizer: Option<Vec<JSItem>>) {
+        let mut exits = vec![];
+
+        let setup_i = self.bc_ins.len();
+        self.bc_ins.push(Op::SetupTry { handler: 0 });
+        self.targets.push(JumpTarget { handler: true, finalizer: finalizer.clone(), ..JumpTarget::new(vec![]) });
+        for item in block {
+            self.visit_statement(item);
+        }
+        self.targets.pop();
+        self.bc_ins.push(Op::PopTry);
+        exits.push(self.bc_ins.len());
+        self.bc_ins.push(Op::JumpAbsolute { to: 0 });
+        self.bc_ins[setup_i] = Op::SetupTry { handler: self.bc_ins.len() };
+
+        if let Some(CatchClause { param, body }) = handler {
+            let catch_setup_i = self.bc_ins.len();
+            if finalizer.is_some() {
+                self.bc_ins.push(Op::SetupTry { handler: 0 });
+            }
+            self.bc_ins.push(Op::SetupLoop);
+            match param {
+                Some(name) => self.bc_ins.push(Op::Store { name }),
+                None => self.bc_ins.push(Op::PopTop)
+            }
+            self.targets.push(JumpTarget {
+                block: true,
+                handler: finalizer.is_some(),
+                finalizer: finalizer.clone(),
+                ..JumpTarget::new(vec![])
+            });
+            for item in body {
+                self.visit_statement(item);
+            }
+            self.targets.pop();
+            self.bc_ins.push(Op::PopBlock);
+            if finalizer.is_some() {
+                self.bc_ins.push(Op::PopTry);
+            }
+            exits.push(self.bc_ins.len());
+            self.bc_ins.push(Op::JumpAbsolute { to: 0 });
+            if finalizer.is_some() {
+                self.bc_ins[catch_setup_i] = Op::SetupTry { handler: self.bc_ins.len() };
+            }
+        }
+
+        if let Some(finalizer) = &finalizer {
+            self.bc_ins.push(Op::SetupLoop);
+            self.bc_ins.push(Op::Store { name: PENDING_EXCEPTION.to_string() });
+            self.targets.push(JumpTarget { block: true, ..JumpTarget::new(vec![]) });
+            for item in finalizer.clone() {
+                self.visit_statement(item);
+            }
+            self.targets.pop();
+            self.bc_ins.push(Op::Load { name: PENDING_EXCEPTION.to_string() });
+            self.bc_ins.push(Op::Throw);
+        }
+
+        let end = self.bc_ins.len();
+        for i in exits {
+            self.bc_ins[i] = Op::JumpAbsolute { to: end };
+        }
+        if let Some(finalizer) = finalizer {
+            for item in finalizer {
+                self.visit_statement(item);
+            }
+        }
+    }
+
+    fn visit_labelled(&mut self, label: String, body: Vec<JSItem>) {
+        self.labels.push(label);
+        let is_loop = body.len() == 1 && match &body[0] {
+            JSItem::St { statement, .. } => match **statement {
+                Statement::ForStatement { .. } | Statement::While { .. } | Statement::DoWhile { .. }
+                | Statement::Switch { .. } | Statement::Labelled { .. } => true,
+                _ => false
+            },
+            _ => false
         };
+        if is_loop {
+            for item in body {
+                self.visit_statement(item);
+            }
+            return;
+        }
+        let labels = std::mem::take(&mut self.labels);
+        self.targets.push(JumpTarget::new(labels));
+        for item in body {
+            self.visit_statement(item);
+        }
+        let end = self.bc_ins.len();
+        self.pop_target(end, end);
+    }
+
+    fn visit_binop(&mut self, a: Expression, op: Operator, b: Expression) {
+        if let Operator::And | Operator::Or | Operator::Nullish = op {
+            return self.visit_logical(a, op, b);
+        }
         self.visit_ex(a);
         self.visit_ex(b);
-        self.bc_ins.push(top);
+        self.bc_ins.push(binary_op(&op));
     }
 
     fn visit_ex(&mut self, ex: Expression) {
         match ex {
-            Expression::UpdateExpression { expression } => {
-                self.visit_ex(*expression);
-                self.bc_ins.push(Op::InplaceAdd);
+            Expression::UpdateExpression { operator, prefix, expression, .. } => {
+                self.visit_update(operator, prefix, *expression)
             }
-            Expression::Binop { a, op, b } => {
+            Expression::Binop { a, op, b, .. } => {
                 self.visit_binop(*a, op, *b)
             }
-            Expression::Number { value } => {
+            Expression::Unary { op, argument, .. } => {
+                self.visit_unary(op, *argument)
+            }
+            Expression::Conditional { test, consequent, alternate, .. } => {
+                self.visit_conditional(*test, *consequent, *alternate)
+            }
+            Expression::Assign { operator, left, right, .. } => {
+                self.visit_assign(operator, *left, *right)
+            }
+            Expression::Number { value, .. } => {
                 self.bc_ins.push(Op::LoadNumConst {value})
             }
-            Expression::Literal { value } => {
+            Expression::True { .. } => {
+                self.bc_ins.push(Op::LoadBool { value: true })
+            }
+            Expression::False { .. } => {
+                self.bc_ins.push(Op::LoadBool { value: false })
+            }
+            Expression::Null { .. } => {
+                self.bc_ins.push(Op::LoadNull)
+            }
+            Expression::Literal { value, .. } => {
                 self.bc_ins.push(Op::LoadStrConst {value})
             }
-            Expression::Identifier { name } => {
+            Expression::Identifier { name, .. } => {
                 self.bc_ins.push(Op::Load { name })
             }
-            Expression::SubExpression { expression } => {
+            Expression::SubExpression { expression, .. } => {
                 self.visit_ex(*expression)
             }
-            Expression::CallExpression { callee, arguments } => {
-                self.visit_ex(*callee);
-                let arg_len = arguments.len().clone();
-                for item in arguments {
-                    self.visit(item);
+            Expression::CallExpression { callee, arguments, .. } => match *callee {
+                Expression::MemberExpression { object, property, .. } => {
+                    self.visit_method_call(*object, *property, false, arguments)
                 }
-                self.bc_ins.push(Op::Call { args: arg_len as i8 });
+                Expression::ComputedMemberExpression { object, property, .. } => {
+                    self.visit_method_call(*object, *property, true, arguments)
+                }
+                callee => {
+                    self.visit_ex(callee);
+                    let arg_len = arguments.len().clone();
+                    for item in arguments {
+                        self.visit(item);
+                    }
+                    self.bc_ins.push(Op::Call { args: arg_len as i8 });
+                }
+            }
+            Expression::FuncEx { params, body, .. } => {
+                self.visit_function(params, body, |start, end, params| Op::LoadFunc { start, end, params })
             }
-            Expression::MemberExpression { object, property } => {
+            Expression::MemberExpression { object, property, .. } => {
                 self.visit_ex(*object);
                 let prop = match *property {
-                    Expression::Identifier { name } => name,
+                    Expression::Identifier { name, .. } => name,
                     _ => "".to_string()
                 };
                 self.bc_ins.push(Op::LoadProp {name: prop})
             }
-            Expression::String {value} => {
+            Expression::ComputedMemberExpression { object, property, .. } => {
+                self.visit_ex(*object);
+                self.visit_ex(*property);
+                self.bc_ins.push(Op::LoadIndex)
+            }
+            Expression::Object { mutable, properties, .. } => {
+                self.visit_object(mutable, properties)
+            }
+            Expression::ArrayExpression { items, properties: _, .. } => {
+                let count = items.len();
+                for item in items {
+                    self.visit(item);
+                }
+                self.bc_ins.push(Op::BuildArray { count })
+            }
+            Expression::String {value, ..} => {
                 self.bc_ins.push(Op::LoadStrConst {value});
             }
+            Expression::TemplateLiteral { cooked, raw: _, expressions, .. } => {
+                self.visit_template_literal(cooked, expressions)
+            }
+            Expression::RegExp { pattern, flags, .. } => {
+                self.bc_ins.push(Op::LoadRegExp { pattern, flags });
+            }
+            _ => {}
+        }
+    }
+
+    /// Compiles a function's body behind the op that declares it, which is only made once the
+    /// body's end is known.
+    fn visit_function(&mut self, params: Vec<Tok>, body: Vec<JSItem>, declare: impl FnOnce(usize, usize, Vec<String>) -> Op) {
+        let mut prams = vec![];
+        for p in params {
+            match p {
+                Tok::Name { name } => prams.push(name),
+                Tok::String { value } => prams.push(value),
+                _ => {}
+            }
+        }
+
+        let func_start = self.bc_ins.len();
+        self.bc_ins.push(Op::LoadUndefined);
+
+        let targets = std::mem::take(&mut self.targets);
+        for item in body {
+            self.visit_statement(item);
+        }
+        self.targets = targets;
+
+        // running off the end returns undefined, Return also drops the function's scope
+        self.bc_ins.push(Op::LoadUndefined);
+        self.bc_ins.push(Op::Return);
+
+        self.bc_ins[func_start] = declare(func_start + 1, self.bc_ins.len() - 1, prams);
+    }
+
+    /// `obj.f(..)` and `obj[k](..)` keep the object under the function to call it as `this`.
+    fn visit_method_call(&mut self, object: Expression, property: Expression, computed: bool, arguments: Vec<JSItem>) {
+        self.visit_ex(object);
+        self.bc_ins.push(Op::DupTop);
+        if computed {
+            self.visit_ex(property);
+            self.bc_ins.push(Op::LoadIndex);
+        } else if let Expression::Identifier { name, .. } = property {
+            self.bc_ins.push(Op::LoadProp { name });
+        }
+        let args = arguments.len() as i8;
+        for item in arguments {
+            self.visit(item);
+        }
+        self.bc_ins.push(Op::CallMethod { args });
+    }
+
+    /// `typeof name` and `delete obj.name` work on the reference rather than its value.
+    fn visit_unary(&mut self, op: UnaryOperator, argument: Expression) {
+        match (op, argument) {
+            (UnaryOperator::TypeOf, Expression::Identifier { name, .. }) => {
+                self.bc_ins.push(Op::TypeOfName { name });
+            }
+            (UnaryOperator::Delete, Expression::MemberExpression { object, property, .. }) => {
+                let name = match *property {
+                    Expression::Identifier { name, .. } => name,
+                    _ => "".to_string()
+                };
+                self.visit_ex(*object);
+                self.bc_ins.push(Op::DeleteProp { name });
+            }
+            (UnaryOperator::Delete, Expression::ComputedMemberExpression { object, property, .. }) => {
+                self.visit_ex(*object);
+                self.visit_ex(*property);
+                self.bc_ins.push(Op::DeleteIndex);
+            }
+            (UnaryOperator::Delete, Expression::Identifier { .. }) => {
+                self.bc_ins.push(Op::LoadBool { value: false });
+            }
+            (op, argument) => {
+                self.visit_ex(argument);
+                let top = match op {
+                    UnaryOperator::Not => Op::UnaryNot,
+                    UnaryOperator::Minus => Op::UnaryNegative,
+                    UnaryOperator::Plus => Op::UnaryPositive,
+                    UnaryOperator::BitNot => Op::UnaryInvert,
+                    UnaryOperator::TypeOf => Op::TypeOf,
+                    UnaryOperator::Void => {
+                        self.bc_ins.push(Op::PopTop);
+                        Op::LoadUndefined
+                    }
+                    UnaryOperator::Delete => {
+                        self.bc_ins.push(Op::PopTop);
+                        Op::LoadBool { value: true }
+                    }
+                };
+                self.bc_ins.push(top);
+            }
+        }
+    }
+
+    fn visit_conditional(&mut self, test: Expression, consequent: Expression, alternate: Expression) {
+        self.visit_ex(test);
+        let jump_to_alternate = self.bc_ins.len();
+        self.bc_ins.push(Op::PopJumpIfFalse { to: 0 });
+        self.visit_ex(consequent);
+        let jump_to_end = self.bc_ins.len();
+        self.bc_ins.push(Op::JumpAbsolute { to: 0 });
+        self.bc_ins[jump_to_alternate] = Op::PopJumpIfFalse { to: self.bc_ins.len() };
+        self.visit_ex(alternate);
+        self.bc_ins[jump_to_end] = Op::JumpAbsolute { to: self.bc_ins.len() };
+    }
+
+    /// The left operand stays on the stack as the result when it decides the operator,
+    /// otherwise it is dropped and the right operand is run in its place.
+    fn visit_logical(&mut self, a: Expression, op: Operator, b: Expression) {
+        self.visit_ex(a);
+        let jump = self.bc_ins.len();
+        self.bc_ins.push(short_circuit(&op, 0));
+        self.visit_ex(b);
+        self.bc_ins[jump] = short_circuit(&op, self.bc_ins.len());
+    }
+
+    /// Stores the value then loads it back, an assignment is also an expression. `&&=`, `||=`
+    /// and `??=` load the current value first and skip the store when it decides the result,
+    /// the other compound operators combine the current value with the right hand side.
+    fn visit_assign(&mut self, operator: Operator, left: Expression, right: Expression) {
+        let logical = match operator {
+            Operator::And | Operator::Or | Operator::Nullish => true,
+            _ => false
+        };
+        let compound = !logical && operator != Operator::None;
+        match left {
+            Expression::Identifier { name, .. } => {
+                let jump = self.bc_ins.len();
+                if logical || compound {
+                    self.bc_ins.push(Op::Load { name: name.clone() });
+                }
+                if logical {
+                    self.bc_ins.push(short_circuit(&operator, 0));
+                }
+                self.visit_ex(right);
+                if compound {
+                    self.bc_ins.push(binary_op(&operator));
+                }
+                self.bc_ins.push(Op::Assign { name: name.clone() });
+                self.bc_ins.push(Op::Load { name });
+                if logical {
+                    self.bc_ins[jump + 1] = short_circuit(&operator, self.bc_ins.len());
+                }
+            }
+            Expression::MemberExpression { object, property, .. } => {
+                let name = match *property {
+                    Expression::Identifier { name, .. } => name,
+                    _ => "".to_string()
+                };
+                self.visit_ex(*object);
+                let jump = self.bc_ins.len() + 2;
+                if logical || compound {
+                    self.bc_ins.push(Op::DupTop);
+                    self.bc_ins.push(Op::LoadProp { name: name.clone() });
+                }
+                if logical {
+                    self.bc_ins.push(short_circuit(&operator, 0));
+                }
+                self.visit_ex(right);
+                if compound {
+                    self.bc_ins.push(binary_op(&operator));
+                }
+                self.bc_ins.push(Op::StoreProp { name: name.clone() });
+                self.bc_ins.push(Op::LoadProp { name });
+                if logical {
+                    // the current value decided it, the object under it goes
+                    let end = self.bc_ins.len() + 3;
+                    self.bc_ins.push(Op::JumpAbsolute { to: end });
+                    self.bc_ins[jump] = short_circuit(&operator, self.bc_ins.len());
+                    self.bc_ins.push(Op::RotTwo);
+                    self.bc_ins.push(Op::PopTop);
+                }
+            }
+            // StoreIndex leaves the value, there is no key in it to load the property back by
+            Expression::ComputedMemberExpression { object, property, .. } => {
+                self.visit_ex(*object);
+                self.visit_ex(*property);
+                let jump = self.bc_ins.len() + 2;
+                if logical || compound {
+                    self.bc_ins.push(Op::DupTopTwo);
+                    self.bc_ins.push(Op::LoadIndex);
+                }
+                if logical {
+                    self.bc_ins.push(short_circuit(&operator, 0));
+                }
+                self.visit_ex(right);
+                if compound {
+                    self.bc_ins.push(binary_op(&operator));
+                }
+                self.bc_ins.push(Op::StoreIndex);
+                if logical {
+                    // the current value decided it, the object and key under it go
+                    let end = self.bc_ins.len() + 4;
+                    self.bc_ins.push(Op::JumpAbsolute { to: end });
+                    self.bc_ins[jump] = short_circuit(&operator, self.bc_ins.len());
+                    self.bc_ins.push(Op::RotThree);
+                    self.bc_ins.push(Op::PopTop);
+                    self.bc_ins.push(Op::PopTop);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `++` and `--` convert the current value to a number and store it plus or minus one.
+    /// Written after the operand the old number is kept under the store as the result,
+    /// written before it the new value is loaded back like an assignment.
+    fn visit_update(&mut self, operator: Operator, prefix: bool, expression: Expression) {
+        match expression {
+            Expression::Identifier { name, .. } => {
+                self.bc_ins.push(Op::Load { name: name.clone() });
+                self.bc_ins.push(Op::UnaryPositive);
+                if !prefix {
+                    self.bc_ins.push(Op::DupTop);
+                }
+                self.bc_ins.push(Op::LoadNumConst { value: 1. });
+                self.bc_ins.push(binary_op(&operator));
+                self.bc_ins.push(Op::Assign { name: name.clone() });
+                if prefix {
+                    self.bc_ins.push(Op::Load { name });
+                }
+            }
+            Expression::MemberExpression { object, property, .. } => {
+                let name = match *property {
+                    Expression::Identifier { name, .. } => name,
+                    _ => "".to_string()
+                };
+                self.visit_ex(*object);
+                self.bc_ins.push(Op::DupTop);
+                self.bc_ins.push(Op::LoadProp { name: name.clone() });
+                self.bc_ins.push(Op::UnaryPositive);
+                if !prefix {
+                    // the old value goes under the object, which is popped once stored to
+                    self.bc_ins.push(Op::DupTop);
+                    self.bc_ins.push(Op::RotThree);
+                }
+                self.bc_ins.push(Op::LoadNumConst { value: 1. });
+                self.bc_ins.push(binary_op(&operator));
+                self.bc_ins.push(Op::StoreProp { name: name.clone() });
+                if prefix {
+                    self.bc_ins.push(Op::LoadProp { name });
+                } else {
+                    self.bc_ins.push(Op::PopTop);
+                }
+            }
+            Expression::ComputedMemberExpression { object, property, .. } => {
+                self.visit_ex(*object);
+                self.visit_ex(*property);
+                self.bc_ins.push(Op::DupTopTwo);
+                self.bc_ins.push(Op::LoadIndex);
+                self.bc_ins.push(Op::UnaryPositive);
+                if !prefix {
+                    self.bc_ins.push(Op::DupTop);
+                    self.bc_ins.push(Op::RotFour);
+                }
+                self.bc_ins.push(Op::LoadNumConst { value: 1. });
+                self.bc_ins.push(binary_op(&operator));
+                self.bc_ins.push(Op::StoreIndex);
+                if !prefix {
+                    self.bc_ins.push(Op::PopTop);
+                }
+            }
             _ => {}
         }
     }
 
+    fn visit_template_literal(&mut self, cooked: Vec<String>, expressions: Vec<JSItem>) {
+        let mut count = 0;
+        let mut expressions = expressions.into_iter();
+        for chunk in cooked {
+            if !chunk.is_empty() {
+                self.bc_ins.push(Op::LoadStrConst { value: chunk });
+                count += 1;
+            }
+            if let Some(expression) = expressions.next() {
+                self.visit(expression);
+                count += 1;
+            }
+        }
+        self.bc_ins.push(Op::BuildString { count });
+    }
+
     fn visit_st(&mut self, st: Statement) {
         match st {
-            Statement::AssignFunction { mutable, function } => {
+            Statement::AssignFunction { mutable, function } | Statement::AssignArrowFunction { mutable, function } => {
                 let func_start = self.bc_ins.len();
                 self.visit_st(*function);
 
@@ -99,41 +734,15 @@ impl Compiler {
                 }
             }
             Statement::FunctionDef { name, params, body } => {
-                let mut prams = vec![];
-                for p in params {
-                    match p {
-                        Tok::Name { name } => prams.push(name),
-                        Tok::String { value } => prams.push(value),
-                        _ => {}
-                    }
-                }
-
-                let func_start = self.bc_ins.len();
-
-                self.bc_ins.push(Op::DeclareFunc {
-                    mutable: true,
-                    name: name.clone(),
-                    start: 0,
-                    end: 0,
-                    params: vec![]
-                });
-
-                for item in body {
-                    self.visit(item);
-                }
-
-                self.bc_ins.push(Op::PopBlock);
-                self.bc_ins.push(Op::Return);
-
-                self.bc_ins[func_start] = Op::DeclareFunc {
-                    start: func_start + 1,
-                    end: self.bc_ins.len() - 1,
+                self.visit_function(params, body, |start, end, params| Op::DeclareFunc {
+                    start,
+                    end,
                     mutable: true,
-                    params: prams,
+                    params,
                     name
-                }
+                })
             }
-            Statement::AssignmentExpression { operator:_, left, right } => {
+            Statement::AssignmentExpression { operator, left, right } => {
                 self.visit(left);
                 let op = self.bc_ins.pop().unwrap();
                 self.visit(right);
@@ -143,6 +752,13 @@ impl Compiler {
                         self.bc_ins.push(Op::StoreProp { name });
                         self.bc_ins.push(Op::PopTop)
                     }
+                    Op::LoadIndex => {
+                        self.bc_ins.push(Op::StoreIndex);
+                        self.bc_ins.push(Op::PopTop)
+                    }
+                    Op::LoadStrConst { value } if operator == AssignOp::None => {
+                        self.bc_ins.push(Op::Assign {name: value})
+                    }
                     Op::LoadStrConst { value } => {
                         self.bc_ins.push(Op::Store {name: value})
                     }
@@ -150,6 +766,7 @@ impl Compiler {
                 }
             }
             Statement::ForStatement { init, test, update, body } => {
+                self.push_block_target(true);
                 self.bc_ins.push(Op::SetupLoop);
                 self.visit(init);
 
@@ -159,14 +776,62 @@ impl Compiler {
                 self.bc_ins.push(Op::PopJumpIfFalse {to: 0});
 
                 for item in body {
-                    self.visit(item);
+                    self.visit_statement(item);
                 }
 
+                let update_start = self.bc_ins.len();
+                // the update's value isn't used
+                let discard = leaves_value(&update);
                 self.visit(update);
+                if discard {
+                    self.bc_ins.push(Op::PopTop);
+                }
                 self.bc_ins.push(Op::JumpAbsolute {to: test_start });
 
                 let jump_to = self.bc_ins.len();
                 self.bc_ins[pop_jump_i] = Op::PopJumpIfFalse {to: jump_to};
+                self.pop_target(jump_to, update_start);
+
+                self.bc_ins.push(Op::PopBlock);
+            }
+            Statement::While { test, body } => {
+                self.push_block_target(true);
+                self.bc_ins.push(Op::SetupLoop);
+
+                let test_start = self.bc_ins.len();
+                self.visit(test);
+                let pop_jump_i = self.bc_ins.len();
+                self.bc_ins.push(Op::PopJumpIfFalse {to: 0});
+
+                for item in body {
+                    self.visit_statement(item);
+                }
+                self.bc_ins.push(Op::JumpAbsolute {to: test_start});
+
+                let jump_to = self.bc_ins.len();
+                self.bc_ins[pop_jump_i] = Op::PopJumpIfFalse {to: jump_to};
+                self.pop_target(jump_to, test_start);
+
+                self.bc_ins.push(Op::PopBlock);
+            }
+            Statement::DoWhile { body, test } => {
+                self.push_block_target(true);
+                self.bc_ins.push(Op::SetupLoop);
+
+                let body_start = self.bc_ins.len();
+                for item in body {
+                    self.visit_statement(item);
+                }
+
+                let test_start = self.bc_ins.len();
+                self.visit(test);
+                let pop_jump_i = self.bc_ins.len();
+                self.bc_ins.push(Op::PopJumpIfFalse {to: 0});
+                self.bc_ins.push(Op::JumpAbsolute {to: body_start});
+
+                let jump_to = self.bc_ins.len();
+                self.bc_ins[pop_jump_i] = Op::PopJumpIfFalse {to: jump_to};
+                self.pop_target(jump_to, test_start);
 
                 self.bc_ins.push(Op::PopBlock);
             }
@@ -176,7 +841,7 @@ impl Compiler {
                 self.bc_ins.push(Op::PopJumpIfFalse {to: 0});
 
                 for item in consequent {
-                    self.visit(item);
+                    self.visit_statement(item);
                 }
                 self.bc_ins.push(Op::JumpAbsolute { to: 0 });
 
@@ -184,20 +849,55 @@ impl Compiler {
                 self.bc_ins[pop_jump_i] = Op::PopJumpIfFalse {to: jump_to};
                 let jump_to_i = jump_to - 1;
 
-                self.visit(alternate);
+                self.visit_statement(alternate);
                 jump_to = self.bc_ins.len();
                 self.bc_ins[jump_to_i] = Op::JumpAbsolute { to: jump_to};
             }
+            Statement::Break { label } => self.visit_jump(label, true),
+            Statement::Continue { label } => self.visit_jump(label, false),
+            Statement::Labelled { label, body } => self.visit_labelled(label, body),
+            Statement::Switch { discriminant, cases } => self.visit_switch(discriminant, cases),
+            Statement::Try { block, handler, finalizer } => self.visit_try(block, handler, finalizer),
+            Statement::Throw { value } => {
+                self.visit(*value);
+                self.bc_ins.push(Op::Throw);
+            }
+            Statement::Return { value } => self.visit_return(value),
+            _ => {}
+        }
+    }
+
+    /// An item run for its effect, any value it leaves is dropped.
+    fn visit_statement(&mut self, item: JSItem) {
+        let discard = leaves_value(&item);
+        let outer = self.locations.last().map(|(_, span)| span.clone());
+        self.locate(&item);
+        self.visit(item);
+        if discard {
+            self.bc_ins.push(Op::PopTop);
+        }
+        // what follows a nested statement still belongs to the one around it
+        if let Some(span) = outer {
+            self.locations.push((self.bc_ins.len(), span));
+        }
+    }
+
+    /// Records that the instructions from here on come from `item`.
+    fn locate(&mut self, item: &JSItem) {
+        match item {
+            JSItem::Ex { span, .. } | JSItem::St { span, .. } => {
+                self.locations.push((self.bc_ins.len(), span.clone()))
+            }
             _ => {}
         }
     }
 
     fn visit(&mut self, item: JSItem) {
         match item {
-            JSItem::Ex { expression } => {
+            JSItem::Ex { expression, .. } => {
                 self.visit_ex(*expression)
             }
-            JSItem::St { statement } => {
+            JSItem::St { statement, .. } => {
                 self.visit_st(*statement)
             }
             JSItem::Object { mutable, properties } => {
@@ -207,23 +907,22 @@ impl Compiler {
         }
     }
 
-    fn visit_object(&mut self, _mutable: bool, mut properties: HashMap<String, JSItem>) {
+    fn visit_object(&mut self, _mutable: bool, properties: PropertyMap) {
         self.bc_ins.push(Op::CreateObj);
 
-        let mut keys = vec![];
-        for key in properties.keys() {
-            keys.push(key.clone())
-        }
-
-        for key in keys {
-            let item = properties.remove(&key).unwrap();
-
+        for (key, item) in properties {
             self.visit(item);
-            self.bc_ins.push(Op::StoreProp {name: key.clone() });
+            self.bc_ins.push(Op::StoreProp {name: key });
         }
     }
 
+    /// Compiles one top level item, the value of the last one is left as the program's result.
     pub(crate) fn compile(&mut self, ast: JSItem) {
+        if self.has_result {
+            self.bc_ins.push(Op::PopTop);
+        }
+        self.has_result = leaves_value(&ast);
+        self.locate(&ast);
         self.visit(ast)
     }
 }
\ No newline at end of file
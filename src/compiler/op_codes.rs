@@ -51,5 +51,106 @@ pub(crate) enum Op {
     And, // 0x18
     EqEq, // 0x19
     EqEqEq, // 0x1a
+    BuildString { // 0x1b
+        count: usize
+    },
+    LoadRegExp { // 0x1c
+        pattern: String,
+        flags: String
+    },
+    LoadBool { // 0x1d
+        value: bool
+    },
+    Assign { // 0x1e
+        name: String
+    },
+    DupTop, // 0x1f
+    SetupTry { // 0x20
+        handler: usize
+    },
+    PopTry, // 0x21
+    Throw, // 0x22
+    UnaryNot, // 0x23
+    UnaryNegative, // 0x24
+    UnaryPositive, // 0x25
+    UnaryInvert, // 0x26
+    TypeOf, // 0x27
+    TypeOfName { // 0x28
+        name: String
+    },
+    LoadUndefined, // 0x29
+    DeleteProp { // 0x2a
+        name: String
+    },
+    LoadNull, // 0x2b
+    JumpIfFalseOrPop { // 0x2c
+        to: usize
+    },
+    JumpIfTrueOrPop { // 0x2d
+        to: usize
+    },
+    JumpIfNotNullishOrPop { // 0x2e
+        to: usize
+    },
+    RotTwo, // 0x2f
+    Mod, // 0x30
+    Exp, // 0x31
+    LShift, // 0x32
+    RShift, // 0x33
+    URShift, // 0x34
+    BitAnd, // 0x35
+    BitOr, // 0x36
+    BitXor, // 0x37
+    RotThree, // 0x38
+    LoadIndex, // 0x39
+    StoreIndex, // 0x3a
+    DeleteIndex, // 0x3b
+    DupTopTwo, // 0x3c
+    RotFour, // 0x3d
+    BuildArray { // 0x3e
+        count: usize
+    },
+    NotEq, // 0x3f
+    NotEqEq, // 0x40
+    LessEqual, // 0x41
+    GreaterEqual, // 0x42
+    In, // 0x43
+    InstanceOf, // 0x44
+    LoadFunc { // 0x45
+        start: usize,
+        end: usize,
+        params: Vec<String>
+    },
+    CallMethod { // 0x46
+        args: i8
+    },
 }
 
+
+impl Op {
+    /// How many values the instruction takes off the stack. Running it with fewer there is a
+    /// fault in the engine rather than in the script.
+    pub(crate) fn operands(&self) -> usize {
+        match self {
+            Op::Call { args } => *args as usize + 1,
+            Op::CallMethod { args } => *args as usize + 2,
+            Op::BuildString { count } | Op::BuildArray { count } => *count,
+            Op::RotFour => 4,
+            Op::RotThree | Op::StoreIndex => 3,
+            Op::Add | Op::Sub | Op::Div | Op::Mul | Op::Mod | Op::Exp
+            | Op::LShift | Op::RShift | Op::URShift | Op::BitAnd | Op::BitOr | Op::BitXor
+            | Op::Less | Op::Greater | Op::LessEqual | Op::GreaterEqual
+            | Op::EqEq | Op::EqEqEq | Op::NotEq | Op::NotEqEq | Op::In | Op::InstanceOf | Op::And
+            | Op::StoreProp { .. } | Op::RotTwo | Op::DupTopTwo | Op::LoadIndex | Op::DeleteIndex => 2,
+            Op::Return | Op::Store { .. } | Op::Assign { .. } | Op::PopTop | Op::PopJumpIfFalse { .. }
+            | Op::InplaceAdd | Op::LoadProp { .. } | Op::DupTop | Op::Throw
+            | Op::UnaryNot | Op::UnaryNegative | Op::UnaryPositive | Op::UnaryInvert | Op::TypeOf
+            | Op::DeleteProp { .. } | Op::JumpIfFalseOrPop { .. } | Op::JumpIfTrueOrPop { .. }
+            | Op::JumpIfNotNullishOrPop { .. } => 1,
+            Op::LoadNumConst { .. } | Op::LoadStrConst { .. } | Op::Load { .. } | Op::LoadMember
+            | Op::SetupLoop | Op::JumpAbsolute { .. } | Op::PopBlock | Op::DeclareFunc { .. }
+            | Op::CreateObj | Op::LoadRegExp { .. } | Op::LoadBool { .. } | Op::SetupTry { .. }
+            | Op::PopTry | Op::TypeOfName { .. } | Op::LoadUndefined | Op::LoadNull | Op::LoadFunc { .. } => 0
+        }
+    }
+}
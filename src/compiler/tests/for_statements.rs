@@ -11,8 +11,8 @@ fn test_for() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
 
     let mut com = Compiler::new();
@@ -26,13 +26,20 @@ fn test_for() {
         Op::Load {name: "i".to_string()},
         Op::LoadNumConst {value: 10.},
         Op::Less,
-        Op::PopJumpIfFalse {to: 14},
+        Op::PopJumpIfFalse {to: 21},
         Op::Load {name: "console".to_string()},
+        Op::DupTop,
         Op::LoadProp {name: "log".to_string()},
         Op::Load {name: "i".to_string()},
-        Op::Call {args: 1},
+        Op::CallMethod {args: 1},
+        Op::PopTop,
         Op::Load {name: "i".to_string()},
-        Op::InplaceAdd,
+        Op::UnaryPositive,
+        Op::DupTop,
+        Op::LoadNumConst {value: 1.},
+        Op::Add,
+        Op::Assign {name: "i".to_string()},
+        Op::PopTop,
         Op::JumpAbsolute {to: 3},
         Op::PopBlock
     ]);
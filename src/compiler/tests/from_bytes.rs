@@ -43,4 +43,21 @@ fn test_console_log() {
         },
         Op::Call {args: 1}
     ]);
-}
\ No newline at end of file
+}
+#[test]
+fn test_from_bytes_comparisons() {
+    let bytes = vec![0x19, 0x3f, 0x1a, 0x40, 0x06, 0x17, 0x41, 0x42, 0x43, 0x44];
+
+    assert_eq!(from_bytes(bytes), vec![
+        Op::EqEq,
+        Op::NotEq,
+        Op::EqEqEq,
+        Op::NotEqEq,
+        Op::Less,
+        Op::Greater,
+        Op::LessEqual,
+        Op::GreaterEqual,
+        Op::In,
+        Op::InstanceOf
+    ]);
+}
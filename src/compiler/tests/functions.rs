@@ -7,9 +7,9 @@ use std::fs;
 #[test]
 fn test_console_dot_log() {
     let mut lex = Lexer::new();
-    let tokens = lex.lex(String::from("console.log(\"hi\");"));
+    let tokens = lex.lex(String::from("console.log(\"hi\");")).unwrap();
     let mut parser = Parser::new();
-    let mut js_items = parser.parse(tokens);
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -19,13 +19,14 @@ fn test_console_dot_log() {
         Op::Load {
             name: "console".to_string()
         },
+        Op::DupTop,
         Op::LoadProp {
             name: "log".to_string()
         },
         Op::LoadStrConst {
             value: "hi".to_string()
         },
-        Op::Call {args: 1}
+        Op::CallMethod {args: 1}
     ]);
 }
 
@@ -34,9 +35,9 @@ fn test_function_call() {
     let file = fs::read_to_string("js/functions/function_call.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
     let mut parser = Parser::new();
-    let mut js_items = parser.parse(tokens);
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     for item in js_items {
@@ -46,16 +47,18 @@ fn test_function_call() {
     assert_eq!(com.bc_ins, vec![
         Op::DeclareFunc {
             start: 1,
-            end: 6,
+            end: 8,
             mutable: true,
             params: vec![],
             name: "f".to_string()
         },
         Op::Load {name: "console".to_string()},
+        Op::DupTop,
         Op::LoadProp {name: "log".to_string()},
         Op::LoadStrConst {value: "hi".to_string()},
-        Op::Call {args: 1},
-        Op::PopBlock,
+        Op::CallMethod {args: 1},
+        Op::PopTop,
+        Op::LoadUndefined,
         Op::Return,
         Op::Load {name: "f".to_string()},
         Op::Call {args: 0}
@@ -67,9 +70,9 @@ fn test_function_function_call_with_args() {
     let file = fs::read_to_string("js/functions/function_function_call_with_args.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
     let mut parser = Parser::new();
-    let mut js_items = parser.parse(tokens);
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     for item in js_items {
@@ -79,30 +82,33 @@ fn test_function_function_call_with_args() {
     assert_eq!(com.bc_ins, vec![
         Op::DeclareFunc {
             start: 1,
-            end: 14,
+            end: 17,
             mutable: true,
             params: vec!["a".to_string()],
             name: "f".to_string()
         },
         Op::DeclareFunc {
             start: 2,
-            end: 8,
+            end: 10,
             mutable: true,
             params: vec!["a".to_string(), "b".to_string()],
             name: "f".to_string()
         },
         Op::Load {name: "console".to_string()},
+        Op::DupTop,
         Op::LoadProp {name: "log".to_string()},
         Op::Load {name: "a".to_string()},
         Op::Load {name: "b".to_string()},
-        Op::Call {args: 2},
-        Op::PopBlock,
+        Op::CallMethod {args: 2},
+        Op::PopTop,
+        Op::LoadUndefined,
         Op::Return,
         Op::Load {name: "f".to_string()},
         Op::Load {name: "a".to_string()},
         Op::LoadStrConst {value: "there".to_string()},
         Op::Call {args: 2},
-        Op::PopBlock,
+        Op::PopTop,
+        Op::LoadUndefined,
         Op::Return,
         Op::Load {name: "f".to_string()},
         Op::LoadStrConst {value: "hi".to_string()},
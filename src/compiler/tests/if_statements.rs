@@ -12,8 +12,8 @@ fn test_if1() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     for item in js_items {
@@ -26,12 +26,14 @@ fn test_if1() {
         Op::Load {name: "x".to_string()},
         Op::LoadNumConst {value: 2.},
         Op::Greater,
-        Op::PopJumpIfFalse {to: 11},
+        Op::PopJumpIfFalse {to: 13},
         Op::Load {name: "console".to_string()},
+        Op::DupTop,
         Op::LoadProp {name: "log".to_string()},
         Op::LoadStrConst {value: "hi".to_string()},
-        Op::Call {args: 1},
-        Op::JumpAbsolute {to: 11}
+        Op::CallMethod {args: 1},
+        Op::PopTop,
+        Op::JumpAbsolute {to: 13}
     ]);
 }
 
@@ -41,8 +43,8 @@ fn test_if2() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     for item in js_items {
@@ -55,25 +57,31 @@ fn test_if2() {
         Op::Load {name: "x".to_string()},
         Op::LoadNumConst {value: 2.},
         Op::Greater,
-        Op::PopJumpIfFalse {to: 11},
+        Op::PopJumpIfFalse {to: 13},
         Op::Load {name: "console".to_string()},
+        Op::DupTop,
         Op::LoadProp {name: "log".to_string()},
         Op::LoadStrConst {value: "hi".to_string()},
-        Op::Call {args: 1},
-        Op::JumpAbsolute {to: 24},
+        Op::CallMethod {args: 1},
+        Op::PopTop,
+        Op::JumpAbsolute {to: 30},
         Op::Load {name: "x".to_string()},
         Op::LoadNumConst {value: 2.},
         Op::Less,
-        Op::PopJumpIfFalse {to: 20},
+        Op::PopJumpIfFalse {to: 24},
         Op::Load {name: "console".to_string()},
+        Op::DupTop,
         Op::LoadProp {name: "log".to_string()},
         Op::LoadStrConst {value: "gt 2".to_string()},
-        Op::Call {args: 1},
-        Op::JumpAbsolute {to: 24},
+        Op::CallMethod {args: 1},
+        Op::PopTop,
+        Op::JumpAbsolute {to: 30},
         Op::Load {name: "console".to_string()},
+        Op::DupTop,
         Op::LoadProp {name: "log".to_string()},
         Op::LoadStrConst {value: "there".to_string()},
-        Op::Call {args: 1},
+        Op::CallMethod {args: 1},
+        Op::PopTop,
     ]);
 }
 
@@ -83,8 +91,8 @@ fn test_if3() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     for item in js_items {
@@ -97,24 +105,30 @@ fn test_if3() {
         Op::Load {name: "x".to_string()},
         Op::LoadNumConst {value: 2.},
         Op::Greater,
-        Op::PopJumpIfFalse {to: 11},
+        Op::PopJumpIfFalse {to: 13},
         Op::Load {name: "console".to_string()},
+        Op::DupTop,
         Op::LoadProp {name: "log".to_string()},
         Op::LoadStrConst {value: "hi".to_string()},
-        Op::Call {args: 1},
-        Op::JumpAbsolute {to: 24},
+        Op::CallMethod {args: 1},
+        Op::PopTop,
+        Op::JumpAbsolute {to: 30},
         Op::Load {name: "x".to_string()},
         Op::LoadNumConst {value: 2.},
         Op::Less,
-        Op::PopJumpIfFalse {to: 20},
+        Op::PopJumpIfFalse {to: 24},
         Op::Load {name: "console".to_string()},
+        Op::DupTop,
         Op::LoadProp {name: "log".to_string()},
         Op::LoadStrConst {value: "gt 2".to_string()},
-        Op::Call {args: 1},
-        Op::JumpAbsolute {to: 24},
+        Op::CallMethod {args: 1},
+        Op::PopTop,
+        Op::JumpAbsolute {to: 30},
         Op::Load {name: "console".to_string()},
+        Op::DupTop,
         Op::LoadProp {name: "log".to_string()},
         Op::LoadStrConst {value: "there".to_string()},
-        Op::Call {args: 1},
+        Op::CallMethod {args: 1},
+        Op::PopTop,
     ]);
 }
\ No newline at end of file
@@ -7,8 +7,8 @@ use crate::compiler::op_codes::Op;
 fn test_simple_add() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("1 + 2 + 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("1 + 2 + 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -33,8 +33,8 @@ fn test_simple_add() {
 fn test_simple_sub() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 - 2"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 - 2")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -55,8 +55,8 @@ fn test_simple_sub() {
 fn test_simple_mul() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 * 2"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 * 2")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -77,8 +77,8 @@ fn test_simple_mul() {
 fn test_simple_div() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 / 2"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 / 2")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -99,8 +99,8 @@ fn test_simple_div() {
 fn test_add_sub() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 + 2 - 1"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 + 2 - 1")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -125,8 +125,8 @@ fn test_add_sub() {
 fn test_add_sub_expression() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("(3 + 2) - 1"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("(3 + 2) - 1")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -151,8 +151,8 @@ fn test_add_sub_expression() {
 fn test_sub_add_expression() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 - (2 + 1)"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 - (2 + 1)")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -177,8 +177,8 @@ fn test_sub_add_expression() {
 fn test_add_mul() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 + 2 * 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 + 2 * 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -203,8 +203,8 @@ fn test_add_mul() {
 fn test_mul_add() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 * 2 + 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 * 2 + 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -229,8 +229,8 @@ fn test_mul_add() {
 fn test_mul_add_expression() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 * (2 + 3)"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 * (2 + 3)")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -255,8 +255,8 @@ fn test_mul_add_expression() {
 fn test_mul_expression_add() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("(3 * 2) + 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("(3 * 2) + 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -281,8 +281,8 @@ fn test_mul_expression_add() {
 fn test_and1() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("x == 5 && x < 10"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("x == 5 && x < 10")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
 
@@ -295,10 +295,10 @@ fn test_and1() {
         Op::Load { name: "x".to_string()},
         Op::LoadNumConst { value: 5. },
         Op::EqEq,
+        Op::JumpIfFalseOrPop { to: 7 },
         Op::Load { name:  "x".to_string()},
         Op::LoadNumConst { value: 10. },
-        Op::Less,
-        Op::And
+        Op::Less
     ]);
 }
 
@@ -9,16 +9,16 @@ fn test_object_new_property() {
     let file = fs::read_to_string("js/objects/object_new_property.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
     let mut parser = Parser::new();
-    let mut js_items = parser.parse(tokens);
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     for item in js_items {
         com.compile(item);
     }
 
-    assert!(com.bc_ins == vec![
+    assert_eq!(com.bc_ins, vec![
         Op::CreateObj,
         Op::LoadNumConst {value: 1.},
         Op::StoreProp { name: "a".to_string()},
@@ -30,23 +30,35 @@ fn test_object_new_property() {
         Op::StoreProp {name: "d".to_string() },
         Op::PopTop,
         Op::Load {name: "console".to_string()},
+        Op::DupTop,
         Op::LoadProp {name: "log".to_string()},
         Op::Load {name: "a".to_string()},
-        Op::Call {args: 1}
-    ] || com.bc_ins == vec![
+        Op::CallMethod {args: 1}
+    ]);
+}
+
+#[test]
+fn test_object_property_order() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("let o = {b: 1, \"2\": 2, a: 3, \"1\": 4}\n")).unwrap();
+    let mut parser = Parser::new();
+    let js_items = parser.parse(tokens).unwrap();
+
+    let mut com = Compiler::new();
+    for item in js_items {
+        com.compile(item);
+    }
+
+    assert_eq!(com.bc_ins, vec![
         Op::CreateObj,
+        Op::LoadNumConst {value: 4.},
+        Op::StoreProp {name: "1".to_string()},
         Op::LoadNumConst {value: 2.},
-        Op::StoreProp {name: "b".to_string() },
+        Op::StoreProp {name: "2".to_string()},
         Op::LoadNumConst {value: 1.},
-        Op::StoreProp { name: "a".to_string() },
-        Op::Store {name: "a".to_string()},
-        Op::Load {name: "a".to_string()},
-        Op::LoadStrConst {value: "hello world".to_string()},
-        Op::StoreProp {name: "d".to_string() },
-        Op::PopTop,
-        Op::Load {name: "console".to_string()},
-        Op::LoadProp {name: "log".to_string()},
-        Op::Load {name: "a".to_string()},
-        Op::Call {args: 1}
+        Op::StoreProp {name: "b".to_string()},
+        Op::LoadNumConst {value: 3.},
+        Op::StoreProp {name: "a".to_string()},
+        Op::Store {name: "o".to_string()}
     ]);
-}
\ No newline at end of file
+}
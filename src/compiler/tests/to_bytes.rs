@@ -7,8 +7,8 @@ use crate::compiler::to_bytes::{to_bytes};
 fn test_math_1() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("1 + 2 + 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("1 + 2 + 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -25,8 +25,8 @@ fn test_math_1() {
 fn test_console_log() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("console.log(\"hi\");"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("console.log(\"hi\");")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -34,7 +34,7 @@ fn test_console_log() {
 
     let bytes = to_bytes(com.bc_ins);
 
-    assert_eq!(bytes, vec![10, 0, 0, 0, 7, 99, 111, 110, 115, 111, 108, 101, 19, 0, 0, 0, 3, 108,
-                           111, 103, 8, 0, 0, 0, 2, 104, 105, 12, 1]);
+    assert_eq!(bytes, vec![10, 0, 0, 0, 7, 99, 111, 110, 115, 111, 108, 101, 31, 19, 0, 0, 0, 3, 108,
+                           111, 103, 8, 0, 0, 0, 2, 104, 105, 70, 1]);
 }
 
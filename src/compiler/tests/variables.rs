@@ -11,8 +11,8 @@ fn test_let_variable_declaration() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -34,8 +34,8 @@ fn test_let_variable_declaration_number() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -57,8 +57,8 @@ fn test_let_math_declaration() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -84,8 +84,8 @@ fn test_let_math_declaration1() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -119,8 +119,8 @@ fn test_let_var_plus_var() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     let item = js_items.pop().unwrap();
@@ -96,6 +96,157 @@ pub(crate) fn to_bytes(ops: Vec<Op>) -> Vec<u8> {
             Op::EqEqEq => {
                 buffer.write_u8(0x1a)
             }
+            Op::BuildString { count } => {
+                buffer.write_u8(0x1b);
+                buffer.write_u64(count as u64);
+            }
+            Op::LoadRegExp { pattern, flags } => {
+                buffer.write_u8(0x1c);
+                buffer.write_string(&pattern);
+                buffer.write_string(&flags);
+            }
+            Op::LoadBool { value } => {
+                buffer.write_u8(0x1d);
+                buffer.write_u8(value as u8);
+            }
+            Op::Assign { name } => {
+                buffer.write_u8(0x1e);
+                buffer.write_string(&name);
+            }
+            Op::DupTop => {
+                buffer.write_u8(0x1f)
+            }
+            Op::SetupTry { handler } => {
+                buffer.write_u8(0x20);
+                buffer.write_u64(handler as u64);
+            }
+            Op::PopTry => {
+                buffer.write_u8(0x21)
+            }
+            Op::Throw => {
+                buffer.write_u8(0x22)
+            }
+            Op::UnaryNot => {
+                buffer.write_u8(0x23)
+            }
+            Op::UnaryNegative => {
+                buffer.write_u8(0x24)
+            }
+            Op::UnaryPositive => {
+                buffer.write_u8(0x25)
+            }
+            Op::UnaryInvert => {
+                buffer.write_u8(0x26)
+            }
+            Op::TypeOf => {
+                buffer.write_u8(0x27)
+            }
+            Op::TypeOfName { name } => {
+                buffer.write_u8(0x28);
+                buffer.write_string(&name);
+            }
+            Op::LoadUndefined => {
+                buffer.write_u8(0x29)
+            }
+            Op::DeleteProp { name } => {
+                buffer.write_u8(0x2a);
+                buffer.write_string(&name);
+            }
+            Op::LoadNull => {
+                buffer.write_u8(0x2b)
+            }
+            Op::JumpIfFalseOrPop { to } => {
+                buffer.write_u8(0x2c);
+                buffer.write_u64(to as u64);
+            }
+            Op::JumpIfTrueOrPop { to } => {
+                buffer.write_u8(0x2d);
+                buffer.write_u64(to as u64);
+            }
+            Op::JumpIfNotNullishOrPop { to } => {
+                buffer.write_u8(0x2e);
+                buffer.write_u64(to as u64);
+            }
+            Op::RotTwo => {
+                buffer.write_u8(0x2f)
+            }
+            Op::Mod => {
+                buffer.write_u8(0x30)
+            }
+            Op::Exp => {
+                buffer.write_u8(0x31)
+            }
+            Op::LShift => {
+                buffer.write_u8(0x32)
+            }
+            Op::RShift => {
+                buffer.write_u8(0x33)
+            }
+            Op::URShift => {
+                buffer.write_u8(0x34)
+            }
+            Op::BitAnd => {
+                buffer.write_u8(0x35)
+            }
+            Op::BitOr => {
+                buffer.write_u8(0x36)
+            }
+            Op::BitXor => {
+                buffer.write_u8(0x37)
+            }
+            Op::RotThree => {
+                buffer.write_u8(0x38)
+            }
+            Op::LoadIndex => {
+                buffer.write_u8(0x39)
+            }
+            Op::StoreIndex => {
+                buffer.write_u8(0x3a)
+            }
+            Op::DeleteIndex => {
+                buffer.write_u8(0x3b)
+            }
+            Op::DupTopTwo => {
+                buffer.write_u8(0x3c)
+            }
+            Op::RotFour => {
+                buffer.write_u8(0x3d)
+            }
+            Op::BuildArray { count } => {
+                buffer.write_u8(0x3e);
+                buffer.write_u64(count as u64);
+            }
+            Op::NotEq => {
+                buffer.write_u8(0x3f)
+            }
+            Op::NotEqEq => {
+                buffer.write_u8(0x40)
+            }
+            Op::LessEqual => {
+                buffer.write_u8(0x41)
+            }
+            Op::GreaterEqual => {
+                buffer.write_u8(0x42)
+            }
+            Op::In => {
+                buffer.write_u8(0x43)
+            }
+            Op::InstanceOf => {
+                buffer.write_u8(0x44)
+            }
+            Op::LoadFunc { start, end, params } => {
+                buffer.write_u8(0x45);
+                buffer.write_u64(start as u64);
+                buffer.write_u64(end as u64);
+                buffer.write_u64(params.len() as u64);
+                for param in params {
+                    buffer.write_string(&param);
+                }
+            }
+            Op::CallMethod { args } => {
+                buffer.write_u8(0x46);
+                buffer.write_u8(args as u8);
+            }
         }
     }
     return buffer.to_bytes();
@@ -145,6 +296,15 @@ pub(crate) fn from_bytes(bytes: Vec<u8>) -> Vec<Op> {
         else if *byte == 0x6 as u8 {
             ops.push(Op::Less);
         }
+        else if *byte == 0x17 as u8 {
+            ops.push(Op::Greater);
+        }
+        else if *byte == 0x19 as u8 {
+            ops.push(Op::EqEq);
+        }
+        else if *byte == 0x1a as u8 {
+            ops.push(Op::EqEqEq);
+        }
         else if *byte == 0x7 as u8 {
             let num: [u8; 8]  = [
                 bytes.get(i + 1).unwrap().clone(),
@@ -194,6 +354,215 @@ pub(crate) fn from_bytes(bytes: Vec<u8>) -> Vec<Op> {
             let nnum = u64::from_be_bytes(num);
             ops.push(Op::JumpAbsolute { to: nnum as usize })
         }
+        else if *byte == 0x1b as u8 {
+            let num: [u8; 8]  = [
+                bytes.get(i + 1).unwrap().clone(),
+                bytes.get(i + 2).unwrap().clone(),
+                bytes.get(i + 3).unwrap().clone(),
+                bytes.get(i + 4).unwrap().clone(),
+                bytes.get(i + 5).unwrap().clone(),
+                bytes.get(i + 6).unwrap().clone(),
+                bytes.get(i + 7).unwrap().clone(),
+                bytes.get(i + 8).unwrap().clone(),
+            ];
+            let count = u64::from_be_bytes(num);
+            ops.push(Op::BuildString { count: count as usize });
+            i += 8;
+        }
+        else if *byte == 0x1c as u8 {
+            let pattern = next_str(i, &bytes);
+            i += pattern.1;
+            let flags = next_str(i, &bytes);
+            i += flags.1;
+            ops.push(Op::LoadRegExp { pattern: pattern.0, flags: flags.0 });
+        }
+        else if *byte == 0x1d as u8 {
+            ops.push(Op::LoadBool { value: *bytes.get(i + 1).unwrap() != 0 });
+            i += 1;
+        }
+        else if *byte == 0x1e as u8 {
+            let vals = next_str(i, &bytes);
+            ops.push(Op::Assign {name: vals.0});
+            i += vals.1;
+        }
+        else if *byte == 0x1f as u8 {
+            ops.push(Op::DupTop);
+        }
+        else if *byte == 0x20 as u8 {
+            let num: [u8; 8]  = [
+                bytes.get(i + 1).unwrap().clone(),
+                bytes.get(i + 2).unwrap().clone(),
+                bytes.get(i + 3).unwrap().clone(),
+                bytes.get(i + 4).unwrap().clone(),
+                bytes.get(i + 5).unwrap().clone(),
+                bytes.get(i + 6).unwrap().clone(),
+                bytes.get(i + 7).unwrap().clone(),
+                bytes.get(i + 8).unwrap().clone(),
+            ];
+            ops.push(Op::SetupTry { handler: u64::from_be_bytes(num) as usize });
+            i += 8;
+        }
+        else if *byte == 0x21 as u8 {
+            ops.push(Op::PopTry);
+        }
+        else if *byte == 0x22 as u8 {
+            ops.push(Op::Throw);
+        }
+        else if *byte == 0x23 as u8 {
+            ops.push(Op::UnaryNot);
+        }
+        else if *byte == 0x24 as u8 {
+            ops.push(Op::UnaryNegative);
+        }
+        else if *byte == 0x25 as u8 {
+            ops.push(Op::UnaryPositive);
+        }
+        else if *byte == 0x26 as u8 {
+            ops.push(Op::UnaryInvert);
+        }
+        else if *byte == 0x27 as u8 {
+            ops.push(Op::TypeOf);
+        }
+        else if *byte == 0x28 as u8 {
+            let vals = next_str(i, &bytes);
+            ops.push(Op::TypeOfName {name: vals.0});
+            i += vals.1;
+        }
+        else if *byte == 0x29 as u8 {
+            ops.push(Op::LoadUndefined);
+        }
+        else if *byte == 0x2a as u8 {
+            let vals = next_str(i, &bytes);
+            ops.push(Op::DeleteProp {name: vals.0});
+            i += vals.1;
+        }
+        else if *byte == 0x2b as u8 {
+            ops.push(Op::LoadNull);
+        }
+        else if *byte == 0x2c as u8 {
+            let num: [u8; 8]  = [
+                bytes.get(i + 1).unwrap().clone(),
+                bytes.get(i + 2).unwrap().clone(),
+                bytes.get(i + 3).unwrap().clone(),
+                bytes.get(i + 4).unwrap().clone(),
+                bytes.get(i + 5).unwrap().clone(),
+                bytes.get(i + 6).unwrap().clone(),
+                bytes.get(i + 7).unwrap().clone(),
+                bytes.get(i + 8).unwrap().clone(),
+            ];
+            ops.push(Op::JumpIfFalseOrPop { to: u64::from_be_bytes(num) as usize });
+            i += 8;
+        }
+        else if *byte == 0x2d as u8 {
+            let num: [u8; 8]  = [
+                bytes.get(i + 1).unwrap().clone(),
+                bytes.get(i + 2).unwrap().clone(),
+                bytes.get(i + 3).unwrap().clone(),
+                bytes.get(i + 4).unwrap().clone(),
+                bytes.get(i + 5).unwrap().clone(),
+                bytes.get(i + 6).unwrap().clone(),
+                bytes.get(i + 7).unwrap().clone(),
+                bytes.get(i + 8).unwrap().clone(),
+            ];
+            ops.push(Op::JumpIfTrueOrPop { to: u64::from_be_bytes(num) as usize });
+            i += 8;
+        }
+        else if *byte == 0x2e as u8 {
+            let num: [u8; 8]  = [
+                bytes.get(i + 1).unwrap().clone(),
+                bytes.get(i + 2).unwrap().clone(),
+                bytes.get(i + 3).unwrap().clone(),
+                bytes.get(i + 4).unwrap().clone(),
+                bytes.get(i + 5).unwrap().clone(),
+                bytes.get(i + 6).unwrap().clone(),
+                bytes.get(i + 7).unwrap().clone(),
+                bytes.get(i + 8).unwrap().clone(),
+            ];
+            ops.push(Op::JumpIfNotNullishOrPop { to: u64::from_be_bytes(num) as usize });
+            i += 8;
+        }
+        else if *byte == 0x2f as u8 {
+            ops.push(Op::RotTwo);
+        }
+        else if *byte == 0x30 as u8 {
+            ops.push(Op::Mod);
+        }
+        else if *byte == 0x31 as u8 {
+            ops.push(Op::Exp);
+        }
+        else if *byte == 0x32 as u8 {
+            ops.push(Op::LShift);
+        }
+        else if *byte == 0x33 as u8 {
+            ops.push(Op::RShift);
+        }
+        else if *byte == 0x34 as u8 {
+            ops.push(Op::URShift);
+        }
+        else if *byte == 0x35 as u8 {
+            ops.push(Op::BitAnd);
+        }
+        else if *byte == 0x36 as u8 {
+            ops.push(Op::BitOr);
+        }
+        else if *byte == 0x37 as u8 {
+            ops.push(Op::BitXor);
+        }
+        else if *byte == 0x38 as u8 {
+            ops.push(Op::RotThree);
+        }
+        else if *byte == 0x39 as u8 {
+            ops.push(Op::LoadIndex);
+        }
+        else if *byte == 0x3a as u8 {
+            ops.push(Op::StoreIndex);
+        }
+        else if *byte == 0x3b as u8 {
+            ops.push(Op::DeleteIndex);
+        }
+        else if *byte == 0x3c as u8 {
+            ops.push(Op::DupTopTwo);
+        }
+        else if *byte == 0x3d as u8 {
+            ops.push(Op::RotFour);
+        }
+        else if *byte == 0x3e as u8 {
+            let num: [u8; 8]  = [
+                bytes.get(i + 1).unwrap().clone(),
+                bytes.get(i + 2).unwrap().clone(),
+                bytes.get(i + 3).unwrap().clone(),
+                bytes.get(i + 4).unwrap().clone(),
+                bytes.get(i + 5).unwrap().clone(),
+                bytes.get(i + 6).unwrap().clone(),
+                bytes.get(i + 7).unwrap().clone(),
+                bytes.get(i + 8).unwrap().clone(),
+            ];
+            let count = u64::from_be_bytes(num);
+            ops.push(Op::BuildArray { count: count as usize });
+            i += 8;
+        }
+        else if *byte == 0x3f as u8 {
+            ops.push(Op::NotEq);
+        }
+        else if *byte == 0x40 as u8 {
+            ops.push(Op::NotEqEq);
+        }
+        else if *byte == 0x41 as u8 {
+            ops.push(Op::LessEqual);
+        }
+        else if *byte == 0x42 as u8 {
+            ops.push(Op::GreaterEqual);
+        }
+        else if *byte == 0x43 as u8 {
+            ops.push(Op::In);
+        }
+        else if *byte == 0x44 as u8 {
+            ops.push(Op::InstanceOf);
+        }
+        else if *byte == 0x46 as u8 {
+            ops.push(Op::CallMethod {args: bytes.get(i + 1).unwrap().clone() as i8});
+            i += 1;
+        }
         else if *byte == 0x13 as u8 {
             let vals = next_str(i, &bytes);
             ops.push(Op::LoadProp {name: vals.0});
@@ -1,502 +1,810 @@
-use crate::lexer::lexer::LexError;
-use crate::lexer::string_iterator::StringIterator;
-use crate::lexer::js_token::Tok;
+use std::rc::Rc;
 
-#[allow(unused_must_use)]
-fn find_float(it: &mut StringIterator, ch: char) -> Result<Vec<Tok>, LexError> {
-    let mut word = String::from("");
-    word.push(ch);
+use unicode_normalization::UnicodeNormalization;
+use unicode_xid::UnicodeXID;
 
-    loop {
-        let ch = it.next();
-        match ch {
-            Ok(ch) => {
-                if !ch.is_numeric() && ch != '.' {
-                    it.prev();
-                    break;
-                }
+use crate::lexer::lexer::{LexError, LexErrorKind};
+use crate::lexer::line_char_iterator::LineCharIterator;
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::span::Span;
+use crate::lexer::trivia::{Comment, CommentKind};
 
+/// Reads a run of digits in `radix` into `word`, dropping `_` separators. Returns how many
+/// digits were read.
+fn find_digits(it: &mut LineCharIterator, word: &mut String, radix: u32) -> Result<usize, LexError> {
+    let mut count = 0;
+    loop {
+        match it.peek() {
+            Some(ch) if ch.is_digit(radix) => {
+                it.next();
                 word.push(ch);
+                count += 1;
             }
-            Err(_) => {
-                break
+            Some('_') => {
+                let after_digit = word.chars().last().map_or(false, |last| last.is_digit(radix));
+                it.next();
+                match it.peek() {
+                    Some(ch) if after_digit && ch.is_digit(radix) => {}
+                    _ => {
+                        return Err(LexError::new(LexErrorKind::InvalidNumber, "Numeric separators are only allowed between digits"));
+                    }
+                }
             }
+            _ => return Ok(count)
         }
     }
-    let f = word.parse::<f64>();
-    if f.is_err() {
-        return Err(LexError::Error { text: String::from("Invalid Float Value") });
-    }
-    return Ok(vec![Tok::Float { value: f.unwrap() }]);
 }
 
-fn find_string_double_quote(it: &mut StringIterator) -> Result<Vec<Tok>, LexError> {
-    let mut word = String::from("");
-    loop {
-        let ch = it.next();
-        match ch {
-            Ok(ch) => {
-                if word.len() > 0 && ch == '"' {
-                    break;
-                }
-
-                word.push(ch);
-            }
-            Err(_) => {
-                break
-            }
+/// Decimal digits of an integer written in `radix`, BigInts can be longer than any float.
+fn to_decimal_digits(digits: &str, radix: u32) -> String {
+    // least significant digit first
+    let mut decimal: Vec<u32> = vec![0];
+    for ch in digits.chars() {
+        let mut carry = ch.to_digit(radix).unwrap();
+        for digit in decimal.iter_mut() {
+            let value = *digit * radix + carry;
+            *digit = value % 10;
+            carry = value / 10;
+        }
+        while carry > 0 {
+            decimal.push(carry % 10);
+            carry /= 10;
         }
     }
-    return Ok(vec![Tok::String { value: word }]);
+    decimal.iter().rev().map(|digit| std::char::from_digit(*digit, 10).unwrap()).collect()
 }
 
-#[allow(unused_must_use)]
-fn find_equal(it: &mut StringIterator) -> Result<Vec<Tok>, LexError> {
-    let mut word = String::from("=");
-
-    loop {
-        let ch = it.next();
-        match ch {
-            Ok(ch) => {
-                if ch != '=' && ch != '>' {
-                    it.prev();
-                    break
-                }
-
-                word.push(ch);
-            }
-            Err(_) => {
-                break
-            }
+/// Finishes a numeric literal, `integer` is false once a fraction or exponent was read.
+fn find_number_end(it: &mut LineCharIterator, word: String, radix: u32, integer: bool) -> Result<Tok, LexError> {
+    let big_int = it.peek() == Some('n');
+    if big_int {
+        it.next();
+        if !integer {
+            return Err(LexError::new(LexErrorKind::InvalidNumber, "Invalid BigInt literal"));
         }
     }
 
-    word = word.trim().parse().unwrap();
-    if word == "=" {
-        return Ok(vec![Tok::Equal]);
+    if let Some(ch) = it.peek() {
+        if is_id_start(ch) || ch == '\\' {
+            return Err(LexError::new(LexErrorKind::InvalidNumber, "Identifier starts immediately after numeric literal"));
+        }
+        // a digit the radix doesn't have, like the 2 in 0b12
+        if ch.is_ascii_digit() {
+            return Err(LexError::new(LexErrorKind::InvalidNumber, "Digit immediately after numeric literal"));
+        }
     }
-    if word == "==" {
-        return Ok(vec![Tok::EqEqual]);
+
+    if big_int {
+        return Ok(Tok::BigInt { value: to_decimal_digits(&word, radix) });
     }
-    if word == "===" {
-        return Ok(vec![Tok::EqEqEual]);
+    if radix != 10 {
+        let value = word.chars().fold(0., |value, ch| value * radix as f64 + ch.to_digit(radix).unwrap() as f64);
+        return Ok(Tok::Float { value });
     }
-    if word == "=>" {
-        return Ok(vec![Tok::RdoubleArrow]);
+    match word.parse::<f64>() {
+        Ok(value) => Ok(Tok::Float { value }),
+        Err(_) => Err(LexError::new(LexErrorKind::InvalidNumber, "Invalid Float Value"))
     }
-    return Err(LexError::Error { text: String::from("Equals Error") });
 }
 
-#[allow(unused_must_use)]
-fn find_plus(it: &mut StringIterator) -> Result<Vec<Tok>, LexError> {
-    let mut word = String::from("+");
-
-    loop {
-        let ch = it.next();
-        match ch {
-            Ok(ch) => {
-                if ch != '+' && ch != '=' {
-                    it.prev();
-                    break
-                }
+/// Reads a numeric literal starting with `ch`, a digit or the `.` of `.5`.
+fn find_number(it: &mut LineCharIterator, ch: char) -> Result<Tok, LexError> {
+    let mut word = String::from("");
 
-                word.push(ch);
+    if ch == '0' {
+        let radix = match it.peek() {
+            Some('x') | Some('X') => 16,
+            Some('o') | Some('O') => 8,
+            Some('b') | Some('B') => 2,
+            _ => 10
+        };
+        if radix != 10 {
+            it.next();
+            if find_digits(it, &mut word, radix)? == 0 {
+                return Err(LexError::new(LexErrorKind::InvalidNumber, "Missing digits after the radix prefix"));
             }
-            Err(_) => {
-                break
+            return find_number_end(it, word, radix, true);
+        }
+        // legacy octals like 017 and 08 are not allowed
+        if let Some(next) = it.peek() {
+            if next.is_ascii_digit() || next == '_' {
+                return Err(LexError::new(LexErrorKind::InvalidNumber, "Leading zeros are not allowed in numeric literals"));
             }
         }
     }
 
-    word = word.trim().parse().unwrap();
-    if word == "+" {
-        return Ok(vec![Tok::Plus]);
-    }
-    if word == "+=" {
-        return Ok(vec![Tok::PlusEqual]);
+    let mut integer = true;
+    if ch == '.' {
+        word.push_str("0.");
+        find_digits(it, &mut word, 10)?;
+        integer = false;
+    } else {
+        word.push(ch);
+        find_digits(it, &mut word, 10)?;
+        if it.peek() == Some('.') {
+            it.next();
+            word.push('.');
+            find_digits(it, &mut word, 10)?;
+            integer = false;
+        }
     }
-    if word == "++" {
-        return Ok(vec![Tok::PlusPlus]);
+
+    if let Some('e') | Some('E') = it.peek() {
+        it.next();
+        word.push('e');
+        integer = false;
+        if let Some(sign) = it.peek() {
+            if sign == '+' || sign == '-' {
+                it.next();
+                word.push(sign);
+            }
+        }
+        if find_digits(it, &mut word, 10)? == 0 {
+            return Err(LexError::new(LexErrorKind::InvalidNumber, "Missing exponent in numeric literal"));
+        }
     }
 
-    return Err(LexError::Error { text: String::from("Plus Error") });
+    find_number_end(it, word, 10, integer)
 }
 
-fn find_let(it: &mut StringIterator) -> Result<Vec<Tok>, LexError> {
-    let mut word = String::from("");
-    loop {
-        let ch = it.next();
-        match ch {
-            Ok(ch) => {
-                if ch != ' ' {
-                    word.push(ch);
-                }
+fn next_raw(it: &mut LineCharIterator, raw: &mut String) -> Option<char> {
+    let (_, _, ch) = it.next()?;
+    raw.push(ch);
+    Some(ch)
+}
 
-                if word.len() > 0 && ch == ' ' {
-                    break;
-                }
+fn find_hex_digits(it: &mut LineCharIterator, raw: &mut String, count: usize) -> Result<u32, LexError> {
+    let mut value = 0;
+    for _ in 0..count {
+        // a short escape like "\x4" leaves the quote after it to end the string
+        match it.peek() {
+            Some(ch) if ch.is_ascii_hexdigit() => {
+                next_raw(it, raw);
+                value = value * 16 + ch.to_digit(16).unwrap();
             }
-            Err(_) => {
-                break
+            _ => {
+                return Err(LexError::new(LexErrorKind::InvalidEscape, "Invalid hexadecimal escape sequence"));
             }
         }
     }
-    word = word.trim().parse().unwrap();
-    return Ok(vec![Tok::Let, Tok::Name { name: word }]);
+    Ok(value)
 }
 
-fn find_const(it: &mut StringIterator) -> Result<Vec<Tok>, LexError> {
-    let mut word = String::from("");
-    loop {
-        let ch = it.next();
-        match ch {
-            Ok(ch) => {
-                if ch != ' ' {
-                    word.push(ch);
+fn find_unicode_escape(it: &mut LineCharIterator, raw: &mut String) -> Result<char, LexError> {
+    let invalid = || LexError::new(LexErrorKind::InvalidEscape, "Invalid Unicode escape sequence");
+
+    let value = if let Some('{') = it.peek() {
+        next_raw(it, raw);
+        let mut value: u32 = 0;
+        let mut digits = 0;
+        loop {
+            match it.peek() {
+                Some('}') if digits > 0 => {
+                    next_raw(it, raw);
+                    break;
+                }
+                Some(ch) if ch.is_ascii_hexdigit() => {
+                    next_raw(it, raw);
+                    value = value * 16 + ch.to_digit(16).unwrap();
+                    digits += 1;
+                    if value > 0x10FFFF {
+                        return Err(invalid());
+                    }
                 }
+                _ => return Err(invalid())
+            }
+        }
+        value
+    } else {
+        find_hex_digits(it, raw, 4).map_err(|_| invalid())?
+    };
+
+    // "\uD83D\uDE00" is one character written as its UTF-16 halves
+    if (0xD800..=0xDBFF).contains(&value) {
+        if let Some(low) = find_low_surrogate(it, raw) {
+            return Ok(std::char::from_u32(0x10000 + ((value - 0xD800) << 10) + (low - 0xDC00)).unwrap());
+        }
+    }
 
-                if word.len() > 0 && ch == ' ' {
-                    break;
+    // lone surrogates can't live in a rust String, swap them for the replacement character
+    Ok(std::char::from_u32(value).unwrap_or('\u{FFFD}'))
+}
+
+/// Reads a `\uDC00`-`\uDFFF` escape if that is what comes next, otherwise steps back over
+/// whatever was read.
+fn find_low_surrogate(it: &mut LineCharIterator, raw: &mut String) -> Option<u32> {
+    fn read(it: &mut LineCharIterator, raw: &mut String) -> Option<u32> {
+        if next_raw(it, raw)? != '\\' || next_raw(it, raw)? != 'u' {
+            return None;
+        }
+        let value = find_hex_digits(it, raw, 4).ok()?;
+        if (0xDC00..=0xDFFF).contains(&value) { Some(value) } else { None }
+    }
+
+    let mark = raw.len();
+    let low = read(it, raw);
+    if low.is_none() {
+        for _ in raw[mark..].chars() {
+            it.prev();
+        }
+        raw.truncate(mark);
+    }
+    low
+}
+
+/// Reads the escape sequence after a `\`, pushing its value to `word` and its source to `raw`.
+fn find_escape(it: &mut LineCharIterator, word: &mut String, raw: &mut String) -> Result<(), LexError> {
+    let ch = match next_raw(it, raw) {
+        Some(ch) => ch,
+        None => return Err(LexError::new(LexErrorKind::UnterminatedString, "Unterminated string"))
+    };
+
+    match ch {
+        'n' => word.push('\n'),
+        't' => word.push('\t'),
+        'r' => word.push('\r'),
+        'b' => word.push('\u{8}'),
+        'f' => word.push('\u{c}'),
+        'v' => word.push('\u{b}'),
+        // \0 is the null character, longer runs are legacy octal escapes
+        '0'..='7' => {
+            let mut value = ch.to_digit(8).unwrap();
+            let max_len = if ch <= '3' { 3 } else { 2 };
+            for _ in 1..max_len {
+                match it.peek() {
+                    Some(digit) if ('0'..='7').contains(&digit) => {
+                        next_raw(it, raw);
+                        value = value * 8 + digit.to_digit(8).unwrap();
+                    }
+                    _ => break
                 }
             }
-            Err(_) => {
-                break
+            word.push(std::char::from_u32(value).unwrap());
+        }
+        'x' => {
+            let value = find_hex_digits(it, raw, 2)?;
+            word.push(std::char::from_u32(value).unwrap());
+        }
+        'u' => word.push(find_unicode_escape(it, raw)?),
+        // line continuation, the backslash and the line terminator are both dropped
+        '\r' => {
+            if let Some('\n') = it.peek() {
+                next_raw(it, raw);
             }
         }
+        '\n' | '\u{2028}' | '\u{2029}' => {}
+        _ => word.push(ch)
     }
-    word = word.trim().parse().unwrap();
-    return Ok(vec![Tok::Const, Tok::Name { name: word }]);
+    Ok(())
 }
 
-#[allow(unused_must_use)]
-fn find_gt_lt(it: &mut StringIterator, ch: char) -> Result<Vec<Tok>, LexError> {
+fn find_string(it: &mut LineCharIterator, file: &Rc<str>, quote: char) -> Result<Tok, LexError> {
     let mut word = String::from("");
-    word.push(ch);
-
+    let mut raw = String::from("");
+    // a bad escape is reported once the closing quote is found, so lexing resumes after it
+    let mut escape_error = None;
     loop {
-        let ch = it.next();
-        match ch {
-            Ok(ch) => {
-                if ch != '<' && ch != '>' && ch != '=' {
-                    it.prev();
-                    break
+        let location = it.location();
+        match it.next() {
+            Some((_, _, ch)) => {
+                if ch == quote {
+                    break;
+                }
+
+                match ch {
+                    '\\' => if let Err(e) = find_escape(it, &mut word, &mut raw) {
+                        escape_error = escape_error.or(Some(e.at(span_from(it, file, location))));
+                    },
+                    '\r' | '\n' => {
+                        // leave the line break to end the statement
+                        it.prev();
+                        return Err(LexError::new(LexErrorKind::UnterminatedString, "Unterminated string"));
+                    }
+                    _ => word.push(ch)
                 }
-                word.push(ch);
             }
-            Err(_) => {
-                break
+            None => {
+                return Err(LexError::new(LexErrorKind::UnterminatedString, "Unterminated string"));
             }
         }
     }
-    word = word.trim().parse().unwrap();
-    if word == ">" {
-        return Ok(vec![Tok::Greater]);
-    }
-    if word == ">=" {
-        return Ok(vec![Tok::GreaterEqual]);
-    }
-    if word == "<" {
-        return Ok(vec![Tok::Less]);
-    }
-    if word == "<=" {
-        return Ok(vec![Tok::LessEqual]);
-    }
-    if word == "<<" {
-        return Ok(vec![Tok::LeftShift]);
-    }
-    if word == ">>" {
-        return Ok(vec![Tok::RightShift]);
-    }
-    if word == "<<=" {
-        return Ok(vec![Tok::LeftShiftEqual]);
+    match escape_error {
+        Some(e) => Err(e),
+        None => Ok(Tok::String { value: word })
     }
-    if word == ">>=" {
-        return Ok(vec![Tok::RightShiftEqual]);
-    }
-    if word == ">>>" {
-        return Ok(vec![Tok::RightShiftUnsigned]);
-    }
-    if word == ">>>=" {
-        return Ok(vec![Tok::RightShiftUnsignedEqual]);
-    }
-    return Err(LexError::Error { text: String::from("Unexpected Token") });
 }
 
-#[allow(unused_must_use)]
-fn find_if(it: &mut StringIterator) -> Result<Vec<Tok>, LexError> {
-    it.prev();
-    let mut parens = vec![];
-
-    // find the first paren
+/// Reads a template chunk up to the closing backtick or the next `${`. `templates` holds the
+/// open brace count of every enclosing substitution, a `}` at count 0 resumes the template.
+fn find_template(it: &mut LineCharIterator, file: &Rc<str>, templates: &mut Vec<usize>, head: bool) -> Result<Tok, LexError> {
+    let mut cooked = String::from("");
+    let mut raw = String::from("");
+    let mut escape_error = None;
+    let substitution;
     loop {
-        let ch = it.next();
-        match ch {
-            Ok(ch) => {
-                if ch == '(' {
-                    parens.push("(");
-                    break;
+        let location = it.location();
+        match it.next() {
+            Some((_, _, '`')) => {
+                substitution = false;
+                break;
+            }
+            Some((_, _, '$')) if it.peek() == Some('{') => {
+                it.next();
+                substitution = true;
+                break;
+            }
+            Some((_, _, '\\')) => {
+                raw.push('\\');
+                if let Err(e) = find_escape(it, &mut cooked, &mut raw) {
+                    escape_error = escape_error.or(Some(e.at(span_from(it, file, location))));
                 }
-                if ch != ' ' {
-                    return Err(LexError::Error { text: String::from("Syntax error") });
+            }
+            Some((_, _, '\r')) => {
+                if let Some('\n') = it.peek() {
+                    it.next();
                 }
+                cooked.push('\n');
+                raw.push('\n');
             }
-            Err(_) => {
-                return Err(LexError::Error { text: String::from("Syntax error") });
+            Some((_, _, ch)) => {
+                cooked.push(ch);
+                raw.push(ch);
+            }
+            None => {
+                return Err(LexError::new(LexErrorKind::UnterminatedTemplate, "Unterminated template literal"));
             }
         }
     }
 
-    let mut return_tokens = vec![Tok::If, Tok::Lpar];
+    // raw chunks always use \n line endings, even inside line continuations
+    let raw = raw.replace("\r\n", "\n").replace('\r', "\n");
+    if substitution {
+        templates.push(0);
+    }
+    if let Some(e) = escape_error {
+        return Err(e);
+    }
+    Ok(match (head, substitution) {
+        (true, false) => Tok::NoSubstitutionTemplate { cooked, raw },
+        (true, true) => Tok::TemplateHead { cooked, raw },
+        (false, true) => Tok::TemplateMiddle { cooked, raw },
+        (false, false) => Tok::TemplateTail { cooked, raw }
+    })
+}
+
+/// Whether a `/` after `prev` starts a regular expression rather than a division. A value
+/// before the slash makes it a division, anything else leaves room for an operand.
+pub(crate) fn regex_allowed(prev: Option<&Tok>) -> bool {
+    match prev {
+        None => true,
+        Some(Tok::Name { .. }) | Some(Tok::Float { .. }) | Some(Tok::BigInt { .. }) | Some(Tok::String { .. })
+        | Some(Tok::NoSubstitutionTemplate { .. }) | Some(Tok::TemplateTail { .. }) | Some(Tok::Regex { .. })
+        | Some(Tok::Rpar) | Some(Tok::Rsqb) | Some(Tok::PlusPlus) | Some(Tok::MinusMinus)
+        | Some(Tok::This) | Some(Tok::Super) | Some(Tok::Null) | Some(Tok::True) | Some(Tok::False) => false,
+        _ => true
+    }
+}
+
+/// Reads a regular expression literal after its opening `/`. The pattern is kept as written,
+/// a `/` inside a `[]` class or after a `\` doesn't end it.
+fn find_regex(it: &mut LineCharIterator) -> Result<Tok, LexError> {
+    let unterminated = || LexError::new(LexErrorKind::UnterminatedRegExp, "Unterminated regular expression");
+    let mut pattern = String::from("");
+    let mut in_class = false;
     loop {
-        let tokens = find_token(it);
-        match tokens {
-            Ok(tokens) => {
-                for token in tokens {
-                    if token == Tok::Lpar {
-                        parens.push("(");
-                    } else if token == Tok::Rpar {
-                        parens.pop();
+        let ch = match it.next() {
+            Some((_, _, ch)) => ch,
+            None => return Err(unterminated())
+        };
+        match ch {
+            '/' if !in_class => break,
+            '[' => in_class = true,
+            ']' => in_class = false,
+            '\\' => {
+                pattern.push(ch);
+                match it.next() {
+                    Some((_, _, '\r')) | Some((_, _, '\n')) | Some((_, _, '\u{2028}')) | Some((_, _, '\u{2029}')) | None => {
+                        return Err(unterminated());
                     }
-                    return_tokens.push(token);
+                    Some((_, _, escaped)) => pattern.push(escaped)
                 }
+                continue;
             }
-            _ => {
-                return Err(LexError::Error { text: String::from("Unexpected Token") });
-            }
+            '\r' | '\n' | '\u{2028}' | '\u{2029}' => return Err(unterminated()),
+            _ => {}
         }
+        pattern.push(ch);
+    }
 
-        if parens.len() == 0 {
+    let mut flags = String::from("");
+    while let Some(ch) = it.peek() {
+        if !is_id_continue(ch) && ch != '\\' {
             break;
         }
+        it.next();
+        if !"dgimsuyv".contains(ch) || flags.contains(ch) {
+            return Err(LexError::new(LexErrorKind::InvalidRegExpFlags, "Invalid regular expression flags"));
+        }
+        flags.push(ch);
     }
-    return Ok(return_tokens);
+    Ok(Tok::Regex { pattern, flags })
+}
+
+/// Every punctuator except the braces, which also open and close template substitutions.
+const PUNCTUATORS: &[(&str, Tok)] = &[
+    ("(", Tok::Lpar),
+    (")", Tok::Rpar),
+    ("[", Tok::Lsqb),
+    ("]", Tok::Rsqb),
+    (";", Tok::Semi),
+    (",", Tok::Comma),
+    (":", Tok::Colon),
+    (".", Tok::Dot),
+    ("...", Tok::Ellipsis),
+    ("?", Tok::Question),
+    ("?.", Tok::QuestionDot),
+    ("??", Tok::QuestionQuestion),
+    ("??=", Tok::QuestionQuestionEqual),
+    ("=", Tok::Equal),
+    ("==", Tok::EqEqual),
+    ("===", Tok::EqEqEual),
+    ("=>", Tok::RdoubleArrow),
+    ("!", Tok::Not),
+    ("!=", Tok::NotEqual),
+    ("!==", Tok::NotDoubleEqual),
+    ("<", Tok::Less),
+    ("<=", Tok::LessEqual),
+    ("<<", Tok::LeftShift),
+    ("<<=", Tok::LeftShiftEqual),
+    (">", Tok::Greater),
+    (">=", Tok::GreaterEqual),
+    (">>", Tok::RightShift),
+    (">>=", Tok::RightShiftEqual),
+    (">>>", Tok::RightShiftUnsigned),
+    (">>>=", Tok::RightShiftUnsignedEqual),
+    ("+", Tok::Plus),
+    ("++", Tok::PlusPlus),
+    ("+=", Tok::PlusEqual),
+    ("-", Tok::Minus),
+    ("--", Tok::MinusMinus),
+    ("-=", Tok::MinusEqual),
+    ("*", Tok::Star),
+    ("*=", Tok::StarEqual),
+    ("**", Tok::StarStar),
+    ("**=", Tok::StarStarEqual),
+    ("/", Tok::Bslash),
+    ("/=", Tok::BslashEqual),
+    ("%", Tok::Percent),
+    ("%=", Tok::PercentEqual),
+    ("&", Tok::Amper),
+    ("&=", Tok::AmperEqual),
+    ("&&", Tok::AmpAmp),
+    ("&&=", Tok::AmpAmpEqual),
+    ("|", Tok::Vbar),
+    ("|=", Tok::VbarEqual),
+    ("||", Tok::VbarVbar),
+    ("||=", Tok::VbarVbarEqual),
+    ("^", Tok::Circumflex),
+    ("^=", Tok::CircumflexEqual),
+    ("~", Tok::Tilde)
+];
+
+/// Source text of a punctuator token.
+pub(crate) fn punctuator_text(tok: &Tok) -> Option<&'static str> {
+    PUNCTUATORS.iter().find(|(_, punctuator)| punctuator == tok).map(|(text, _)| *text)
 }
 
-#[allow(unused_must_use)]
-fn find_end_of_line(it: &mut StringIterator) -> Result<Vec<Tok>, LexError> {
+/// Longest punctuator starting with `ch`, characters read past the match are given back.
+fn find_punctuator(it: &mut LineCharIterator, ch: char) -> Option<Tok> {
+    let mut word = ch.to_string();
+    let mut longest = None;
     loop {
-        let ch = it.next();
-        match ch {
-            Ok(ch) => {
-                if ch != '\r' && ch != '\n' {
-                    it.prev();
-                    break
-                }
-            }
-            Err(_) => {
-                break
-            }
+        if let Some((_, tok)) = PUNCTUATORS.iter().find(|(punctuator, _)| *punctuator == word) {
+            longest = Some((word.chars().count(), tok.clone()));
+        }
+        let longer = match it.peek() {
+            Some(next) => format!("{}{}", word, next),
+            None => break
+        };
+        if !PUNCTUATORS.iter().any(|(punctuator, _)| punctuator.starts_with(&longer)) {
+            break;
         }
+        it.next();
+        word = longer;
     }
-    return Ok(vec![Tok::EndOfLine])
-}
 
+    let matched = longest.as_ref().map_or(1, |(len, _)| *len);
+    for _ in matched..word.chars().count() {
+        it.prev();
+    }
 
+    match longest {
+        // a?.5:1 is a conditional, not optional chaining
+        Some((_, Tok::QuestionDot)) if it.peek().map_or(false, |next| next.is_ascii_digit()) => {
+            it.prev();
+            Some(Tok::Question)
+        }
+        Some((_, tok)) => Some(tok),
+        None => None
+    }
+}
 
-pub(crate) fn find_token(it: &mut StringIterator) -> Result<Vec<Tok>, LexError> {
-    let mut word = String::from("");
+fn find_end_of_line(it: &mut LineCharIterator) -> Result<Tok, LexError> {
+    while let Some('\r') | Some('\n') | Some('\u{2028}') | Some('\u{2029}') = it.peek() {
+        it.next();
+    }
+    return Ok(Tok::EndOfLine)
+}
 
+/// Reads the rest of a `//` or `#!` comment, leaving the line terminator for the next token.
+fn find_line_comment(it: &mut LineCharIterator) -> String {
+    let mut text = String::from("");
     loop {
-        let ch = it.next();
-        match ch {
-            Ok(ch) => {
-                if ch == '.' {
-                    if word.len() > 0 {
-                        return Ok(vec![Tok::Name { name: word }, Tok::Dot]);
-                    }
-                    return Ok(vec![Tok::Dot]);
-                }
-
-                if ch == '(' {
-                    if word.len() > 0 {
-                        if word == "for" {
-                            return Ok(vec![Tok::For, Tok::Lpar]);
-                        }
-                        if word == "function" {
-                            return Ok(vec![Tok::Function, Tok::Lpar]);
-                        }
-                        if word == "if" {
-                            return find_if(it);
-                        }
-                        if word == "else" {
-                            return Ok(vec![Tok::Else, Tok::Lpar]);
-                        }
-                        return Ok(vec![Tok::Name { name: word }, Tok::Lpar]);
-                    }
-                    return Ok(vec![Tok::Lpar]);
-                }
+        match it.peek() {
+            Some('\r') | Some('\n') | Some('\u{2028}') | Some('\u{2029}') | None => break,
+            Some(ch) => {
+                it.next();
+                text.push(ch);
+            }
+        }
+    }
+    text
+}
 
-                if ch == ' ' && word.len() > 0 {
-                    if word == "for" {
-                        return Ok(vec![Tok::For]);
-                    }
-                    if word == "let" {
-                        return find_let(it);
-                    }
-                    if word == "const" {
-                        return find_const(it);
-                    }
-                    if word == "return" {
-                        return Ok(vec![Tok::Return]);
-                    }
-                    if word == "function" {
-                        return Ok(vec![Tok::Function]);
-                    }
-                    if word == "if" {
-                        return find_if(it);
-                    }
-                    if word == "else" {
-                        return Ok(vec![Tok::Else]);
-                    }
-                    if word == "null" {
-                        return Ok(vec![Tok::Null]);
-                    }
-                    if word == "&&" {
-                        return Ok(vec![Tok::AmpAmp]);
-                    }
-                    return Ok(vec![Tok::Name { name: word }]);
+/// Reads a `/* */` comment, the bool is true when it spanned a line terminator.
+fn find_block_comment(it: &mut LineCharIterator) -> Result<(String, bool), LexError> {
+    let mut text = String::from("");
+    let mut multi_line = false;
+    loop {
+        match it.next() {
+            Some((_, _, '*')) if it.peek() == Some('/') => {
+                it.next();
+                return Ok((text, multi_line));
+            }
+            Some((_, _, ch)) => {
+                if let '\r' | '\n' | '\u{2028}' | '\u{2029}' = ch {
+                    multi_line = true;
                 }
+                text.push(ch);
+            }
+            None => {
+                return Err(LexError::new(LexErrorKind::UnterminatedComment, "Unterminated comment"));
+            }
+        }
+    }
+}
 
-                if ch == '=' {
-                    return find_equal(it);
-                }
+fn span_from(it: &LineCharIterator, file: &Rc<str>, (line, column, start): (usize, usize, usize)) -> Span {
+    let (_, _, end) = it.location();
+    Span::new(file.clone(), line + 1, column + 1, start, end)
+}
 
-                if ch == '"' {
-                    return find_string_double_quote(it);
-                }
+/// Skips whitespace and comments, collecting the comments. Returns an `EndOfLine` token when
+/// a block comment crossed a line, since it separates statements the same way a newline does.
+fn skip_trivia(it: &mut LineCharIterator, file: &Rc<str>, comments: &mut Vec<Comment>) -> Result<Option<Token>, LexError> {
+    loop {
+        while it.peek().map_or(false, is_whitespace) {
+            it.next();
+        }
 
-                if ch.is_numeric() {
-                    return find_float(it, ch);
+        let location = it.location();
+        match it.peek() {
+            Some('#') if location == (0, 0, 0) => {
+                it.next();
+                if it.peek() != Some('!') {
+                    it.prev();
+                    return Ok(None);
                 }
-
-                if ch == '\r' || ch == '\n' {
-                    let result = find_end_of_line(it);
-                    match result {
-                        Ok(mut tokens) => {
-                            if word.len() > 0 {
-                                if word == "return" {
-                                    tokens.insert(0, Tok::Return);
-                                }
-                                else if word == "else" {
-                                    tokens.insert(0, Tok::Else);
-                                }
-                                else if word == "null" {
-                                    tokens.insert(0, Tok::Null);
-                                }
-                                else {
-                                    tokens.insert(0, Tok::Name {name: String::from(&word)});
-                                }
-                            }
-                            return Ok(tokens)
-                        }
-                        Err(e) => {
-                            return Err(e)
-                        }
+                it.next();
+                let text = find_line_comment(it);
+                comments.push(Comment::new(CommentKind::Hashbang, text, span_from(it, file, location)));
+            }
+            Some('/') => {
+                it.next();
+                match it.peek() {
+                    Some('/') => {
+                        it.next();
+                        let text = find_line_comment(it);
+                        comments.push(Comment::new(CommentKind::Line, text, span_from(it, file, location)));
                     }
-                }
-
-                if ch == '>' || ch == '<' {
-                    let result = find_gt_lt(it, ch);
-                    match result {
-                        Ok(mut tokens) => {
-                            if word.len() > 0 {
-                                tokens.insert(0, Tok::Name {name: String::from(&word)});
-                            }
-                            return Ok(tokens)
+                    Some('*') => {
+                        it.next();
+                        let (text, multi_line) = find_block_comment(it)
+                            .map_err(|e| e.at(span_from(it, file, location)))?;
+                        comments.push(Comment::new(CommentKind::Block, text, span_from(it, file, location)));
+                        if multi_line {
+                            let tok = find_end_of_line(it)?;
+                            return Ok(Some(Token::new(tok, span_from(it, file, location))));
                         }
-                        Err(e) => {
-                            return Err(e)
-                        }
-                    }
-                }
-
-                if ch == ';' {
-                    if word.len() > 0 {
-                        return Ok(vec![Tok::Name {name: word}, Tok::Semi]);
                     }
-                    return Ok(vec![Tok::Semi]);
-                }
-
-                if ch == ':' {
-                    if word.len() > 0 {
-                        return Ok(vec![Tok::Name {name: word}, Tok::Colon]);
+                    _ => {
+                        it.prev();
+                        return Ok(None);
                     }
-                    return Ok(vec![Tok::Colon]);
-                }
-
-                if ch == ')' {
-                    if word.len() > 0 {
-                        return Ok(vec![Tok::Name {name: word}, Tok::Rpar]);
-                    }
-                    return Ok(vec![Tok::Rpar]);
-                }
-
-                if ch == '{' {
-                    return Ok(vec![Tok::Lbrace]);
                 }
+            }
+            _ => return Ok(None)
+        }
+    }
+}
 
-                if ch == '}' {
-                    return Ok(vec![Tok::Rbrace]);
-                }
+/// Reserved words. Contextual words like `of`, `get`, `set`, `async`, `static`, `from` and `as`
+/// are left as names for the parser to reinterpret where they have a meaning, and so are the
+/// words only reserved in strict code or modules, `implements`, `interface`, `package`,
+/// `private`, `protected`, `public`, `await` and `yield`.
+const KEYWORDS: &[(&str, Tok)] = &[
+    ("break", Tok::Break),
+    ("case", Tok::Case),
+    ("catch", Tok::Catch),
+    ("class", Tok::Class),
+    ("const", Tok::Const),
+    ("continue", Tok::Continue),
+    ("debugger", Tok::Debugger),
+    ("default", Tok::Default),
+    ("delete", Tok::Delete),
+    ("do", Tok::Do),
+    ("else", Tok::Else),
+    ("enum", Tok::Enum),
+    ("export", Tok::Export),
+    ("extends", Tok::Extends),
+    ("false", Tok::False),
+    ("finally", Tok::Finally),
+    ("for", Tok::For),
+    ("function", Tok::Function),
+    ("if", Tok::If),
+    ("import", Tok::Import),
+    ("in", Tok::In),
+    ("instanceof", Tok::InstanceOf),
+    ("let", Tok::Let),
+    ("new", Tok::New),
+    ("null", Tok::Null),
+    ("return", Tok::Return),
+    ("super", Tok::Super),
+    ("switch", Tok::Switch),
+    ("this", Tok::This),
+    ("throw", Tok::Throw),
+    ("true", Tok::True),
+    ("try", Tok::Try),
+    ("typeof", Tok::TypeOf),
+    ("var", Tok::Var),
+    ("void", Tok::Void),
+    ("while", Tok::While),
+    ("with", Tok::With),
+];
+
+/// Source text of a reserved word token.
+pub(crate) fn keyword_name(tok: &Tok) -> Option<&'static str> {
+    KEYWORDS.iter().find(|(_, keyword)| keyword == tok).map(|(name, _)| *name)
+}
 
-                if ch == '+' {
-                    let result = find_plus(it);
-                    match result {
-                        Ok(mut tokens) => {
-                            if word.len() > 0 {
-                                tokens.insert(0, Tok::Name {name: word});
-                            }
-                            return Ok(tokens);
-                        }
-                        Err(e) => {
-                            return Err(e)
-                        }
-                    }
-                }
+fn is_id_start(ch: char) -> bool {
+    UnicodeXID::is_xid_start(ch) || ch == '$' || ch == '_'
+}
 
-                if ch == '-' {
-                    if word.len() > 0 {
-                        return Ok(vec![Tok::Name {name: word}, Tok::Minus]);
-                    }
-                    return Ok(vec![Tok::Minus]);
-                }
+fn is_id_continue(ch: char) -> bool {
+    UnicodeXID::is_xid_continue(ch) || ch == '$' || ch == '\u{200C}' || ch == '\u{200D}'
+}
 
-                if ch == '*' {
-                    return Ok(vec![Tok::Star]);
-                }
+/// JS WhiteSpace, every Zs space plus tab, vertical tab, form feed and the byte order mark.
+fn is_whitespace(ch: char) -> bool {
+    match ch {
+        '\r' | '\n' | '\u{85}' | '\u{2028}' | '\u{2029}' => false,
+        '\u{FEFF}' => true,
+        _ => ch.is_whitespace()
+    }
+}
 
-                if ch == '/' {
-                    return Ok(vec![Tok::Bslash]);
-                }
+/// Reads an identifier or reserved word. `\u` escapes are decoded, and names are NFC
+/// normalised so `café` finds the same variable however the accent was written.
+fn find_word(it: &mut LineCharIterator, ch: char) -> Result<Tok, LexError> {
+    let mut word = String::from("");
+    let mut escaped = false;
+    let mut next = Some(ch);
+
+    while let Some(ch) = next {
+        let ch = if ch == '\\' {
+            escaped = true;
+            match it.next() {
+                Some((_, _, 'u')) => find_unicode_escape(it, &mut String::from(""))?,
+                _ => return Err(LexError::new(LexErrorKind::InvalidEscape, "Invalid Unicode escape sequence"))
+            }
+        } else {
+            ch
+        };
+
+        let valid = if word.is_empty() { is_id_start(ch) } else { is_id_continue(ch) };
+        if !valid && escaped {
+            return Err(LexError::new(LexErrorKind::InvalidEscape, "Invalid Unicode escape sequence"));
+        } else if !valid {
+            return Err(LexError::new(LexErrorKind::InvalidCharacter, "Invalid or unexpected token"));
+        }
+        word.push(ch);
 
-                if ch == '[' {
-                    return Ok(vec![Tok::Lsqb]);
-                }
+        next = match it.peek() {
+            Some(ch) if ch == '\\' || is_id_continue(ch) => {
+                it.next();
+                Some(ch)
+            }
+            _ => None
+        };
+    }
 
-                if ch == ']' {
-                    return Ok(vec![Tok::Rsqb]);
-                }
+    let word = word.nfc().collect::<String>();
+    match KEYWORDS.iter().find(|(keyword, _)| *keyword == word) {
+        Some(_) if escaped => Err(LexError::new(LexErrorKind::EscapedKeyword, "Keyword must not contain escaped characters")),
+        Some((_, tok)) => Ok(tok.clone()),
+        None => Ok(Tok::Name { name: word })
+    }
+}
 
-                if ch == ',' {
-                    if word == "null" {
-                        return Ok(vec![Tok::Null, Tok::Comma]);
-                    }
-                    if word.len() > 0 {
-                        return Ok(vec![Tok::Name {name: word}, Tok::Comma]);
-                    }
-                    return Ok(vec![Tok::Comma]);
-                }
+/// Reads the next token, any comments before it are attached to it as trivia. Comments at the
+/// end of the input come back on a final `EndOfLine` token.
+/// `regex` is whether a `/` here starts a regular expression rather than a division.
+pub(crate) fn find_token(it: &mut LineCharIterator, file: &Rc<str>, templates: &mut Vec<usize>, regex: bool) -> Result<Token, LexError> {
+    let mut comments = vec![];
+    if let Some(mut token) = skip_trivia(it, file, &mut comments)? {
+        token.comments = comments;
+        return Ok(token);
+    }
 
-                if ch != ' ' && ch != '\n' && ch != '\r' {
-                    word.push(ch);
-                }
+    let location = it.location();
+    let ch = match it.next() {
+        Some((_, _, ch)) => ch,
+        None if comments.is_empty() => return Err(LexError::End),
+        None => {
+            let mut token = Token::new(Tok::EndOfLine, span_from(it, file, location));
+            token.comments = comments;
+            return Ok(token);
+        }
+    };
+
+    let tok = match ch {
+        '\r' | '\n' | '\u{2028}' | '\u{2029}' => find_end_of_line(it),
+        '"' | '\'' => find_string(it, file, ch),
+        '`' => find_template(it, file, templates, true),
+        '{' => {
+            if let Some(depth) = templates.last_mut() {
+                *depth += 1;
             }
-            Err(_) => {
-                if word.len() > 0 {
-                    return Ok(vec![Tok::Name {name: word}]);
+            Ok(Tok::Lbrace)
+        }
+        '}' => match templates.last_mut() {
+            Some(0) => {
+                templates.pop();
+                find_template(it, file, templates, false)
+            }
+            Some(depth) => {
+                *depth -= 1;
+                Ok(Tok::Rbrace)
+            }
+            None => Ok(Tok::Rbrace)
+        },
+        '.' if it.peek().map_or(false, |next| next.is_ascii_digit()) => find_number(it, ch),
+        '/' if regex => find_regex(it),
+        ch if ch.is_ascii_digit() => find_number(it, ch),
+        _ => match find_punctuator(it, ch) {
+            Some(tok) => Ok(tok),
+            None => find_word(it, ch)
+        }
+    };
+
+    let tok = match tok {
+        Ok(tok) => tok,
+        Err(e) => {
+            // skip the rest of a bad number or word so it is only reported once
+            if let LexError::Error { kind: LexErrorKind::InvalidNumber, .. }
+                | LexError::Error { kind: LexErrorKind::InvalidCharacter, .. }
+                | LexError::Error { kind: LexErrorKind::InvalidEscape, .. }
+                | LexError::Error { kind: LexErrorKind::EscapedKeyword, .. } = e {
+                while it.peek().map_or(false, |ch| ch == '.' || is_id_continue(ch)) {
+                    it.next();
                 }
-                return Err(LexError::End);
             }
+            return Err(e.within(span_from(it, file, location)));
         }
-    }
-}
\ No newline at end of file
+    };
+
+    let mut token = Token::new(tok, span_from(it, file, location));
+    token.comments = comments;
+    Ok(token)
+}
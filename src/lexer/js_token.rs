@@ -1,3 +1,6 @@
+use crate::lexer::span::Span;
+use crate::lexer::trivia::Comment;
+
 #[allow(dead_code)]
 #[derive(Debug, PartialEq, Clone)]
 pub(crate) enum Tok {
@@ -51,7 +54,13 @@ pub(crate) enum Tok {
 
     Name { name: String },
     Float { value: f64 },
+    BigInt { value: String }, // decimal digits of 123n
     String { value: String },
+    NoSubstitutionTemplate { cooked: String, raw: String }, // `...`
+    TemplateHead { cooked: String, raw: String }, // `...${
+    TemplateMiddle { cooked: String, raw: String }, // }...${
+    TemplateTail { cooked: String, raw: String }, // }...`
+    Regex { pattern: String, flags: String }, // /pattern/flags
     StartProgram,
     StartStatement,
     StartExpression,
@@ -73,13 +82,28 @@ pub(crate) enum Tok {
     BslashEqual, // /=
     BslashBslash, // //
     Vbar,  // '|'
+    VbarVbar, // ||
+    VbarEqual, // |=
+    VbarVbarEqual, // ||=
     Amper, // '&'
+    AmperEqual, // &=
     AmpAmp, // &&
+    AmpAmpEqual, // &&=
+    Circumflex, // ^
+    CircumflexEqual, // ^=
+    Tilde, // ~
+    Not, // !
+    Question, // ?
+    QuestionDot, // ?.
+    QuestionQuestion, // ??
+    QuestionQuestionEqual, // ??=
+    Ellipsis, // ...
     Less, // <
     Greater, // >
     Equal, // =
     Dot, // .
     Percent, // %
+    PercentEqual, // %=
     Lbrace, // {
     Rbrace, // }
     EqEqual, // ==
@@ -95,8 +119,44 @@ pub(crate) enum Tok {
     RightShiftUnsigned, // >>>
     RightShiftUnsignedEqual, // >>>=
     PlusEqual, // +=
+    MinusMinus, // --
     MinusEqual, // -=
     StarEqual, // *=
+    StarStar, // **
+    StarStarEqual, // **=
     RsingleArrow, // ->
     RdoubleArrow // =>
 }
+
+
+/// A `Tok` together with the place in the source it was read from and the comments
+/// that came before it.
+#[derive(Debug, Clone)]
+pub(crate) struct Token {
+    pub(crate) tok: Tok,
+    pub(crate) span: Span,
+    pub(crate) comments: Vec<Comment>
+}
+
+impl Token {
+    pub(crate) fn new(tok: Tok, span: Span) -> Token {
+        Token {
+            tok,
+            span,
+            comments: vec![]
+        }
+    }
+}
+
+// like spans, comments are trivia and don't change which token this is
+impl PartialEq for Token {
+    fn eq(&self, other: &Self) -> bool {
+        self.tok == other.tok
+    }
+}
+
+impl PartialEq<Tok> for Token {
+    fn eq(&self, other: &Tok) -> bool {
+        &self.tok == other
+    }
+}
@@ -0,0 +1,180 @@
+use crate::lexer::find_token::{keyword_name, punctuator_text};
+use crate::lexer::js_token::{Tok, Token};
+
+/// `text` as a quoted JSON string.
+fn json_string(text: &str) -> String {
+    let mut out = String::from("\"");
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => out.push(ch)
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// The `Tok` variant name, `Name` for `Tok::Name { .. }`.
+fn kind(tok: &Tok) -> &'static str {
+    match tok {
+        Tok::Await => "Await",
+        Tok::Break => "Break",
+        Tok::Case => "Case",
+        Tok::Catch => "Catch",
+        Tok::Class => "Class",
+        Tok::Const => "Const",
+        Tok::Continue => "Continue",
+        Tok::Debugger => "Debugger",
+        Tok::Default => "Default",
+        Tok::Delete => "Delete",
+        Tok::Do => "Do",
+        Tok::Else => "Else",
+        Tok::Enum => "Enum",
+        Tok::Export => "Export",
+        Tok::Extends => "Extends",
+        Tok::False => "False",
+        Tok::Finally => "Finally",
+        Tok::For => "For",
+        Tok::Function => "Function",
+        Tok::AnonFunction => "AnonFunction",
+        Tok::If => "If",
+        Tok::Implements => "Implements",
+        Tok::Import => "Import",
+        Tok::In => "In",
+        Tok::InstanceOf => "InstanceOf",
+        Tok::Interface => "Interface",
+        Tok::Let => "Let",
+        Tok::New => "New",
+        Tok::Null => "Null",
+        Tok::Package => "Package",
+        Tok::Private => "Private",
+        Tok::Protected => "Protected",
+        Tok::Public => "Public",
+        Tok::Return => "Return",
+        Tok::Super => "Super",
+        Tok::Switch => "Switch",
+        Tok::Static => "Static",
+        Tok::This => "This",
+        Tok::Throw => "Throw",
+        Tok::Try => "Try",
+        Tok::True => "True",
+        Tok::TypeOf => "TypeOf",
+        Tok::Var => "Var",
+        Tok::Void => "Void",
+        Tok::While => "While",
+        Tok::With => "With",
+        Tok::Yield => "Yield",
+        Tok::Name { .. } => "Name",
+        Tok::Float { .. } => "Float",
+        Tok::BigInt { .. } => "BigInt",
+        Tok::String { .. } => "String",
+        Tok::NoSubstitutionTemplate { .. } => "NoSubstitutionTemplate",
+        Tok::TemplateHead { .. } => "TemplateHead",
+        Tok::TemplateMiddle { .. } => "TemplateMiddle",
+        Tok::TemplateTail { .. } => "TemplateTail",
+        Tok::Regex { .. } => "Regex",
+        Tok::StartProgram => "StartProgram",
+        Tok::StartStatement => "StartStatement",
+        Tok::StartExpression => "StartExpression",
+        Tok::EndOfLine => "EndOfLine",
+        Tok::EndOfFile => "EndOfFile",
+        Tok::Lpar => "Lpar",
+        Tok::Rpar => "Rpar",
+        Tok::Lsqb => "Lsqb",
+        Tok::Rsqb => "Rsqb",
+        Tok::Colon => "Colon",
+        Tok::Comma => "Comma",
+        Tok::Semi => "Semi",
+        Tok::Plus => "Plus",
+        Tok::PlusPlus => "PlusPlus",
+        Tok::Minus => "Minus",
+        Tok::Star => "Star",
+        Tok::Slash => "Slash",
+        Tok::Bslash => "Bslash",
+        Tok::BslashEqual => "BslashEqual",
+        Tok::BslashBslash => "BslashBslash",
+        Tok::Vbar => "Vbar",
+        Tok::VbarVbar => "VbarVbar",
+        Tok::VbarEqual => "VbarEqual",
+        Tok::VbarVbarEqual => "VbarVbarEqual",
+        Tok::Amper => "Amper",
+        Tok::AmperEqual => "AmperEqual",
+        Tok::AmpAmp => "AmpAmp",
+        Tok::AmpAmpEqual => "AmpAmpEqual",
+        Tok::Circumflex => "Circumflex",
+        Tok::CircumflexEqual => "CircumflexEqual",
+        Tok::Tilde => "Tilde",
+        Tok::Not => "Not",
+        Tok::Question => "Question",
+        Tok::QuestionDot => "QuestionDot",
+        Tok::QuestionQuestion => "QuestionQuestion",
+        Tok::QuestionQuestionEqual => "QuestionQuestionEqual",
+        Tok::Ellipsis => "Ellipsis",
+        Tok::Less => "Less",
+        Tok::Greater => "Greater",
+        Tok::Equal => "Equal",
+        Tok::Dot => "Dot",
+        Tok::Percent => "Percent",
+        Tok::PercentEqual => "PercentEqual",
+        Tok::Lbrace => "Lbrace",
+        Tok::Rbrace => "Rbrace",
+        Tok::EqEqual => "EqEqual",
+        Tok::EqEqEual => "EqEqEual",
+        Tok::NotEqual => "NotEqual",
+        Tok::NotDoubleEqual => "NotDoubleEqual",
+        Tok::LessEqual => "LessEqual",
+        Tok::GreaterEqual => "GreaterEqual",
+        Tok::LeftShift => "LeftShift",
+        Tok::RightShift => "RightShift",
+        Tok::LeftShiftEqual => "LeftShiftEqual",
+        Tok::RightShiftEqual => "RightShiftEqual",
+        Tok::RightShiftUnsigned => "RightShiftUnsigned",
+        Tok::RightShiftUnsignedEqual => "RightShiftUnsignedEqual",
+        Tok::PlusEqual => "PlusEqual",
+        Tok::MinusMinus => "MinusMinus",
+        Tok::MinusEqual => "MinusEqual",
+        Tok::StarEqual => "StarEqual",
+        Tok::StarStar => "StarStar",
+        Tok::StarStarEqual => "StarStarEqual",
+        Tok::RsingleArrow => "RsingleArrow",
+        Tok::RdoubleArrow => "RdoubleArrow"
+    }
+}
+
+/// What the token stands for: the name, string or number it holds, the source text of a
+/// keyword or punctuator, `null` for line breaks.
+fn value(tok: &Tok) -> String {
+    match tok {
+        Tok::Name { name } => json_string(name),
+        Tok::String { value } | Tok::BigInt { value } => json_string(value),
+        Tok::Float { value } if value.is_finite() => value.to_string(),
+        Tok::Float { .. } => json_string("Infinity"),
+        Tok::NoSubstitutionTemplate { cooked, .. } | Tok::TemplateHead { cooked, .. }
+        | Tok::TemplateMiddle { cooked, .. } | Tok::TemplateTail { cooked, .. } => json_string(cooked),
+        Tok::Regex { pattern, flags } => json_string(&format!("/{}/{}", pattern, flags)),
+        tok => match keyword_name(tok).or_else(|| punctuator_text(tok)) {
+            Some(text) => json_string(text),
+            None => String::from("null")
+        }
+    }
+}
+
+/// One line of `--tokens` output.
+pub(crate) fn token_to_json(token: &Token) -> String {
+    let span = &token.span;
+    format!(
+        "{{\"kind\":{},\"value\":{},\"span\":{{\"file\":{},\"line\":{},\"column\":{},\"start\":{},\"end\":{}}}}}",
+        json_string(kind(&token.tok)),
+        value(&token.tok),
+        json_string(&span.file),
+        span.line,
+        span.column,
+        span.start,
+        span.end
+    )
+}
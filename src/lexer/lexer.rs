@@ -1,175 +1,167 @@
-use crate::lexer::find_token::find_token;
-use crate::lexer::js_token::Tok;
-use crate::lexer::string_iterator::StringIterator;
+use std::fmt;
+use std::io::{self, BufRead, Cursor};
+use std::rc::Rc;
 
+use crate::lexer::find_token::{find_token, keyword_name, regex_allowed};
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::line_char_iterator::LineCharIterator;
+use crate::lexer::span::Span;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum LexErrorKind {
+    UnterminatedString,
+    UnterminatedTemplate,
+    UnterminatedRegExp,
+    UnterminatedComment,
+    InvalidCharacter,
+    InvalidEscape,
+    InvalidNumber,
+    InvalidRegExpFlags,
+    EscapedKeyword
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub(crate) enum LexError {
-    Error { text: String },
+    Error { kind: LexErrorKind, text: String, span: Span },
     End
 }
 
+impl LexError {
+    /// Error without a position yet, `find_token` gives it the span of the token it was reading.
+    pub(crate) fn new(kind: LexErrorKind, text: &str) -> LexError {
+        LexError::Error { kind, text: String::from(text), span: Span::default() }
+    }
+
+    pub(crate) fn at(self, span: Span) -> LexError {
+        match self {
+            LexError::Error { kind, text, .. } => LexError::Error { kind, text, span },
+            LexError::End => LexError::End
+        }
+    }
+
+    /// Places the error at the span of the token it was found in, unless it already points
+    /// at the part that was wrong, like a bad escape inside a string.
+    pub(crate) fn within(self, span: Span) -> LexError {
+        match self {
+            LexError::Error { span: ref placed, .. } if !placed.file.is_empty() => self,
+            error => error.at(span)
+        }
+    }
+}
+
+impl fmt::Display for LexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LexError::Error { text, span, .. } => write!(f, "{}: SyntaxError: {}", span, text),
+            LexError::End => write!(f, "end of input")
+        }
+    }
+}
+
+/// Reads tokens one at a time from a character stream, only the token being read is held in
+/// memory. `lex` runs it over a whole string.
 pub(crate) struct Lexer {
-    pub(crate) tokens: Vec<Tok>
+    file: Rc<str>,
+    it: LineCharIterator,
+    templates: Vec<usize>,
+    // the last token that wasn't a line break
+    prev: Option<Tok>,
+    // one entry per open `(`, true when it holds the condition of if, for, while or with
+    parens: Vec<bool>,
+    // `prev` is the `)` closing such a condition, a statement starts after it
+    after_head: bool
 }
 
 impl Lexer {
 
+    #[allow(dead_code)]
     pub fn new() -> Lexer {
+        Lexer::with_file("<anonymous>")
+    }
+
+    /// Lexer whose token spans point into `file_name`.
+    pub fn with_file(file_name: &str) -> Lexer {
+        Lexer::from_reader(file_name, io::empty())
+    }
+
+    /// Lexer pulling characters from `reader` as tokens are asked for.
+    pub fn from_reader<R: BufRead + 'static>(file_name: &str, reader: R) -> Lexer {
         Lexer {
-            tokens: Vec::new()
+            file: Rc::from(file_name),
+            it: LineCharIterator::from_reader(reader),
+            templates: Vec::new(),
+            prev: None,
+            parens: Vec::new(),
+            after_head: false
         }
     }
 
-    fn copy(&mut self) -> Vec<Tok> {
+    /// Lexes a whole file. A bad token is reported and skipped, so every error in the file
+    /// comes back from one call.
+    #[allow(dead_code)]
+    pub fn lex(&mut self, file: String) -> Result<Vec<Token>, Vec<LexError>> {
+        self.it = LineCharIterator::from_reader(Cursor::new(file.into_bytes()));
+        self.templates.clear();
+        self.prev = None;
+        self.parens.clear();
+        self.after_head = false;
+        self.collect_tokens()
+    }
+
+    /// Reads every token left in the input, carrying on past errors like `lex`.
+    pub fn collect_tokens(&mut self) -> Result<Vec<Token>, Vec<LexError>> {
         let mut tokens = vec![];
-        loop {
-            let ex = self.tokens.pop();
-            match ex {
-                Some(token) => {
-                    tokens.push(token);
-                }
-                None => {
-                    break
-                }
+        let mut errors = vec![];
+        while let Some(token) = self.next() {
+            match token {
+                Ok(token) => tokens.push(token),
+                Err(e) => errors.push(e)
             }
         }
-        tokens.reverse();
-        return tokens;
+        if errors.is_empty() {
+            Ok(tokens)
+        } else {
+            Err(errors)
+        }
     }
 
-    pub fn lex(&mut self, file: String) -> Vec<Tok> {
-        let mut it = StringIterator::new(file.chars());
-
-        loop {
-            let token = find_token(&mut it);
-
-            match token {
-                Ok(tokens) => {
-                    for token in tokens {
-                        self.add_token(token);
-                    }
-                }
-                Err(e) => {
-                    match e {
-                        LexError::Error { text } => {
-                            self.test();
-                            println!("{:?}", text);
-                        },
-                        LexError::End => {
-                            return self.copy();
-                        }
-                    }
-                    break
-                }
+    fn add_token(&mut self, mut token: Token) -> Token {
+        // reserved words are fine as property names, obj.return is a member access even with
+        // a line break after the dot
+        if let Some(Tok::Dot) | Some(Tok::QuestionDot) = self.prev {
+            if let Some(name) = keyword_name(&token.tok) {
+                token.tok = Tok::Name { name: String::from(name) };
             }
         }
-        return self.copy();
+        if token.tok != Tok::EndOfLine {
+            self.after_head = match token.tok {
+                Tok::Lpar => {
+                    let head = match self.prev {
+                        Some(Tok::If) | Some(Tok::For) | Some(Tok::While) | Some(Tok::With) => true,
+                        _ => false
+                    };
+                    self.parens.push(head);
+                    false
+                }
+                Tok::Rpar => self.parens.pop().unwrap_or(false),
+                _ => false
+            };
+            self.prev = Some(token.tok.clone());
+        }
+        token
     }
+}
 
-    pub(crate) fn add_token(&mut self, token: Tok) {
-        self.tokens.push(token);
-    }
+impl Iterator for Lexer {
+    type Item = Result<Token, LexError>;
 
-    pub fn test(&self) {
-        for token in self.tokens.iter() {
-            match token {
-                Tok::Name {name} => println!("Name: {}", name),
-                Tok::Await => println!("{}", "Await"),
-                Tok::Break => println!("{}", "Break"),
-                Tok::Case => println!("{}", "Case"),
-                Tok::Catch => println!("{}", "Catch"),
-                Tok::Class => println!("{}", "Class"),
-                Tok::Const => println!("{}", "Const"),
-                Tok::Continue => println!("{}", "Continue"),
-                Tok::Debugger => println!("{}", "Debugger"),
-                Tok::Default => println!("{}", "Default"),
-                Tok::Delete => println!("{}", "Delete"),
-                Tok::Do => println!("{}", "Do"),
-                Tok::Else => println!("{}", "Else"),
-                Tok::Enum => println!("{}", "Enum"),
-                Tok::Export => println!("{}", "Export"),
-                Tok::Extends => println!("{}", "Extends"),
-                Tok::False => println!("{}", "False"),
-                Tok::Finally => println!("{}", "Finally"),
-                Tok::For => println!("{}", "For"),
-                Tok::Function => println!("{}", "Function"),
-                Tok::AnonFunction => println!("{}", "AnonFunction"),
-                Tok::If => println!("{}", "If"),
-                Tok::Implements => println!("{}", "Implements"),
-                Tok::Import => println!("{}", "Import"),
-                Tok::In => println!("{}", "In"),
-                Tok::InstanceOf => println!("{}", "InstanceOf"),
-                Tok::Interface => println!("{}", "Interface"),
-                Tok::Let => println!("{}", "Let"),
-                Tok::New => println!("{}", "New"),
-                Tok::Null => println!("{}", "Null"),
-                Tok::Package => println!("{}", "Package"),
-                Tok::Private => println!("{}", "Private"),
-                Tok::Protected => println!("{}", "Protected"),
-                Tok::Public => println!("{}", "Public"),
-                Tok::Return => println!("{}", "Return"),
-                Tok::Super => println!("{}", "Super"),
-                Tok::Switch => println!("{}", "Switch"),
-                Tok::Static => println!("{}", "Static"),
-                Tok::This => println!("{}", "This"),
-                Tok::Throw => println!("{}", "Throw"),
-                Tok::Try => println!("{}", "Try"),
-                Tok::True => println!("{}", "True"),
-                Tok::TypeOf => println!("{}", "TypeOf"),
-                Tok::Var => println!("{}", "Var"),
-                Tok::Void => println!("{}", "Void"),
-                Tok::While => println!("{}", "While"),
-                Tok::With => println!("{}", "With"),
-                Tok::Yield => println!("{}", "Yield"),
-                Tok::Float { value } => println!("Float: {}", value),
-                Tok::String { value } => println!("String: {}", value),
-                Tok::StartProgram => println!("{}", "StartProgram"),
-                Tok::StartStatement => println!("{}", "StartStatement"),
-                Tok::StartExpression => println!("{}", "StartExpression"),
-                Tok::EndOfFile => println!("{}", "EndOfFile"),
-                Tok::Lpar => println!("{}", "Lpar"),
-                Tok::Rpar => println!("{}", "Rpar"),
-                Tok::Lsqb => println!("{}", "Lsqb"),
-                Tok::Rsqb => println!("{}", "Rsqb"),
-                Tok::Colon => println!("{}", "Colon"),
-                Tok::Comma => println!("{}", "Comma"),
-                Tok::Semi => println!("{}", "Semi"),
-                Tok::Plus => println!("{}", "Plus"),
-                Tok::Minus => println!("{}", "Minus"),
-                Tok::Star => println!("{}", "Star"),
-                Tok::Slash => println!("{}", "Slash"),
-                Tok::Bslash => println!("{}", "Bslash"),
-                Tok::Vbar => println!("{}", "Vbar"),
-                Tok::Amper => println!("{}", "Amper"),
-                Tok::Less => println!("{}", "Less"),
-                Tok::Greater => println!("{}", "Greater"),
-                Tok::Equal => println!("{}", "Equal"),
-                Tok::Dot => println!("{}", "Dot"),
-                Tok::Percent => println!("{}", "Percent"),
-                Tok::Lbrace => println!("{}", "Lbrace"),
-                Tok::Rbrace => println!("{}", "Rbrace"),
-                Tok::EqEqual => println!("{}", "EqEqual"),
-                Tok::EqEqEual => println!("{}", "EqEqEual"),
-                Tok::NotEqual => println!("{}", "NotEqual"),
-                Tok::NotDoubleEqual => println!("{}", "NotDoubleEqual"),
-                Tok::LessEqual => println!("{}", "LessEqual"),
-                Tok::GreaterEqual => println!("{}", "GreaterEqual"),
-                Tok::LeftShift => println!("{}", "LeftShift"),
-                Tok::RightShift => println!("{}", "RightShift"),
-                Tok::PlusEqual => println!("{}", "PlusEqual"),
-                Tok::MinusEqual => println!("{}", "MinusEqual"),
-                Tok::StarEqual => println!("{}", "StarEqual"),
-                Tok::RsingleArrow => println!("{}", "RsingleArrow"),
-                Tok::RdoubleArrow => println!("{}", "RdoubleArrow"),
-                Tok::LeftShiftEqual => println!("{}", "LeftShiftEqual"),
-                Tok::RightShiftEqual => println!("{}", "RightShiftEqual"),
-                Tok::RightShiftUnsigned => println!("{}", "RigthShiftUnsigned"),
-                Tok::RightShiftUnsignedEqual => println!("{}", "RightShiftUnsignedEqual"),
-                Tok::EndOfLine => println!("{}", "EndOfLine"),
-                Tok::BslashEqual => println!("{}", "BslashEqual"),
-                Tok::PlusPlus => println!("{}", "PlusPlus"),
-                Tok::BslashBslash => println!("{}", "BslashBslash"),
-                Tok::AmpAmp => println!("{}", "AmpAmp")
-            }
+    fn next(&mut self) -> Option<Self::Item> {
+        // `if (x) /re/.test(y)`, the slash starts the statement so it can't be a division
+        let regex = self.after_head || regex_allowed(self.prev.as_ref());
+        match find_token(&mut self.it, &self.file, &mut self.templates, regex) {
+            Ok(token) => Some(Ok(self.add_token(token))),
+            Err(LexError::End) => None,
+            Err(e) => Some(Err(e))
         }
     }
-}
\ No newline at end of file
+}
@@ -1,57 +1,123 @@
-use std::path::Path;
-use std::fs::File;
-use std::error::Error;
-use std::io::{BufReader, BufRead};
+use std::collections::VecDeque;
+use std::io::BufRead;
 
+/// How many characters `prev` can step back over. The lexer never backs up further than the
+/// longest punctuator.
+pub(crate) const HISTORY: usize = 8;
 
 pub(crate) struct LineCharIterator {
+    reader: Box<dyn BufRead>,
+    // the last HISTORY characters read plus any stepped back over, with their line, column and
+    // byte offset. `pos` is the next one to hand out
+    chars: VecDeque<(usize, usize, usize, char)>,
+    pos: usize,
+    // location of the next character still in `reader`
     line_num: usize,
     char_num: usize,
-    lines: Vec<Vec<char>>
+    byte_offset: usize
 }
 
 impl LineCharIterator {
 
+    #[cfg(test)]
     pub(crate) fn new(file_name: &str) -> LineCharIterator {
-        let path = Path::new(file_name);
-        let display = path.display();
-
-        // Open the path in read-only mode, returns `io::Result<File>`
-        let file = match File::open(&path) {
-            // The `description` method of `io::Error` returns a string that describes the error
-            Err(why) => panic!("couldn't open {}: {}", display, Error::to_string(&why)),
+        let file = match std::fs::File::open(file_name) {
+            Err(why) => panic!("couldn't open {}: {}", file_name, why),
             Ok(file) => file,
         };
 
-        let reader = BufReader::new(file);
-        let mut lines = vec![];
+        LineCharIterator::from_reader(std::io::BufReader::new(file))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn from_string(code: &str) -> LineCharIterator {
+        LineCharIterator::from_reader(std::io::Cursor::new(code.to_string().into_bytes()))
+    }
+
+    /// Characters are decoded as they are needed, only `HISTORY` of them are kept.
+    pub(crate) fn from_reader<R: BufRead + 'static>(reader: R) -> LineCharIterator {
+        LineCharIterator {
+            reader: Box::new(reader),
+            chars: VecDeque::with_capacity(HISTORY + 1),
+            pos: 0,
+            line_num: 0,
+            char_num: 0,
+            byte_offset: 0
+        }
+    }
 
-        for line in reader.lines() {
-            if let Ok(string) = line {
-                let mut chars = string.chars().collect::<Vec<char>>();
-                chars.push('\n');
-                lines.push(chars);
+    fn read_byte(&mut self) -> Option<u8> {
+        let byte = *self.reader.fill_buf().ok()?.first()?;
+        self.reader.consume(1);
+        Some(byte)
+    }
+
+    /// Decodes one UTF-8 character from the reader, bad bytes come out as U+FFFD.
+    fn read_char(&mut self) -> Option<char> {
+        let first = self.read_byte()?;
+        let len = match first {
+            0x00..=0x7f => return Some(first as char),
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            0xf0..=0xf7 => 4,
+            _ => return Some(std::char::REPLACEMENT_CHARACTER)
+        };
+        let mut bytes = vec![first];
+        while bytes.len() < len {
+            match self.reader.fill_buf().ok().and_then(|buf| buf.first().copied()) {
+                Some(byte) if byte & 0xc0 == 0x80 => {
+                    self.reader.consume(1);
+                    bytes.push(byte);
+                }
+                _ => break
             }
         }
+        Some(std::str::from_utf8(&bytes).ok()
+            .and_then(|s| s.chars().next())
+            .unwrap_or(std::char::REPLACEMENT_CHARACTER))
+    }
 
-        LineCharIterator{
-            lines,
-            line_num: 0,
-            char_num: 0
+    /// Makes sure the character at `pos` is buffered, false at the end of the input.
+    fn fill(&mut self) -> bool {
+        if self.pos < self.chars.len() {
+            return true;
+        }
+        let ch = match self.read_char() {
+            Some(ch) => ch,
+            None => return false
+        };
+        self.chars.push_back((self.line_num, self.char_num, self.byte_offset, ch));
+        self.byte_offset += ch.len_utf8();
+        if ch == '\n' {
+            self.line_num += 1;
+            self.char_num = 0;
+        } else {
+            self.char_num += 1;
+        }
+        true
+    }
+
+    /// Line, column and byte offset of the next character, all 0 based.
+    pub(crate) fn location(&self) -> (usize, usize, usize) {
+        match self.chars.get(self.pos) {
+            Some((line, column, offset, _)) => (*line, *column, *offset),
+            None => (self.line_num, self.char_num, self.byte_offset)
         }
     }
 
+    pub(crate) fn peek(&mut self) -> Option<char> {
+        if !self.fill() {
+            return None;
+        }
+        self.chars.get(self.pos).map(|(_, _, _, ch)| *ch)
+    }
+
     pub(crate) fn prev(&mut self) -> Option<(usize, usize, char)> {
-        if self.char_num != 0 {
-            self.char_num -= 1;
-            return Some((self.line_num, self.char_num, self.lines[self.line_num][self.char_num]));
-        } else if self.line_num != 0 {
-            self.line_num -= 1;
-            self.char_num = self.lines[self.line_num].len() - 1;
-            return Some((self.line_num, self.char_num, self.lines[self.line_num][self.char_num]));
-        } else {
+        if self.pos == 0 {
             return None;
         }
+        self.pos -= 1;
+        self.chars.get(self.pos).map(|(line, column, _, ch)| (*line, *column, *ch))
     }
 }
 
@@ -59,25 +125,15 @@ impl Iterator for LineCharIterator {
     type Item = (usize, usize, char);
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if self.lines[self.line_num].len() > self.char_num {
-                let item = (self.line_num, self.char_num, self.lines[self.line_num][self.char_num]);
-                self.char_num += 1;
-                return Some(item);
-            } else if self.lines.len() > self.line_num + 1 {
-                self.char_num = 0;
-                self.line_num += 1;
-                if self.lines[self.line_num].len() > 0 {
-                    let item = (self.line_num, self.char_num, self.lines[self.line_num][self.char_num]);
-                    self.char_num += 1;
-                    return Some(item);
-                } else {
-                    self.line_num += 1;
-                    continue;
-                }
-            } else {
-                return None;
-            }
+        if !self.fill() {
+            return None;
+        }
+        let (line, column, _, ch) = self.chars[self.pos];
+        self.pos += 1;
+        while self.pos > HISTORY {
+            self.chars.pop_front();
+            self.pos -= 1;
         }
+        Some((line, column, ch))
     }
-}
\ No newline at end of file
+}
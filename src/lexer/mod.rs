@@ -1,6 +1,8 @@
 pub(crate) mod js_token;
-pub(crate) mod string_iterator;
 pub(crate) mod lexer;
 pub(crate) mod find_token;
 mod tests;
 pub(crate) mod line_char_iterator;
+pub(crate) mod span;
+pub(crate) mod trivia;
+pub(crate) mod json;
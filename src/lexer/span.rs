@@ -0,0 +1,66 @@
+use std::fmt;
+use std::rc::Rc;
+
+use crate::lexer::js_token::Token;
+
+/// Location of a token or node in the source text. `line` and `column` are 1-based,
+/// `start` and `end` are byte offsets into the source.
+#[derive(Debug, Clone)]
+pub(crate) struct Span {
+    pub(crate) file: Rc<str>,
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+    pub(crate) start: usize,
+    pub(crate) end: usize
+}
+
+impl Span {
+    pub(crate) fn new(file: Rc<str>, line: usize, column: usize, start: usize, end: usize) -> Span {
+        Span {
+            file,
+            line,
+            column,
+            start,
+            end
+        }
+    }
+
+    /// Span covering a run of tokens in either order, the default span when there are none.
+    pub(crate) fn of(tokens: &[Token]) -> Span {
+        match (tokens.first(), tokens.last()) {
+            (Some(first), Some(last)) if first.span.start <= last.span.start => first.span.to(&last.span),
+            (Some(first), Some(last)) => last.span.to(&first.span),
+            _ => Span::default()
+        }
+    }
+
+    /// Span covering everything from the start of `self` to the end of `other`.
+    pub(crate) fn to(&self, other: &Span) -> Span {
+        Span {
+            file: self.file.clone(),
+            line: self.line,
+            column: self.column,
+            start: self.start,
+            end: other.end
+        }
+    }
+}
+
+impl Default for Span {
+    fn default() -> Self {
+        Span::new(Rc::from(""), 0, 0, 0, 0)
+    }
+}
+
+// Spans are metadata, two nodes parsed from different places are still the same node.
+impl PartialEq for Span {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
@@ -7,7 +7,7 @@ fn test_number_array() {
     let file = fs::read_to_string("js/arrays/number_array.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 30);
     let expected = vec![
@@ -50,7 +50,7 @@ fn test_var_array() {
     let file = fs::read_to_string("js/arrays/variable_array.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 30);
     let expected = vec![
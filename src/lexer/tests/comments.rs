@@ -0,0 +1,79 @@
+use std::fs;
+use crate::lexer::lexer::Lexer;
+use crate::lexer::js_token::Tok;
+use crate::lexer::trivia::CommentKind;
+
+#[test]
+fn test_comments_are_skipped() {
+    let file = fs::read_to_string("js/comments/comments.js");
+
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+
+    assert_eq!(tokens, vec![
+        Tok::EndOfLine,
+        Tok::EndOfLine,
+        Tok::Let,
+        Tok::Name { name: String::from("a") },
+        Tok::Equal,
+        Tok::Float { value: 1. },
+        Tok::Semi,
+        Tok::EndOfLine,
+        Tok::Let,
+        Tok::Name { name: String::from("b") },
+        Tok::Equal,
+        Tok::Float { value: 4. },
+        Tok::Bslash,
+        Tok::Float { value: 2. },
+        Tok::Semi,
+        Tok::EndOfLine,
+        Tok::EndOfLine,
+        Tok::Name { name: String::from("console") },
+        Tok::Dot,
+        Tok::Name { name: String::from("log") },
+        Tok::Lpar,
+        Tok::Name { name: String::from("a") },
+        Tok::Plus,
+        Tok::Name { name: String::from("b") },
+        Tok::Rpar,
+        Tok::Semi,
+        Tok::EndOfLine,
+        Tok::EndOfLine
+    ]);
+}
+
+#[test]
+fn test_comments_are_kept_as_trivia() {
+    let file = fs::read_to_string("js/comments/comments.js");
+
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+
+    let comments = tokens.iter()
+        .flat_map(|token| token.comments.iter().map(move |comment| (&token.tok, comment)))
+        .map(|(tok, comment)| (tok.clone(), comment.kind.clone(), comment.text.clone(), comment.span.line))
+        .collect::<Vec<(Tok, CommentKind, String, usize)>>();
+
+    assert_eq!(comments, vec![
+        (Tok::EndOfLine, CommentKind::Hashbang, String::from("/usr/bin/env node"), 1),
+        (Tok::EndOfLine, CommentKind::Line, String::from(" say hello"), 2),
+        (Tok::EndOfLine, CommentKind::Line, String::from(" trailing"), 3),
+        (Tok::Let, CommentKind::Block, String::from(" block "), 4),
+        (Tok::EndOfLine, CommentKind::Block, String::from("*\n * doc\n "), 5),
+        (Tok::Plus, CommentKind::Block, String::from(" inline "), 8),
+        (Tok::EndOfLine, CommentKind::Line, String::from(" end"), 9)
+    ]);
+}
+
+#[test]
+fn test_multi_line_comment_ends_line() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("a /*\n*/ b // done")).unwrap();
+
+    assert_eq!(tokens, vec![
+        Tok::Name { name: String::from("a") },
+        Tok::EndOfLine,
+        Tok::Name { name: String::from("b") },
+        Tok::EndOfLine
+    ]);
+}
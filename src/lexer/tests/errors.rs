@@ -0,0 +1,72 @@
+use std::fs;
+use crate::lexer::lexer::{Lexer, LexError, LexErrorKind};
+
+#[test]
+fn test_every_error_reported() {
+    let file = fs::read_to_string("js/errors/bad_tokens.js");
+
+    let mut lex = Lexer::with_file("bad_tokens.js");
+    let errors = match lex.lex(file.unwrap()) {
+        Err(errors) => errors,
+        Ok(_) => panic!("bad_tokens.js should not lex")
+    };
+
+    let found = errors.iter()
+        .map(|e| match e {
+            LexError::Error { kind, span, .. } => (*kind, span.line, span.column),
+            LexError::End => panic!("End is not an error")
+        })
+        .collect::<Vec<(LexErrorKind, usize, usize)>>();
+
+    assert_eq!(found, vec![
+        (LexErrorKind::UnterminatedString, 1, 9),
+        (LexErrorKind::InvalidNumber, 2, 9),
+        (LexErrorKind::InvalidCharacter, 4, 11),
+        (LexErrorKind::InvalidEscape, 5, 10),
+        (LexErrorKind::InvalidNumber, 6, 9),
+        (LexErrorKind::InvalidEscape, 6, 19),
+        (LexErrorKind::UnterminatedComment, 7, 17)
+    ]);
+}
+
+#[test]
+fn test_error_display() {
+    let mut lex = Lexer::with_file("test.js");
+    let errors = lex.lex(String::from("let a = 1;\nlet b = 0b;")).err().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), "test.js:2:9: SyntaxError: Missing digits after the radix prefix");
+}
+
+#[test]
+fn test_clean_file_lexes() {
+    let mut lex = Lexer::new();
+    assert!(lex.lex(String::from("let ok = 'fine' + `${1}`;")).is_ok());
+}
+
+#[test]
+fn test_digit_after_number() {
+    for source in vec!["0b12", "0o78", "0b1n2"] {
+        let mut lex = Lexer::with_file("test.js");
+        let errors = lex.lex(format!("let a = {};", source)).err().unwrap();
+        assert_eq!(errors.len(), 1, "{}", source);
+        assert_eq!(errors[0].to_string(), "test.js:1:9: SyntaxError: Digit immediately after numeric literal");
+    }
+}
+
+#[test]
+fn test_short_escape_points_at_the_escape() {
+    for (source, text) in vec![
+        ("let s = \"a\\x4\";", "Invalid hexadecimal escape sequence"),
+        ("let s = 'a\\u12';", "Invalid Unicode escape sequence"),
+        ("let s = `a\\u{12`;", "Invalid Unicode escape sequence")
+    ] {
+        let mut lex = Lexer::with_file("test.js");
+        let errors = lex.lex(String::from(source)).err().unwrap();
+        assert_eq!(errors.len(), 1, "{}", source);
+        assert_eq!(errors[0].to_string(), format!("test.js:1:11: SyntaxError: {}", text));
+        if let LexError::Error { kind, span, .. } = &errors[0] {
+            assert_eq!(*kind, LexErrorKind::InvalidEscape);
+            assert_eq!(&source[span.start..span.end], &source[10..source.len() - 2]);
+        }
+    }
+}
@@ -7,7 +7,7 @@ fn test_for() {
     let file = fs::read_to_string("js/if_while_for/for1.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 25);
     let expected = vec![
@@ -45,7 +45,7 @@ fn test_for_test_ident() {
     let file = fs::read_to_string("js/if_while_for/for_test_ident.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 25);
     let expected = vec![
@@ -5,7 +5,7 @@ use crate::lexer::js_token::Tok;
 #[test]
 fn test_declare_string_variable() {
     let mut lex = Lexer::new();
-    let tokens = lex.lex(String::from("let a = \"hi\";"));
+    let tokens = lex.lex(String::from("let a = \"hi\";")).unwrap();
 
     assert_eq!(tokens.len(), 5);
     let expected = vec![
@@ -23,7 +23,7 @@ fn test_let_function() {
     let file = fs::read_to_string("js/functions/let_function.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
 
     assert_eq!(tokens.len(), 18);
@@ -55,7 +55,7 @@ fn test_let_function_arrow() {
     let file = fs::read_to_string("js/functions/let_arrow.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 18);
     let expected = vec![
@@ -86,7 +86,7 @@ fn test_const_function() {
     let file = fs::read_to_string("js/functions/const_function.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 18);
     let expected = vec![
@@ -117,7 +117,7 @@ fn test_const_function_arrow() {
     let file = fs::read_to_string("js/functions/const_arrow.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 18);
     let expected = vec![
@@ -148,7 +148,7 @@ fn test_function() {
     let file = fs::read_to_string("js/functions/function.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 15);
     let expected = vec![
@@ -0,0 +1,59 @@
+use std::rc::Rc;
+use crate::lexer::lexer::{Lexer, LexError};
+use crate::lexer::js_token::Tok;
+use crate::lexer::find_token::find_token;
+use crate::lexer::line_char_iterator::LineCharIterator;
+
+#[test]
+fn test_unicode_identifiers() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("$el _private caf\u{e9} \u{3c0} \\u0061bc a\\u{62}c x\u{200D}y \u{4e2d}\u{6587} a1$_")).unwrap();
+
+    let names = tokens.iter()
+        .map(|token| match &token.tok {
+            Tok::Name { name } => name.clone(),
+            tok => panic!("{:?} should be a name", tok)
+        })
+        .collect::<Vec<String>>();
+
+    assert_eq!(names, vec![
+        "$el", "_private", "caf\u{e9}", "\u{3c0}", "abc", "abc", "x\u{200D}y", "\u{4e2d}\u{6587}", "a1$_"
+    ]);
+}
+
+#[test]
+fn test_identifiers_are_normalised() {
+    let mut lex = Lexer::new();
+    let composed = lex.lex(String::from("caf\u{e9}")).unwrap();
+    let decomposed = lex.lex(String::from("cafe\u{301}")).unwrap();
+    let escaped = lex.lex(String::from("cafe\\u0301")).unwrap();
+
+    assert_eq!(composed, vec![Tok::Name { name: String::from("caf\u{e9}") }]);
+    assert_eq!(decomposed, composed);
+    assert_eq!(escaped, composed);
+}
+
+#[test]
+fn test_unicode_whitespace() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("\u{FEFF}a\u{a0}=\u{3000}1\u{2028}b")).unwrap();
+
+    assert_eq!(tokens, vec![
+        Tok::Name { name: String::from("a") },
+        Tok::Equal,
+        Tok::Float { value: 1. },
+        Tok::EndOfLine,
+        Tok::Name { name: String::from("b") }
+    ]);
+}
+
+#[test]
+fn test_invalid_identifiers() {
+    for code in vec!["@a", "\\u0031a", "a\\u002D", "\\x61", "\\u{110000}", "\\u0069f", "#a"] {
+        let mut it = LineCharIterator::from_string(code);
+        match find_token(&mut it, &Rc::from("test.js"), &mut vec![], true) {
+            Err(LexError::Error { .. }) => {}
+            _ => panic!("{:?} should not lex", code)
+        }
+    }
+}
@@ -7,7 +7,7 @@ fn test_simple_if() {
     let file = fs::read_to_string("js/if_while_for/if1.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 23);
     let expected = vec![
@@ -43,7 +43,7 @@ fn test_if2() {
     let file = fs::read_to_string("js/if_while_for/if2.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 51);
     let expected = vec![
@@ -107,7 +107,7 @@ fn test_if3() {
     let file = fs::read_to_string("js/if_while_for/if3.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 44);
     let expected = vec![
@@ -0,0 +1,39 @@
+use crate::lexer::lexer::Lexer;
+use crate::lexer::json::token_to_json;
+
+#[test]
+fn test_token_json_lines() {
+    let mut lex = Lexer::with_file("tokens.js");
+    let tokens = lex.lex(String::from("let s = \"a\\\"b\\n\" + 1.5;\n")).unwrap();
+
+    let lines = tokens.iter().map(token_to_json).collect::<Vec<String>>();
+    assert_eq!(lines, vec![
+        r#"{"kind":"Let","value":"let","span":{"file":"tokens.js","line":1,"column":1,"start":0,"end":3}}"#,
+        r#"{"kind":"Name","value":"s","span":{"file":"tokens.js","line":1,"column":5,"start":4,"end":5}}"#,
+        r#"{"kind":"Equal","value":"=","span":{"file":"tokens.js","line":1,"column":7,"start":6,"end":7}}"#,
+        r#"{"kind":"String","value":"a\"b\n","span":{"file":"tokens.js","line":1,"column":9,"start":8,"end":16}}"#,
+        r#"{"kind":"Plus","value":"+","span":{"file":"tokens.js","line":1,"column":18,"start":17,"end":18}}"#,
+        r#"{"kind":"Float","value":1.5,"span":{"file":"tokens.js","line":1,"column":20,"start":19,"end":22}}"#,
+        r#"{"kind":"Semi","value":";","span":{"file":"tokens.js","line":1,"column":23,"start":22,"end":23}}"#,
+        r#"{"kind":"EndOfLine","value":null,"span":{"file":"tokens.js","line":1,"column":24,"start":23,"end":24}}"#
+    ]);
+}
+
+#[test]
+fn test_literal_values() {
+    let mut lex = Lexer::with_file("t.js");
+    let tokens = lex.lex(String::from("f(`x${y}\tz`, /re/gi, 10n, 1e999)")).unwrap();
+
+    let values = tokens.iter()
+        .map(|token| {
+            let line = token_to_json(token);
+            let start = line.find("\"value\":").unwrap() + 8;
+            let end = line.find(",\"span\"").unwrap();
+            line[start..end].to_string()
+        })
+        .collect::<Vec<String>>();
+    assert_eq!(values, vec![
+        r#""f""#, r#""(""#, r#""x""#, r#""y""#, r#""\tz""#, r#"",""#, r#""/re/gi""#, r#"",""#, r#""10""#,
+        r#"",""#, r#""Infinity""#, r#"")""#
+    ]);
+}
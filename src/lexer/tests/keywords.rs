@@ -0,0 +1,74 @@
+use crate::lexer::lexer::Lexer;
+use crate::lexer::js_token::Tok;
+
+#[test]
+fn test_reserved_words() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from(
+        "break case catch class const continue debugger default delete do else enum export extends \
+         false finally for function if import in instanceof let new null return super switch this throw \
+         true try typeof var void while with"
+    )).unwrap();
+
+    assert_eq!(tokens, vec![
+        Tok::Break, Tok::Case, Tok::Catch, Tok::Class, Tok::Const, Tok::Continue, Tok::Debugger, Tok::Default,
+        Tok::Delete, Tok::Do, Tok::Else, Tok::Enum, Tok::Export, Tok::Extends, Tok::False, Tok::Finally,
+        Tok::For, Tok::Function, Tok::If, Tok::Import, Tok::In, Tok::InstanceOf, Tok::Let, Tok::New,
+        Tok::Null, Tok::Return, Tok::Super, Tok::Switch, Tok::This, Tok::Throw, Tok::True, Tok::Try,
+        Tok::TypeOf, Tok::Var, Tok::Void, Tok::While, Tok::With
+    ]);
+}
+
+#[test]
+fn test_contextual_words_are_names() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from(
+        "of get set async static from as letter iffy implements interface package private protected public await yield"
+    )).unwrap();
+
+    let names = tokens.iter()
+        .map(|token| match &token.tok {
+            Tok::Name { name } => name.clone(),
+            tok => panic!("{:?} should be a name", tok)
+        })
+        .collect::<Vec<String>>();
+
+    assert_eq!(names, vec![
+        "of", "get", "set", "async", "static", "from", "as", "letter", "iffy",
+        "implements", "interface", "package", "private", "protected", "public", "await", "yield"
+    ]);
+}
+
+#[test]
+fn test_reserved_words_as_property_names() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("obj.return(a?.class).new")).unwrap();
+
+    assert_eq!(tokens, vec![
+        Tok::Name { name: String::from("obj") },
+        Tok::Dot,
+        Tok::Name { name: String::from("return") },
+        Tok::Lpar,
+        Tok::Name { name: String::from("a") },
+        Tok::QuestionDot,
+        Tok::Name { name: String::from("class") },
+        Tok::Rpar,
+        Tok::Dot,
+        Tok::Name { name: String::from("new") }
+    ]);
+}
+
+#[test]
+fn test_property_name_after_line_break() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("o.\nreturn\nreturn")).unwrap();
+
+    assert_eq!(tokens, vec![
+        Tok::Name { name: String::from("o") },
+        Tok::Dot,
+        Tok::EndOfLine,
+        Tok::Name { name: String::from("return") },
+        Tok::EndOfLine,
+        Tok::Return
+    ]);
+}
@@ -1,7 +1,7 @@
 use std::fs;
 use crate::lexer::lexer::Lexer;
 use crate::lexer::js_token::Tok;
-use crate::lexer::line_char_iterator::LineCharIterator;
+use crate::lexer::line_char_iterator::{LineCharIterator, HISTORY};
 
 #[test]
 fn test_line_char_iterator() {
@@ -16,6 +16,7 @@ fn test_line_char_iterator() {
         }
     }
 
+    // only the last few characters are kept to step back over
     let mut fi = forward.len();
     loop {
         if let Some(item) = lci.prev() {
@@ -25,4 +26,10 @@ fn test_line_char_iterator() {
             break;
         }
     }
+    assert_eq!(fi, forward.len() - HISTORY);
+
+    for item in forward[fi..].iter() {
+        assert_eq!(&lci.next().unwrap(), item);
+    }
+    assert_eq!(lci.next(), None);
 }
\ No newline at end of file
@@ -7,7 +7,7 @@ fn test_plus() {
     let file = fs::read_to_string("js/math/plus.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 28);
     let expected = vec![
@@ -48,7 +48,7 @@ fn test_minus() {
     let file = fs::read_to_string("js/math/minus.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 28);
     let expected = vec![
@@ -89,7 +89,7 @@ fn test_mul() {
     let file = fs::read_to_string("js/math/mul.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 28);
     let expected = vec![
@@ -130,7 +130,7 @@ fn test_number_plus_return_value() {
     let file = fs::read_to_string("js/math/number_plus_return_value.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 31);
     let expected = vec![
@@ -174,7 +174,7 @@ fn test_bitwise() {
     let file = fs::read_to_string("js/math/bitwise.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 30);
     let expected = vec![
@@ -217,7 +217,7 @@ fn test_bitwise() {
 fn test_number_less_identifier() {
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex("2 < a".to_string());
+    let tokens = lex.lex("2 < a".to_string()).unwrap();
 
     assert_eq!(tokens.len(), 3);
     let expected = vec![
@@ -233,7 +233,7 @@ fn test_number_less_identifier() {
 fn test_number_plus_plus() {
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex("2++".to_string());
+    let tokens = lex.lex("2++".to_string()).unwrap();
 
     assert_eq!(tokens.len(), 2);
     let expected = vec![
@@ -248,7 +248,7 @@ fn test_number_plus_plus() {
 fn test_and1() {
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex("x == 5 && x < 10".to_string());
+    let tokens = lex.lex("x == 5 && x < 10".to_string()).unwrap();
 
     assert_eq!(tokens.len(), 7);
     let expected = vec![
@@ -268,7 +268,7 @@ fn test_and1() {
 fn test_triple_equal() {
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex("x === 5".to_string());
+    let tokens = lex.lex("x === 5".to_string()).unwrap();
 
     assert_eq!(tokens.len(), 3);
     let expected = vec![
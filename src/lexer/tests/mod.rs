@@ -1,5 +1,7 @@
 #![allow(unused_imports)]
-use crate::lexer::string_iterator::StringIterator;
+use crate::lexer::lexer::Lexer;
+use crate::lexer::js_token::Tok;
+use crate::lexer::line_char_iterator::LineCharIterator;
 
 mod functions;
 mod if_statement;
@@ -8,26 +10,54 @@ mod one_liners;
 mod for_statements;
 mod arrays;
 mod line_char_iterator;
+mod strings;
+mod templates;
+mod comments;
+mod numbers;
+mod punctuators;
+mod keywords;
+mod identifiers;
+mod regex;
+mod errors;
+mod streaming;
+mod json;
 
 #[test]
 fn test_iterator() {
-    let s = "let a = \"hi\";".chars();
-    let mut it: StringIterator = StringIterator::new(s);
+    let mut it = LineCharIterator::from_string("let a = \"hi\";");
 
     loop {
-        let ch = it.next();
-        match ch {
-            Err(_) => {
+        match it.next() {
+            None => {
                 break
             }
-            Ok(ch) => {
+            Some((_, _, ch)) => {
                 if ch == 't' {
-                    let ch = it.prev().unwrap_or('f');
+                    let ch = it.prev().map(|(_, _, ch)| ch).unwrap_or('f');
                     assert_eq!(ch, 't');
-                    assert_eq!(2, it.index());
+                    assert_eq!((0, 2, 2), it.location());
                     break
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_token_spans() {
+    let mut lex = Lexer::with_file("spans.js");
+    let tokens = lex.lex(String::from("let a = 1;\nconsole.log(\"hé\");")).unwrap();
+
+    let a = tokens.get(1).unwrap();
+    assert_eq!(a.tok, Tok::Name { name: String::from("a") });
+    assert_eq!((a.span.line, a.span.column, a.span.start, a.span.end), (1, 5, 4, 5));
+
+    let console = tokens.get(6).unwrap();
+    assert_eq!(console.tok, Tok::Name { name: String::from("console") });
+    assert_eq!((console.span.line, console.span.column, console.span.start), (2, 1, 11));
+    assert_eq!(console.span.to_string(), "spans.js:2:1");
+
+    let string = tokens.get(10).unwrap();
+    assert_eq!(string.tok, Tok::String { value: String::from("hé") });
+    assert_eq!((string.span.column, string.span.start, string.span.end), (13, 23, 28));
+}
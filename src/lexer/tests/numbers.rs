@@ -0,0 +1,61 @@
+use std::fs;
+use std::rc::Rc;
+use crate::lexer::lexer::{Lexer, LexError};
+use crate::lexer::js_token::Tok;
+use crate::lexer::find_token::find_token;
+use crate::lexer::line_char_iterator::LineCharIterator;
+
+#[test]
+fn test_numeric_literals() {
+    let file = fs::read_to_string("js/math/numeric_literals.js");
+
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+
+    let numbers = tokens.iter()
+        .filter_map(|token| match &token.tok {
+            Tok::Float { .. } | Tok::BigInt { .. } => Some(token.tok.clone()),
+            _ => None
+        })
+        .collect::<Vec<Tok>>();
+
+    assert_eq!(numbers, vec![
+        Tok::Float { value: 31. },
+        Tok::Float { value: 15. },
+        Tok::Float { value: 10. },
+        Tok::Float { value: 1e-7 },
+        Tok::Float { value: 0.5 },
+        Tok::Float { value: 5. },
+        Tok::Float { value: 1000000. },
+        Tok::BigInt { value: String::from("123") },
+        Tok::BigInt { value: String::from("4722366482869645213695") },
+        Tok::Float { value: 2500. }
+    ]);
+}
+
+#[test]
+fn test_numbers_next_to_punctuators() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("a.b + 0.5*.25")).unwrap();
+
+    assert_eq!(tokens, vec![
+        Tok::Name { name: String::from("a") },
+        Tok::Dot,
+        Tok::Name { name: String::from("b") },
+        Tok::Plus,
+        Tok::Float { value: 0.5 },
+        Tok::Star,
+        Tok::Float { value: 0.25 }
+    ]);
+}
+
+#[test]
+fn test_malformed_numbers() {
+    for code in vec!["08", "017", "1__0", "1_", "0x", "0b2", "1e", "1e+", "1._5", "1.5n", "1e3n", "3in", "0x1g"] {
+        let mut it = LineCharIterator::from_string(code);
+        match find_token(&mut it, &Rc::from("test.js"), &mut vec![], true) {
+            Err(LexError::Error { .. }) => {}
+            _ => panic!("{:?} should not lex", code)
+        }
+    }
+}
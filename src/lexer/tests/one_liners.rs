@@ -7,7 +7,7 @@ fn test_one_liner() {
     let file = fs::read_to_string("js/one_line1.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
 
     assert_eq!(tokens.len(), 18);
     let expected = vec![
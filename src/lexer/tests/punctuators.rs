@@ -0,0 +1,55 @@
+use crate::lexer::lexer::Lexer;
+use crate::lexer::js_token::Tok;
+
+#[test]
+fn test_all_punctuators() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from(
+        "{ } ( ) [ ] ; , : . ... ? ?. ?? ??= = == === => ! != !== < <= << <<= > >= >> >>= >>> >>>= \
+         + ++ += - -- -= * *= ** **= a / a /= % %= & &= && &&= | |= || ||= ^ ^= ~"
+    )).unwrap();
+
+    assert_eq!(tokens, vec![
+        Tok::Lbrace, Tok::Rbrace, Tok::Lpar, Tok::Rpar, Tok::Lsqb, Tok::Rsqb, Tok::Semi, Tok::Comma,
+        Tok::Colon, Tok::Dot, Tok::Ellipsis, Tok::Question, Tok::QuestionDot, Tok::QuestionQuestion,
+        Tok::QuestionQuestionEqual, Tok::Equal, Tok::EqEqual, Tok::EqEqEual, Tok::RdoubleArrow, Tok::Not,
+        Tok::NotEqual, Tok::NotDoubleEqual, Tok::Less, Tok::LessEqual, Tok::LeftShift, Tok::LeftShiftEqual,
+        Tok::Greater, Tok::GreaterEqual, Tok::RightShift, Tok::RightShiftEqual, Tok::RightShiftUnsigned,
+        Tok::RightShiftUnsignedEqual, Tok::Plus, Tok::PlusPlus, Tok::PlusEqual, Tok::Minus, Tok::MinusMinus,
+        Tok::MinusEqual, Tok::Star, Tok::StarEqual, Tok::StarStar, Tok::StarStarEqual, Tok::Name { name: String::from("a") },
+        Tok::Bslash, Tok::Name { name: String::from("a") }, Tok::BslashEqual, Tok::Percent, Tok::PercentEqual, Tok::Amper, Tok::AmperEqual, Tok::AmpAmp,
+        Tok::AmpAmpEqual, Tok::Vbar, Tok::VbarEqual, Tok::VbarVbar, Tok::VbarVbarEqual, Tok::Circumflex,
+        Tok::CircumflexEqual, Tok::Tilde
+    ]);
+}
+
+#[test]
+fn test_longest_match() {
+    let name = |name: &str| Tok::Name { name: String::from(name) };
+    let mut lex = Lexer::new();
+
+    assert_eq!(lex.lex(String::from("a---b")).unwrap(), vec![name("a"), Tok::MinusMinus, Tok::Minus, name("b")]);
+    assert_eq!(lex.lex(String::from("a>>>=b")).unwrap(), vec![name("a"), Tok::RightShiftUnsignedEqual, name("b")]);
+    assert_eq!(lex.lex(String::from("a..b")).unwrap(), vec![name("a"), Tok::Dot, Tok::Dot, name("b")]);
+    assert_eq!(lex.lex(String::from("f(...a)")).unwrap(), vec![name("f"), Tok::Lpar, Tok::Ellipsis, name("a"), Tok::Rpar]);
+    assert_eq!(lex.lex(String::from("a!==b")).unwrap(), vec![name("a"), Tok::NotDoubleEqual, name("b")]);
+    assert_eq!(lex.lex(String::from("a&&!b")).unwrap(), vec![name("a"), Tok::AmpAmp, Tok::Not, name("b")]);
+}
+
+#[test]
+fn test_optional_chaining_before_digit() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("a?.b:a?.5:1")).unwrap();
+
+    assert_eq!(tokens, vec![
+        Tok::Name { name: String::from("a") },
+        Tok::QuestionDot,
+        Tok::Name { name: String::from("b") },
+        Tok::Colon,
+        Tok::Name { name: String::from("a") },
+        Tok::Question,
+        Tok::Float { value: 0.5 },
+        Tok::Colon,
+        Tok::Float { value: 1. }
+    ]);
+}
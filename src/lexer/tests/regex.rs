@@ -0,0 +1,73 @@
+use std::rc::Rc;
+use crate::lexer::lexer::{Lexer, LexError};
+use crate::lexer::js_token::Tok;
+use crate::lexer::find_token::find_token;
+use crate::lexer::line_char_iterator::LineCharIterator;
+
+#[test]
+fn test_regex_literals() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("let re = /ab+c/gi; f(/[/]\\//, x)")).unwrap();
+
+    assert_eq!(tokens, vec![
+        Tok::Let,
+        Tok::Name { name: String::from("re") },
+        Tok::Equal,
+        Tok::Regex { pattern: String::from("ab+c"), flags: String::from("gi") },
+        Tok::Semi,
+        Tok::Name { name: String::from("f") },
+        Tok::Lpar,
+        Tok::Regex { pattern: String::from("[/]\\/"), flags: String::from("") },
+        Tok::Comma,
+        Tok::Name { name: String::from("x") },
+        Tok::Rpar
+    ]);
+}
+
+#[test]
+fn test_slash_after_value_is_division() {
+    let name = |name: &str| Tok::Name { name: String::from(name) };
+    let mut lex = Lexer::new();
+
+    assert_eq!(lex.lex(String::from("a / b / c")).unwrap(), vec![name("a"), Tok::Bslash, name("b"), Tok::Bslash, name("c")]);
+    assert_eq!(lex.lex(String::from("(a) / 2 /g")).unwrap(), vec![
+        Tok::Lpar, name("a"), Tok::Rpar, Tok::Bslash, Tok::Float { value: 2. }, Tok::Bslash, name("g")
+    ]);
+    assert_eq!(lex.lex(String::from("a\n/b/g")).unwrap(), vec![
+        name("a"), Tok::EndOfLine, Tok::Bslash, name("b"), Tok::Bslash, name("g")
+    ]);
+    assert_eq!(lex.lex(String::from("return /b/g")).unwrap(), vec![
+        Tok::Return, Tok::Regex { pattern: String::from("b"), flags: String::from("g") }
+    ]);
+}
+
+#[test]
+fn test_slash_after_statement_head_is_regex() {
+    let name = |name: &str| Tok::Name { name: String::from(name) };
+    let regex = |pattern: &str| Tok::Regex { pattern: String::from(pattern), flags: String::from("") };
+    let mut lex = Lexer::new();
+
+    assert_eq!(lex.lex(String::from("if (x) /re/.test(y)")).unwrap(), vec![
+        Tok::If, Tok::Lpar, name("x"), Tok::Rpar, regex("re"), Tok::Dot, name("test"), Tok::Lpar, name("y"), Tok::Rpar
+    ]);
+    assert_eq!(lex.lex(String::from("while (f(a))\n/b/.exec(c)")).unwrap(), vec![
+        Tok::While, Tok::Lpar, name("f"), Tok::Lpar, name("a"), Tok::Rpar, Tok::Rpar, Tok::EndOfLine,
+        regex("b"), Tok::Dot, name("exec"), Tok::Lpar, name("c"), Tok::Rpar
+    ]);
+    // the inner parens of a condition and parens after other names are values
+    assert_eq!(lex.lex(String::from("if ((a) / 2) f(b) / 2")).unwrap(), vec![
+        Tok::If, Tok::Lpar, Tok::Lpar, name("a"), Tok::Rpar, Tok::Bslash, Tok::Float { value: 2. }, Tok::Rpar,
+        name("f"), Tok::Lpar, name("b"), Tok::Rpar, Tok::Bslash, Tok::Float { value: 2. }
+    ]);
+}
+
+#[test]
+fn test_invalid_regex() {
+    for code in vec!["/abc", "/ab\nc/", "/[/", "/a/gg", "/a/x", "/a\\\n/"] {
+        let mut it = LineCharIterator::from_string(code);
+        match find_token(&mut it, &Rc::from("test.js"), &mut vec![], true) {
+            Err(LexError::Error { .. }) => {}
+            _ => panic!("{:?} should not lex", code)
+        }
+    }
+}
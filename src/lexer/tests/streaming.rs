@@ -0,0 +1,60 @@
+use std::io::{self, BufReader, Cursor, Read};
+use crate::lexer::lexer::{Lexer, LexError};
+use crate::lexer::js_token::{Tok, Token};
+
+/// Reader producing `x = 1;` lines forever.
+#[cfg(test)]
+struct Endless {
+    line: &'static [u8],
+    pos: usize
+}
+
+#[cfg(test)]
+impl Read for Endless {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        for byte in buf.iter_mut() {
+            *byte = self.line[self.pos];
+            self.pos = (self.pos + 1) % self.line.len();
+        }
+        Ok(buf.len())
+    }
+}
+
+#[test]
+fn test_tokens_pulled_lazily() {
+    let lex = Lexer::from_reader("endless.js", BufReader::new(Endless { line: b"x = 1;\n", pos: 0 }));
+
+    let tokens = lex.take(10).collect::<Result<Vec<Token>, LexError>>().unwrap();
+    let name = Tok::Name { name: String::from("x") };
+    assert_eq!(tokens, vec![
+        name.clone(), Tok::Equal, Tok::Float { value: 1. }, Tok::Semi, Tok::EndOfLine,
+        name, Tok::Equal, Tok::Float { value: 1. }, Tok::Semi, Tok::EndOfLine
+    ]);
+}
+
+#[test]
+fn test_reader_matches_string() {
+    let code = "let s = `a${ {b: 'é'}.b }c` / 2\n// done\nlet r = /x/g;";
+
+    let streamed = Lexer::from_reader("code.js", Cursor::new(code.as_bytes().to_vec()))
+        .collect::<Result<Vec<Token>, LexError>>()
+        .unwrap();
+    let mut lex = Lexer::with_file("code.js");
+    let tokens = lex.lex(String::from(code)).unwrap();
+
+    assert_eq!(streamed, tokens);
+    let spans = |tokens: &Vec<Token>| tokens.iter()
+        .map(|token| (token.span.line, token.span.column, token.span.start, token.span.end))
+        .collect::<Vec<(usize, usize, usize, usize)>>();
+    assert_eq!(spans(&streamed), spans(&tokens));
+}
+
+#[test]
+fn test_errors_come_out_of_the_iterator() {
+    let lex = Lexer::from_reader("bad.js", Cursor::new(b"a 08 b".to_vec()));
+
+    let items = lex.collect::<Vec<Result<Token, LexError>>>();
+    assert_eq!(items.len(), 3);
+    assert!(items[1].is_err());
+    assert_eq!(items[2].as_ref().unwrap().tok, Tok::Name { name: String::from("b") });
+}
@@ -0,0 +1,61 @@
+use std::fs;
+use std::rc::Rc;
+use crate::lexer::lexer::{Lexer, LexError};
+use crate::lexer::js_token::Tok;
+use crate::lexer::find_token::find_token;
+use crate::lexer::line_char_iterator::LineCharIterator;
+
+#[test]
+fn test_string_escapes() {
+    let file = fs::read_to_string("js/strings/escapes.js");
+
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+
+    let strings = tokens.iter()
+        .filter_map(|token| match &token.tok {
+            Tok::String { value } => Some(value.clone()),
+            _ => None
+        })
+        .collect::<Vec<String>>();
+
+    assert_eq!(strings, vec![
+        String::from("single"),
+        String::from("tab\tnew\nline"),
+        String::from("it's \\ \"quoted\""),
+        String::from("ABC\u{1F600}A"),
+        String::from("line continues")
+    ]);
+}
+
+#[test]
+fn test_surrogate_pair_escapes() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from(r#"'\uD83D\uDE00' '\uD83D' '\uD83Dx' '\uD83D\u0041' '\uDE00\uD83D'"#)).unwrap();
+
+    let strings = tokens.iter()
+        .filter_map(|token| match &token.tok {
+            Tok::String { value } => Some(value.clone()),
+            _ => None
+        })
+        .collect::<Vec<String>>();
+
+    assert_eq!(strings, vec![
+        String::from("\u{1F600}"),
+        String::from("\u{FFFD}"),
+        String::from("\u{FFFD}x"),
+        String::from("\u{FFFD}A"),
+        String::from("\u{FFFD}\u{FFFD}")
+    ]);
+}
+
+#[test]
+fn test_unterminated_strings() {
+    for code in vec!["'abc", "\"abc\n\"", "'abc\\", "\"\\xZ1\"", "'\\u{110000}'"] {
+        let mut it = LineCharIterator::from_string(code);
+        match find_token(&mut it, &Rc::from("test.js"), &mut vec![], true) {
+            Err(LexError::Error { .. }) => {}
+            _ => panic!("{:?} should not lex", code)
+        }
+    }
+}
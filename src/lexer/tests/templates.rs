@@ -0,0 +1,53 @@
+use std::fs;
+use crate::lexer::lexer::Lexer;
+use crate::lexer::js_token::Tok;
+
+#[test]
+fn test_template_tokens() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("`a${b}c${ {d: 1}.d }e` + `f\\n`")).unwrap();
+
+    assert_eq!(tokens, vec![
+        Tok::TemplateHead { cooked: String::from("a"), raw: String::from("a") },
+        Tok::Name { name: String::from("b") },
+        Tok::TemplateMiddle { cooked: String::from("c"), raw: String::from("c") },
+        Tok::Lbrace,
+        Tok::Name { name: String::from("d") },
+        Tok::Colon,
+        Tok::Float { value: 1. },
+        Tok::Rbrace,
+        Tok::Dot,
+        Tok::Name { name: String::from("d") },
+        Tok::TemplateTail { cooked: String::from("e"), raw: String::from("e") },
+        Tok::Plus,
+        Tok::NoSubstitutionTemplate { cooked: String::from("f\n"), raw: String::from("f\\n") }
+    ]);
+}
+
+#[test]
+fn test_nested_template_tokens() {
+    let file = fs::read_to_string("js/templates/nested.js");
+
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+
+    let templates = tokens.iter()
+        .filter_map(|token| match &token.tok {
+            Tok::TemplateHead { cooked, .. } => Some(format!("head {}", cooked)),
+            Tok::TemplateTail { cooked, .. } => Some(format!("tail {}", cooked)),
+            _ => None
+        })
+        .collect::<Vec<String>>();
+
+    assert_eq!(templates, vec!["head out ", "head and ", "tail ", "tail  done"]);
+}
+
+#[test]
+fn test_multi_line_template_tokens() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("`one\r\ntwo`")).unwrap();
+
+    assert_eq!(tokens, vec![
+        Tok::NoSubstitutionTemplate { cooked: String::from("one\ntwo"), raw: String::from("one\ntwo") }
+    ]);
+}
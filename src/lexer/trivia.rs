@@ -0,0 +1,26 @@
+use crate::lexer::span::Span;
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum CommentKind {
+    Line, // // ...
+    Block, // /* ... */
+    Hashbang // #! ... on the first line
+}
+
+/// A comment skipped by the lexer. `text` is everything between the delimiters.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct Comment {
+    pub(crate) kind: CommentKind,
+    pub(crate) text: String,
+    pub(crate) span: Span
+}
+
+impl Comment {
+    pub(crate) fn new(kind: CommentKind, text: String, span: Span) -> Comment {
+        Comment {
+            kind,
+            text,
+            span
+        }
+    }
+}
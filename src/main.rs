@@ -6,48 +6,98 @@ mod compiler;
 mod ast_interpreter;
 mod vm;
 
-use std::{fs};
+use std::process;
 use crate::lexer::lexer::Lexer;
+use crate::lexer::json::token_to_json;
 use clap::{App, Arg};
 use crate::parser::parser::Parser;
 use crate::parser::symbols::JSItem;
 use crate::compiler::compiler::Compiler;
 use crate::compiler::to_bytes::to_bytes;
 use std::fs::File;
-use std::io::{Write, Read};
+use std::io::{Write, Read, BufReader};
 use crate::vm::vm::Vm;
 
 extern crate clap;
-#[macro_use] extern crate maplit;
 
-fn get_js_items(file_name: &str) -> Vec<JSItem> {
-    match  fs::read_to_string(file_name) {
-        Ok(code) => {
-            let mut lex = Lexer::new();
-            let mut parser = Parser::new();
-            let tokens = lex.lex(code);
-            let mut js_items = parser.parse(tokens);
-            return js_items;
+/// Parses a file as it is read and hands each top level item to `take` as soon as its
+/// statement is parsed, so neither the file's tokens nor its whole tree are held at once.
+/// Lexer errors are all reported, even past a syntax error, and win over it. Nothing is left
+/// to run when there is one, so `take` should only build up what runs afterwards.
+fn for_each_js_item(file_name: &str, mut take: impl FnMut(JSItem)) {
+    let file = match File::open(file_name) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("{}: {}", file_name, e);
+            process::exit(1)
         }
+    };
+    let mut lex = Lexer::from_reader(file_name, BufReader::new(file));
+    let mut parser = Parser::new();
+    let mut errors = vec![];
+    let mut syntax_error = None;
+    let tokens = lex.by_ref().filter_map(|token| match token {
+        Ok(token) => Some(token),
         Err(e) => {
-            println!("{:?}", e);
-            panic!()
+            errors.push(e);
+            None
+        }
+    });
+    for items in parser.parse_statements(tokens) {
+        match items {
+            Ok(items) => items.into_iter().for_each(&mut take),
+            Err(e) => {
+                syntax_error = Some(e);
+                break;
+            }
+        }
+    }
+    errors.extend(lex.filter_map(|token| token.err()));
+    if !errors.is_empty() {
+        for e in errors {
+            eprintln!("{}", e);
         }
+        process::exit(1)
+    }
+    if let Some(e) = syntax_error {
+        eprintln!("{}", e);
+        process::exit(1)
     }
 }
 
 #[allow(unused_must_use)]
 fn compile(file_name: &str, output_file: &str) {
-    let js_items = get_js_items(file_name);
     let mut com = Compiler::new();
-    for item in js_items {
-        com.compile(item);
-    }
+    for_each_js_item(file_name, |item| com.compile(item));
     let bytes = to_bytes(com.bc_ins);
     let mut file = File::create(output_file).unwrap();
     file.write_all(&bytes);
 }
 
+/// Prints every token as a line of JSON, lexer errors go to stderr.
+fn print_tokens(file_name: &str) {
+    let file = match File::open(file_name) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("{}: {}", file_name, e);
+            process::exit(1)
+        }
+    };
+    let mut failed = false;
+    for token in Lexer::from_reader(file_name, BufReader::new(file)) {
+        match token {
+            Ok(token) => println!("{}", token_to_json(&token)),
+            Err(e) => {
+                eprintln!("{}", e);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
 #[allow(unused_must_use)]
 fn run_bytes(file_name: &str) {
     let mut file = File::open(file_name).unwrap();
@@ -57,12 +107,10 @@ fn run_bytes(file_name: &str) {
 }
 
 fn run(file_name: &str) {
-    let js_items = get_js_items(file_name);
     let mut compiler = Compiler::new();
-    for item in js_items {
-        compiler.compile(item);
-    }
+    for_each_js_item(file_name, |item| compiler.compile(item));
     let mut vm = Vm::new();
+    vm.locations = compiler.locations;
     let out = vm.run(compiler.bc_ins);
     match out {
         JSItem::Null | JSItem::Undefined => {}
@@ -100,12 +148,18 @@ fn main() {
             .short("b")
             .requires_all(&["file"])
             .takes_value(false))
+        .arg(Arg::with_name("tokens")
+            .help("Print the tokens as JSON Lines")
+            .long("tokens")
+            .required(false))
         .get_matches();
 
     let file_name = matches.value_of("file").unwrap();
 
     if matches.is_present("compile") {
         compile(file_name, matches.value_of("outputfile").unwrap());
+    } else if matches.is_present("tokens") {
+        print_tokens(file_name);
     } else if matches.is_present("bytes") {
         run_bytes(file_name);
     } else {
@@ -0,0 +1,236 @@
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::span::Span;
+use std::collections::VecDeque;
+
+/// What an open bracket holds, which decides how the line ends inside it are read.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Bracket {
+    // ( [ and ${, a newline inside never ends a statement
+    Paren,
+    // the condition of if, for, while and with, a statement follows the )
+    Head,
+    // object literal and class bodies
+    Object,
+    // statements, `expression` is true for the body of a function expression
+    Block { expression: bool }
+}
+
+enum LineEnd {
+    Drop,
+    Keep,
+    Insert
+}
+
+/// Tokens that can be the last token of a statement.
+fn can_end_statement(tok: &Tok) -> bool {
+    match tok {
+        Tok::Name { .. } | Tok::Float { .. } | Tok::BigInt { .. } | Tok::String { .. } | Tok::Regex { .. }
+        | Tok::NoSubstitutionTemplate { .. } | Tok::TemplateTail { .. } | Tok::Rpar | Tok::Rsqb
+        | Tok::Rbrace | Tok::PlusPlus | Tok::MinusMinus | Tok::This | Tok::Super | Tok::Null | Tok::True
+        | Tok::False | Tok::Debugger => true,
+        _ => false
+    }
+}
+
+/// `return`, `break`, `continue` and `throw` take nothing from the next line.
+fn is_restricted(tok: &Tok) -> bool {
+    match tok {
+        Tok::Return | Tok::Break | Tok::Continue | Tok::Throw => true,
+        _ => false
+    }
+}
+
+/// Tokens that carry on the expression from the line before, so no semicolon goes between.
+fn continues_expression(tok: &Tok) -> bool {
+    match tok {
+        Tok::Lpar | Tok::Lsqb | Tok::Dot | Tok::QuestionDot | Tok::NoSubstitutionTemplate { .. }
+        | Tok::TemplateHead { .. } | Tok::Comma | Tok::Question | Tok::Colon | Tok::RdoubleArrow
+        | Tok::Plus | Tok::Minus | Tok::Star | Tok::StarStar | Tok::Bslash | Tok::Percent | Tok::Amper
+        | Tok::Vbar | Tok::Circumflex | Tok::AmpAmp | Tok::VbarVbar | Tok::QuestionQuestion | Tok::Less
+        | Tok::Greater | Tok::LessEqual | Tok::GreaterEqual | Tok::EqEqual | Tok::EqEqEual | Tok::NotEqual
+        | Tok::NotDoubleEqual | Tok::LeftShift | Tok::RightShift | Tok::RightShiftUnsigned | Tok::In
+        | Tok::InstanceOf | Tok::Equal | Tok::PlusEqual | Tok::MinusEqual | Tok::StarEqual
+        | Tok::StarStarEqual | Tok::BslashEqual | Tok::PercentEqual | Tok::AmperEqual | Tok::VbarEqual
+        | Tok::CircumflexEqual | Tok::LeftShiftEqual | Tok::RightShiftEqual | Tok::RightShiftUnsignedEqual
+        | Tok::AmpAmpEqual | Tok::VbarVbarEqual | Tok::QuestionQuestionEqual => true,
+        _ => false
+    }
+}
+
+/// Whether `prev` leaves the parser waiting for an operand, where a `{` is an object literal
+/// and `function` is a function expression.
+fn expects_operand(prev: Option<&Tok>, inside: Option<&Bracket>) -> bool {
+    match prev {
+        None | Some(Tok::Semi) | Some(Tok::Lbrace) | Some(Tok::Rbrace) | Some(Tok::Rpar) | Some(Tok::Else)
+        | Some(Tok::Do) | Some(Tok::Try) | Some(Tok::Finally) | Some(Tok::Export) | Some(Tok::Default) => false,
+        // `a: {` is a labelled block unless we are already inside an object literal
+        Some(Tok::Colon) => inside == Some(&Bracket::Object),
+        Some(tok) => !can_end_statement(tok)
+    }
+}
+
+fn line_end(prev: Option<&Tok>, prefix: bool, closed: Option<Bracket>, inside: Option<&Bracket>, next: Option<&Tok>) -> LineEnd {
+    if let Some(Bracket::Paren) | Some(Bracket::Head) | Some(Bracket::Object) = inside {
+        return LineEnd::Drop;
+    }
+    let prev = match prev {
+        None | Some(Tok::Semi) => return LineEnd::Keep,
+        // a block needs no semicolon after it, an object literal or function expression does
+        Some(Tok::Rbrace) if closed == Some(Bracket::Block { expression: false }) => return LineEnd::Keep,
+        Some(Tok::Rpar) if closed == Some(Bracket::Head) => return LineEnd::Drop,
+        Some(prev) => prev
+    };
+    if is_restricted(prev) {
+        return LineEnd::Insert;
+    }
+    // a prefix ++ or -- still needs its operand
+    if prefix || !can_end_statement(prev) {
+        return LineEnd::Drop;
+    }
+    match next {
+        // postfix ++ and -- have to be on the same line as their operand
+        Some(Tok::PlusPlus) | Some(Tok::MinusMinus) => LineEnd::Insert,
+        Some(next) if continues_expression(next) => LineEnd::Drop,
+        _ => LineEnd::Insert
+    }
+}
+
+/// Automatic semicolon insertion. A line end that continues the statement before it is
+/// removed, one that ends a statement is kept with a `Semi` put in front of it if the
+/// statement didn't already have one. Running it again over its own output changes nothing.
+pub(crate) fn insert_semicolons(tokens: Vec<Token>) -> Vec<Token> {
+    SemicolonInserter::new(tokens.into_iter()).collect()
+}
+
+/// `insert_semicolons` over a stream of tokens. Only the line ends in a row and the token
+/// after them are read ahead, to see whether the next line carries on the statement.
+pub(crate) struct SemicolonInserter<I: Iterator<Item = Token>> {
+    tokens: I,
+    // line ends read ahead, with the token after them last
+    ahead: VecDeque<Token>,
+    // tokens ready to hand out, a line end that ends a statement comes after its `Semi`
+    out: VecDeque<Token>,
+    brackets: Vec<Bracket>,
+    // bodies still to open for `function` and `class`, with the bracket depth they were seen at
+    bodies: Vec<(usize, Bracket)>,
+    closed: Option<Bracket>,
+    // bracket depths of the `do` statements still waiting for their `while`
+    dos: Vec<usize>,
+    do_while: bool,
+    prefix: bool,
+    prev: Option<Tok>
+}
+
+impl<I: Iterator<Item = Token>> SemicolonInserter<I> {
+    pub(crate) fn new(tokens: I) -> SemicolonInserter<I> {
+        SemicolonInserter {
+            tokens,
+            ahead: VecDeque::new(),
+            out: VecDeque::new(),
+            brackets: vec![],
+            bodies: vec![],
+            closed: None,
+            dos: vec![],
+            do_while: false,
+            prefix: false,
+            prev: None
+        }
+    }
+
+    fn pull(&mut self) -> Option<Token> {
+        self.ahead.pop_front().or_else(|| self.tokens.next())
+    }
+
+    /// The first token that isn't a line end, read ahead without taking it.
+    fn next_on_a_line(&mut self) -> Option<Tok> {
+        loop {
+            if let Some(token) = self.ahead.iter().find(|token| token.tok != Tok::EndOfLine) {
+                return Some(token.tok.clone());
+            }
+            match self.tokens.next() {
+                Some(token) => self.ahead.push_back(token),
+                None => return None
+            }
+        }
+    }
+
+    fn line_end(&mut self, token: Token) {
+        let next = self.next_on_a_line();
+        match line_end(self.prev.as_ref(), self.prefix, self.closed, self.brackets.last(), next.as_ref()) {
+            LineEnd::Drop => {}
+            LineEnd::Keep => self.out.push_back(token),
+            LineEnd::Insert => {
+                let span = Span::new(token.span.file.clone(), token.span.line, token.span.column, token.span.start, token.span.start);
+                self.out.push_back(Token::new(Tok::Semi, span));
+                self.out.push_back(token);
+                self.prev = Some(Tok::Semi);
+            }
+        }
+    }
+
+    fn token(&mut self, token: Token) {
+        let prev = self.prev.clone();
+        match &token.tok {
+            Tok::Lpar => match prev {
+                Some(Tok::While) if self.do_while => self.brackets.push(Bracket::Paren),
+                Some(Tok::If) | Some(Tok::For) | Some(Tok::While) | Some(Tok::With) => self.brackets.push(Bracket::Head),
+                _ => self.brackets.push(Bracket::Paren)
+            },
+            Tok::Lsqb | Tok::TemplateHead { .. } => self.brackets.push(Bracket::Paren),
+            Tok::Rsqb | Tok::TemplateTail { .. } => {
+                self.brackets.pop();
+            }
+            Tok::Rpar | Tok::Rbrace => {
+                self.closed = self.brackets.pop();
+            }
+            Tok::Lbrace => {
+                let bracket = match self.bodies.last() {
+                    Some((depth, body)) if *depth == self.brackets.len() => {
+                        let body = *body;
+                        self.bodies.pop();
+                        body
+                    }
+                    _ if prev == Some(Tok::RdoubleArrow) => Bracket::Block { expression: false },
+                    _ if expects_operand(prev.as_ref(), self.brackets.last()) => Bracket::Object,
+                    _ => Bracket::Block { expression: false }
+                };
+                self.brackets.push(bracket);
+            }
+            Tok::Function => {
+                let expression = expects_operand(prev.as_ref(), self.brackets.last());
+                self.bodies.push((self.brackets.len(), Bracket::Block { expression }));
+            }
+            Tok::Class => {
+                self.bodies.push((self.brackets.len(), Bracket::Object));
+            }
+            Tok::Do => self.dos.push(self.brackets.len()),
+            Tok::While => {
+                self.do_while = self.dos.last() == Some(&self.brackets.len());
+                if self.do_while {
+                    self.dos.pop();
+                }
+            }
+            _ => {}
+        }
+        self.prefix = (token.tok == Tok::PlusPlus || token.tok == Tok::MinusMinus)
+            && !prev.as_ref().map_or(false, can_end_statement);
+        self.prev = Some(token.tok.clone());
+        self.out.push_back(token);
+    }
+}
+
+impl<I: Iterator<Item = Token>> Iterator for SemicolonInserter<I> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        while self.out.is_empty() {
+            let token = self.pull()?;
+            if token.tok == Tok::EndOfLine {
+                self.line_end(token);
+            } else {
+                self.token(token);
+            }
+        }
+        self.out.pop_front()
+    }
+}
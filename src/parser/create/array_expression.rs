@@ -1,17 +1,20 @@
-use crate::lexer::js_token::Tok;
+use crate::parser::property_map::PropertyMap;
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::span::Span;
 use crate::parser::symbols::{JSItem, Expression};
 use crate::parser::parser::{SyntaxError, Parser};
 use crate::parser::create::comma_separate_tokens;
 
-pub(crate) fn create_array_expression(mut tokens: Vec<Tok>) -> Result<JSItem, SyntaxError> {
+pub(crate) fn create_array_expression(mut tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
     //get rid of lsqb
     tokens.remove(0);
     //get rid of eol and semi if they are on the end
     loop {
         //get rid of EOL if it exists, we don't need it at this point.
-        if let Tok::EndOfLine = tokens.get(tokens.len() - 1).unwrap() {
+        if let Tok::EndOfLine = tokens.get(tokens.len() - 1).unwrap().tok {
             tokens.pop();
-        } else if let Tok::Semi = tokens.get(tokens.len() - 1).unwrap() {
+        } else if let Tok::Semi = tokens.get(tokens.len() - 1).unwrap().tok {
             tokens.pop();
         } else {
             break;
@@ -27,9 +30,9 @@ pub(crate) fn create_array_expression(mut tokens: Vec<Tok>) -> Result<JSItem, Sy
     for mut item in items {
         loop {
             //get rid of EOL if it exists, we don't need it at this point.
-            if let Tok::EndOfLine = item.get(item.len() - 1).unwrap() {
+            if let Tok::EndOfLine = item.get(item.len() - 1).unwrap().tok {
                 item.pop();
-            } else if let Tok::Semi = item.get(item.len() - 1).unwrap() {
+            } else if let Tok::Semi = item.get(item.len() - 1).unwrap().tok {
                 item.pop();
             } else {
                 break;
@@ -38,25 +41,24 @@ pub(crate) fn create_array_expression(mut tokens: Vec<Tok>) -> Result<JSItem, Sy
 
         loop {
             //get rid of EOL if it exists, we don't need it at this point.
-            if let Tok::EndOfLine = item.get(0).unwrap() {
+            if let Tok::EndOfLine = item.get(0).unwrap().tok {
                 item.remove(0);
-            } else if let Tok::Semi = item.get(0).unwrap() {
+            } else if let Tok::Semi = item.get(0).unwrap().tok {
                 item.remove(0);
             } else {
                 break;
             }
         }
         let mut p = Parser::new();
-        let mut value = p.parse(item);
-        array.push(value.pop().unwrap());
+        array.push(p.parse_one(item)?);
     }
     let len = JSItem::Number {value: array.len() as f64 };
     Ok(JSItem::Ex {
         expression: Box::new(Expression::ArrayExpression {
             items: array,
-            properties: hashmap!{
-                "length".to_string() => len
-            }
-        })
+            properties: PropertyMap::from(vec![("length".to_string(), len)]),
+            span: span.clone()
+        }),
+        span
     })
 }
\ No newline at end of file
@@ -1,11 +1,12 @@
-use crate::parser::symbols::JSItem;
-use crate::lexer::js_token::Tok;
+use crate::parser::symbols::{JSItem, number_to_string};
+use crate::lexer::js_token::{Tok, Token};
 use crate::parser::create::comma_separate_tokens;
 use crate::parser::parser::{Parser, SyntaxError};
-use std::collections::HashMap;
+use crate::parser::property_map::PropertyMap;
 use crate::parser::parser::SyntaxError::UnexpectedToken;
+use crate::lexer::find_token::keyword_name;
 
-pub(crate) fn create_object_expression(mut tokens: Vec<Tok>) -> Result<JSItem, SyntaxError> {
+pub(crate) fn create_object_expression(mut tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
     //get rid of braces
     tokens.remove(0);
     tokens.pop();
@@ -13,14 +14,14 @@ pub(crate) fn create_object_expression(mut tokens: Vec<Tok>) -> Result<JSItem, S
 
     let mut items = comma_separate_tokens(tokens);
 
-    let mut object = HashMap::new();
+    let mut object = PropertyMap::new();
 
     for mut item in items {
         loop {
             //get rid of EOL if it exists, we don't need it at this point.
-            if let Tok::EndOfLine = item.get(item.len() - 1).unwrap() {
+            if let Tok::EndOfLine = item.get(item.len() - 1).unwrap().tok {
                 item.pop();
-            } else if let Tok::Semi = item.get(item.len() - 1).unwrap() {
+            } else if let Tok::Semi = item.get(item.len() - 1).unwrap().tok {
                 item.pop();
             } else {
                 break;
@@ -29,9 +30,9 @@ pub(crate) fn create_object_expression(mut tokens: Vec<Tok>) -> Result<JSItem, S
 
         loop {
             //get rid of EOL if it exists, we don't need it at this point.
-            if let Tok::EndOfLine = item.get(0).unwrap() {
+            if let Tok::EndOfLine = item.get(0).unwrap().tok {
                 item.remove(0);
-            } else if let Tok::Semi = item.get(0).unwrap() {
+            } else if let Tok::Semi = item.get(0).unwrap().tok {
                 item.remove(0);
             } else {
                 break;
@@ -40,12 +41,20 @@ pub(crate) fn create_object_expression(mut tokens: Vec<Tok>) -> Result<JSItem, S
 
         let mut key ;
         let tok = item.remove(0);
-        match tok {
+        match &tok.tok {
             Tok::Name {name} => {
-                key = name;
+                key = name.clone();
             }
             Tok::String {value} => {
-                key = value;
+                key = value.clone();
+            }
+            // {1: a} and {1.0: a} both have the key "1"
+            Tok::Float {value} => {
+                key = number_to_string(*value);
+            }
+            // reserved words are fine as keys, {default: 1}
+            tok if keyword_name(tok).is_some() => {
+                key = keyword_name(tok).unwrap().to_string();
             }
             _ => {
                 return Err(SyntaxError::UnexpectedToken {tok})
@@ -54,7 +63,7 @@ pub(crate) fn create_object_expression(mut tokens: Vec<Tok>) -> Result<JSItem, S
 
         //get rid of colon
         let colon = item.remove(0);
-        match colon {
+        match colon.tok {
             Tok::Colon => {},
             _ => {
                 return Err(UnexpectedToken {tok: colon})
@@ -62,8 +71,7 @@ pub(crate) fn create_object_expression(mut tokens: Vec<Tok>) -> Result<JSItem, S
         }
 
         let mut p = Parser::new();
-        let mut value = p.parse(item);
-        object.insert(key, value.pop().unwrap());
+        object.insert(key, p.parse_one(item)?);
     }
 
     Ok(JSItem::Object {
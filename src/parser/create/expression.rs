@@ -1,12 +1,14 @@
-use crate::lexer::js_token::Tok;
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::span::Span;
 use crate::parser::symbols::{JSItem, Statement, Expression, AssignOp};
-use crate::parser::combine::{combine_star, combine_bslash, combine_plus, combine_minus, combine_float, combine_dot, combine_name, combine_string, combine_call, combine_expression, combine_less, combine_array, combine_greater, combine_ampamp, combine_eqeq, combine_eqeqeq};
-use crate::parser::parser::{Parser, SyntaxError};
-use crate::parser::create::comma_separate_tokens;
+use crate::parser::parser::SyntaxError;
+use crate::parser::pratt::parse_expression;
 use crate::parser::create::block_statement::create_object_expression;
 use crate::parser::create::array_expression::create_array_expression;
+use crate::parser::find::matching::{find_matching_brace, find_matching_sqb};
 
-pub(crate) fn create_assignment_expression(mut tokens: Vec<Tok>) -> Result<JSItem, SyntaxError> {
+pub(crate) fn create_assignment_expression(mut tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
     tokens.reverse();
     let mut left = vec![];
     let mut right = vec![];
@@ -24,7 +26,13 @@ pub(crate) fn create_assignment_expression(mut tokens: Vec<Tok>) -> Result<JSIte
     }
 
     if !found_equal {
-        return Err(SyntaxError::UnexpectedToken {tok: left.pop().unwrap()})
+        return match left.pop() {
+            Some(tok) => Err(SyntaxError::UnexpectedToken {tok}),
+            None => Err(SyntaxError::UnexpectedEndOfInput)
+        }
+    }
+    if left.is_empty() || right.iter().all(|t| t.tok == Tok::EndOfLine || t.tok == Tok::Semi) {
+        return Err(SyntaxError::UnexpectedEndOfInput)
     }
 
     let mut assign_op = AssignOp::None;
@@ -39,253 +47,48 @@ pub(crate) fn create_assignment_expression(mut tokens: Vec<Tok>) -> Result<JSIte
         assign_op = AssignOp::Var;
     }
 
-    if let JSItem::Ex {expression: left_expression} = create_expression(left) {
+    if let JSItem::Ex {expression: left_expression, span: left_span} = create_expression(left)? {
         let mut left ;
-        if let Expression::Identifier {name} = *left_expression {
-            left = Box::from(Expression::Literal {value: name});
+        if let Expression::Identifier {name, span} = *left_expression {
+            left = Box::from(Expression::Literal {value: name, span});
         } else {
             left = left_expression;
         }
 
         loop {
             //get rid of EOL if it exists, we don't need it at this point.
-            if let Tok::EndOfLine = right.get(right.len() - 1).unwrap() {
+            if let Tok::EndOfLine = right.get(right.len() - 1).unwrap().tok {
                 right.pop();
-            }else if let Tok::Semi = right.get(right.len() - 1).unwrap() {
+            }else if let Tok::Semi = right.get(right.len() - 1).unwrap().tok {
                 right.pop();
             } else {
                 break;
             }
         }
 
-        if right.get(0).unwrap().eq(&Tok::Lbrace) && right.get(right.len() - 1).unwrap().eq(&Tok::Rbrace) {
-            if let Ok(item) = create_object_expression(right) {
-                if let JSItem::Object { mutable, properties } = item {
-                    return Ok(JSItem::St {
-                        statement: Box::new(Statement::AssignmentExpression {
-                            operator: assign_op,
-                            left: JSItem::Ex { expression: left },
-                            right: JSItem::Object { mutable, properties }
-                        })
-                    })
-                }
-            }
-        } else if right.get(0).unwrap().eq(&Tok::Lsqb) && right.get(right.len() - 1).unwrap().eq(&Tok::Rsqb) {
-            if let Ok(item) = create_array_expression(right) {
-                if let JSItem::Ex { expression: right_expression } = item {
-                    return Ok(JSItem::St {
-                        statement: Box::new(Statement::AssignmentExpression {
-                            operator: assign_op,
-                            left: JSItem::Ex { expression: left },
-                            right: JSItem::Ex {expression: right_expression}
-                        })
-                    })
-                }
-            }
+        // a literal on its own, `[1, 2].length` is an expression that starts with one
+        let right = if right[0].eq(&Tok::Lbrace) && find_matching_brace(0, &right) == right.len() - 1 {
+            create_object_expression(right)?
+        } else if right[0].eq(&Tok::Lsqb) && find_matching_sqb(0, &right) == right.len() - 1 {
+            create_array_expression(right)?
         } else {
-            if let JSItem::Ex { expression: right_expression } = create_expression(right) {
-                return Ok(JSItem::St {
-                    statement: Box::new(Statement::AssignmentExpression {
-                        operator: assign_op,
-                        left: JSItem::Ex { expression: left },
-                        right: JSItem::Ex { expression: right_expression }
-                    })
-                })
-            }
-        }
+            create_expression(right)?
+        };
+        return Ok(JSItem::St {
+            statement: Box::new(Statement::AssignmentExpression {
+                operator: assign_op,
+                left: JSItem::Ex { expression: left, span: left_span },
+                right
+            }),
+            span
+        })
     }
 
-    return Ok(JSItem::Ex {expression: Box::new(Expression::None)})
+    return Ok(JSItem::Ex {expression: Box::new(Expression::None), span})
 }
 
-fn parse_parameters(mut tokens: Vec<Tok>) -> Vec<JSItem> {
-    let mut all_params = comma_separate_tokens(tokens);
-    let mut exp_params = vec![];
-
-    for p in all_params {
-        let mut parser = Parser::new();
-        let mut out = parser.parse(p);
-        exp_params.push(out.pop().unwrap());
-    }
-
-    exp_params
+pub(crate) fn create_expression(tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
+    let expression = parse_expression(&tokens)?;
+    return Ok(JSItem::Ex { expression: Box::new(expression), span });
 }
-
-pub(crate) fn create_expression(mut tokens: Vec<Tok>) -> JSItem {
-    let mut parens_content = vec![];
-    let mut expression_stack = vec![];
-    while tokens.len() > 0 {
-        let token = tokens.pop().unwrap();
-        match token {
-            Tok::EqEqEual => {
-                let ex = expression_stack.pop().unwrap();
-                let exp = combine_eqeqeq(ex);
-                expression_stack.push(exp);
-            }
-            Tok::EqEqual => {
-                let ex = expression_stack.pop().unwrap();
-                let exp = combine_eqeq(ex);
-                expression_stack.push(exp);
-            }
-            Tok::AmpAmp => {
-                let ex = expression_stack.pop().unwrap();
-                let exp = combine_ampamp(ex);
-                expression_stack.push(exp);
-            }
-            Tok::PlusPlus => {
-                let exp = Expression::UpdateExpression {
-                    expression: Box::new(Expression::None),
-                };
-                expression_stack.push(exp);
-            }
-            Tok::Star => {
-                let ex = expression_stack.pop().unwrap();
-                let exp = combine_star(ex);
-                expression_stack.push(exp);
-            }
-            Tok::Bslash => {
-                let ex = expression_stack.pop().unwrap();
-                let exp = combine_bslash(ex);
-                expression_stack.push(exp);
-            }
-            Tok::Plus => {
-                let ex = expression_stack.pop().unwrap_or(Expression::None);
-                let exp = combine_plus(ex);
-                expression_stack.push(exp);
-            }
-            Tok::Minus => {
-                let ex = expression_stack.pop().unwrap_or(Expression::None);
-                let exp = combine_minus(ex);
-                expression_stack.push(exp);
-            }
-            Tok::Less => {
-                let ex = expression_stack.pop().unwrap_or(Expression::None);
-                let exp = combine_less(ex);
-                expression_stack.push(exp);
-            }
-            Tok::Greater => {
-                let ex = expression_stack.pop().unwrap_or(Expression::None);
-                let exp = combine_greater(ex);
-                expression_stack.push(exp);
-            }
-            Tok::Float { value } => {
-                let ex = expression_stack.pop().unwrap_or(Expression::None);
-                let exp = combine_float(ex, value);
-                expression_stack.push(exp);
-            }
-            Tok::Dot => {
-                let ex = expression_stack.pop().unwrap();
-                let exp = combine_dot(ex, token);
-                expression_stack.push(exp);
-            }
-            Tok::Name { name } => {
-                let ex = expression_stack.pop().unwrap_or(Expression::None);
-                let exp = combine_name(ex, name);
-                expression_stack.push(exp);
-            }
-            Tok::String { value } => {
-                let ex = expression_stack.pop().unwrap_or(Expression::None);
-                let exp = combine_string(ex, value);
-                expression_stack.push(exp);
-            }
-            Tok::Semi | Tok::EndOfLine => {}
-            Tok::Comma => {}
-            Tok::Rsqb => {
-                let mut sqb_content = vec![];
-                let mut sqb_stack = vec![];
-                sqb_stack.push("]");
-                while tokens.len() > 0 {
-                    let token = tokens.pop().unwrap();
-                    match token {
-                        Tok::Rsqb => {
-                            sqb_stack.push("]");
-                            sqb_content.push(token);
-                        }
-                        Tok::Lsqb => {
-                            sqb_stack.pop();
-                            if sqb_stack.is_empty() {
-                                break;
-                            } else {
-                                sqb_content.push(token);
-                            }
-                        }
-                        _ => {
-                            sqb_content.push(token);
-                        }
-                    }
-                }
-                let elements = parse_parameters(sqb_content);
-                let ex = expression_stack.pop().unwrap_or(Expression::None);
-                let exp = combine_array(ex, elements);
-                expression_stack.push(exp);
-            }
-            Tok::Rpar => {
-                let mut parens_content = vec![];
-                let mut parens_stack = vec![];
-                parens_stack.push(")");
-                while tokens.len() > 0 {
-                    let token = tokens.pop().unwrap();
-                    match token {
-                        Tok::Rpar => {
-                            parens_stack.push(")");
-                            parens_content.push(token);
-                        }
-                        Tok::Lpar => {
-                            parens_stack.pop();
-                            if parens_stack.is_empty() {
-                                break;
-                            } else {
-                                parens_content.push(token);
-                            }
-                        }
-                        _ => {
-                            parens_content.push(token);
-                        }
-                    }
-                }
-                let ex = expression_stack.pop().unwrap_or(Expression::None);
-                if !tokens.is_empty() {
-                    let next = tokens.pop().unwrap();
-                    match next {
-                        Tok::Name { name } => {
-                            tokens.push(Tok::Name { name });
-                            let parameters = parse_parameters(parens_content);
-                            let exp = combine_call(ex, parameters);
-                            expression_stack.push(exp);
-                        }
-                        _ => {
-                            parens_content.reverse();
-                            tokens.push(next);
-                            let item = create_expression(parens_content);
-                            match item {
-                                JSItem::Ex { expression } => {
-                                    let exp = combine_expression(ex, *expression);
-                                    expression_stack.push(exp);
-                                }
-                                JSItem::St { statement: _ } => {}
-                                _ => {}
-                            }
-                        }
-                    }
-                } else {
-                    parens_content.reverse();
-                    let item = create_expression(parens_content);
-                    match item {
-                        JSItem::Ex { expression } => {
-                            let exp = combine_expression(ex, *expression);
-                            expression_stack.push(exp);
-                        }
-                        JSItem::St { statement: _ } => {}
-                        _ => {}
-                    }
-                }
-            }
-            _ => {
-                parens_content.push(token);
-            }
-        }
-    }
-    let expression = Box::new(expression_stack.pop().unwrap());
-    return JSItem::Ex { expression };
-}
-
@@ -1,8 +1,10 @@
-use crate::lexer::js_token::Tok;
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::span::Span;
 use crate::parser::symbols::{JSItem, Statement};
-use crate::parser::parser::Parser;
+use crate::parser::parser::{Parser, SyntaxError};
 
-pub(crate) fn create_for_statement(mut tokens: Vec<Tok>) -> JSItem {
+pub(crate) fn create_for_statement(mut tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
     tokens.reverse();
 
     //remove for
@@ -14,7 +16,7 @@ pub(crate) fn create_for_statement(mut tokens: Vec<Tok>) -> JSItem {
     let mut initialization_tokens = vec![];
     while !tokens.is_empty() {
         let tok = tokens.pop().unwrap();
-        match tok {
+        match &tok.tok {
             Tok::Semi => {
                 break;
             }
@@ -27,7 +29,7 @@ pub(crate) fn create_for_statement(mut tokens: Vec<Tok>) -> JSItem {
     let mut condition_tokens = vec![];
     while !tokens.is_empty() {
         let tok = tokens.pop().unwrap();
-        match tok {
+        match &tok.tok {
             Tok::Semi => {
                 break;
             }
@@ -41,7 +43,7 @@ pub(crate) fn create_for_statement(mut tokens: Vec<Tok>) -> JSItem {
     let mut stack = vec!["("];
     while !tokens.is_empty() {
         let tok = tokens.pop().unwrap();
-        match tok {
+        match &tok.tok {
             Tok::Lpar => {
                 stack.push("(");
                 final_expression_tokens.push(tok);
@@ -66,7 +68,7 @@ pub(crate) fn create_for_statement(mut tokens: Vec<Tok>) -> JSItem {
     tokens.pop();
     while !tokens.is_empty() {
         let tok = tokens.pop().unwrap();
-        match tok {
+        match &tok.tok {
             Tok::Lbrace => {
                 stack.push("{");
                 body_expression_tokens.push(tok);
@@ -86,23 +88,24 @@ pub(crate) fn create_for_statement(mut tokens: Vec<Tok>) -> JSItem {
     }
 
     let mut parser = Parser::new();
-    let mut initialization_expression = parser.parse(initialization_tokens);
+    let initialization_expression = parser.parse_one(initialization_tokens)?;
 
     parser = Parser::new();
-    let mut condition_expression = parser.parse(condition_tokens);
+    let condition_expression = parser.parse_one(condition_tokens)?;
 
     parser = Parser::new();
-    let mut final_expression = parser.parse(final_expression_tokens);
+    let final_expression = parser.parse_one(final_expression_tokens)?;
 
     parser = Parser::new();
-    let body_expression = parser.parse(body_expression_tokens);
+    let body_expression = parser.parse(body_expression_tokens)?;
 
-    return JSItem::St {
+    return Ok(JSItem::St {
         statement: Box::new(Statement::ForStatement {
-            init: initialization_expression.pop().unwrap(),
-            test: condition_expression.pop().unwrap(),
-            update: final_expression.pop().unwrap(),
+            init: initialization_expression,
+            test: condition_expression,
+            update: final_expression,
             body: body_expression
-        })
-    };
+        }),
+        span
+    });
 }
\ No newline at end of file
@@ -1,13 +1,15 @@
-use crate::lexer::js_token::Tok;
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::span::Span;
 use crate::parser::symbols::{JSItem, Statement, Expression};
-use crate::parser::parser::Parser;
+use crate::parser::parser::{Parser, SyntaxError};
 
 
-pub(crate) fn create_arrow_function(mut tokens: Vec<Tok>) -> JSItem {
+pub(crate) fn create_arrow_function(mut tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
     tokens.reverse();
     let mutable = tokens.pop().unwrap();
     let mut function_name = "".to_string();
-    match tokens.pop().unwrap() {
+    match tokens.pop().unwrap().tok {
         Tok::Name { name } => {
             function_name = String::from(name);
         }
@@ -22,18 +24,18 @@ pub(crate) fn create_arrow_function(mut tokens: Vec<Tok>) -> JSItem {
     stack.push(tokens.pop().unwrap());
     while !stack.is_empty() {
         let tok = tokens.pop().unwrap();
-        match tok {
+        match &tok.tok {
             Tok::Rpar => {
                 stack.pop();
                 if stack.is_empty() {
                     break;
                 } else {
-                    function_args.push(Tok::Rpar);
+                    function_args.push(tok);
                 }
             }
             Tok::Lpar => {
-                stack.push(Tok::Lpar);
-                function_args.push(Tok::Lpar);
+                stack.push(tok.clone());
+                function_args.push(tok);
             }
             _ => {
                 function_args.push(tok)
@@ -48,18 +50,18 @@ pub(crate) fn create_arrow_function(mut tokens: Vec<Tok>) -> JSItem {
     stack.push(tokens.pop().unwrap());
     while !stack.is_empty() {
         let tok = tokens.pop().unwrap();
-        match tok {
+        match &tok.tok {
             Tok::Rbrace => {
                 stack.pop();
                 if stack.is_empty() {
                     break;
                 } else {
-                    function_body.push(Tok::Rpar);
+                    function_body.push(tok);
                 }
             }
             Tok::Lbrace => {
-                stack.push(Tok::Lbrace);
-                function_body.push(Tok::Lbrace);
+                stack.push(tok.clone());
+                function_body.push(tok);
             }
             _ => {
                 function_body.push(tok)
@@ -68,24 +70,25 @@ pub(crate) fn create_arrow_function(mut tokens: Vec<Tok>) -> JSItem {
     }
 
     let mut p = Parser::new();
-    let out = p.parse(function_body);
+    let out = p.parse(function_body)?;
     let statement = Box::new(Statement::AssignArrowFunction {
         mutable: mutable.eq(&Tok::Let),
         function: Box::new(Statement::FunctionDef {
             name: function_name,
-            params: function_args,
+            params: function_args.into_iter().map(|token| token.tok).collect(),
             body: out,
         }),
     });
-    let item = JSItem::St { statement };
-    return item;
+    let item = JSItem::St { statement, span };
+    return Ok(item);
 }
 
-pub(crate) fn create_function_assignment(mut tokens: Vec<Tok>) -> JSItem {
+pub(crate) fn create_function_assignment(mut tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
     tokens.reverse();
     let mutable = tokens.pop().unwrap();
     let mut function_name = "".to_string();
-    match tokens.pop().unwrap() {
+    match tokens.pop().unwrap().tok {
         Tok::Name { name } => {
             function_name = String::from(name);
         }
@@ -101,16 +104,16 @@ pub(crate) fn create_function_assignment(mut tokens: Vec<Tok>) -> JSItem {
     stack.push(tokens.pop().unwrap());
     while !stack.is_empty() {
         let tok = tokens.pop().unwrap();
-        match tok {
+        match &tok.tok {
             Tok::Rpar => {
                 stack.pop();
                 if !stack.is_empty() {
-                    function_args.push(Tok::Rpar);
+                    function_args.push(tok);
                 }
             }
             Tok::Lpar => {
-                stack.push(Tok::Lpar);
-                function_args.push(Tok::Lpar);
+                stack.push(tok.clone());
+                function_args.push(tok);
             }
             _ => {
                 function_args.push(tok)
@@ -122,16 +125,16 @@ pub(crate) fn create_function_assignment(mut tokens: Vec<Tok>) -> JSItem {
     stack.push(tokens.pop().unwrap());
     while !stack.is_empty() {
         let tok = tokens.pop().unwrap();
-        match tok {
+        match &tok.tok {
             Tok::Rbrace => {
                 stack.pop();
                 if !stack.is_empty() {
-                    function_body.push(Tok::Rbrace);
+                    function_body.push(tok);
                 }
             }
             Tok::Lbrace => {
-                stack.push(Tok::Lbrace);
-                function_body.push(Tok::Lbrace);
+                stack.push(tok.clone());
+                function_body.push(tok);
             }
             _ => {
                 function_body.push(tok)
@@ -140,27 +143,28 @@ pub(crate) fn create_function_assignment(mut tokens: Vec<Tok>) -> JSItem {
     }
 
     let mut p = Parser::new();
-    let out = p.parse(function_body);
+    let out = p.parse(function_body)?;
     let statement = Box::new(Statement::AssignFunction {
         mutable: mutable.eq(&Tok::Let),
         function: Box::new(Statement::FunctionDef {
             name: function_name,
-            params: function_args,
+            params: function_args.into_iter().map(|token| token.tok).collect(),
             body: out,
         }),
     });
-    let item = JSItem::St { statement };
-    return item;
+    let item = JSItem::St { statement, span };
+    return Ok(item);
 }
 
-pub(crate) fn create_function(mut tokens: Vec<Tok>) -> JSItem {
+pub(crate) fn create_function(mut tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
     tokens.reverse();
 
     //get rid of function
     tokens.pop();
     //function name should be next
     let mut function_name = String::from("");
-    match tokens.pop().unwrap() {
+    match tokens.pop().unwrap().tok {
         Tok::Name { name } => {
             function_name = name;
         }
@@ -173,7 +177,7 @@ pub(crate) fn create_function(mut tokens: Vec<Tok>) -> JSItem {
     let mut in_body = false;
     while tokens.len() > 0 {
         let token = tokens.pop().unwrap();
-        match token {
+        match &token.tok {
             Tok::Lpar => {
                 if !in_body {
                     stack.push("(");
@@ -226,18 +230,19 @@ pub(crate) fn create_function(mut tokens: Vec<Tok>) -> JSItem {
     }
 
     let mut p = Parser::new();
-    let out = p.parse(function_body);
+    let out = p.parse(function_body)?;
 
     let statement = Box::new(Statement::FunctionDef {
         name: function_name,
-        params: function_params,
+        params: function_params.into_iter().map(|token| token.tok).collect(),
         body: out,
     });
-    let item = JSItem::St { statement };
-    return item;
+    let item = JSItem::St { statement, span };
+    return Ok(item);
 }
 
-pub(crate) fn create_function_expression(mut tokens: Vec<Tok>) -> JSItem {
+pub(crate) fn create_function_expression(mut tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
     tokens.reverse();
 
     //get rid of function
@@ -248,17 +253,18 @@ pub(crate) fn create_function_expression(mut tokens: Vec<Tok>) -> JSItem {
     stack.push(tokens.pop().unwrap());
     while !stack.is_empty() {
         let tok = tokens.pop().unwrap();
-        match tok {
+        match &tok.tok {
             Tok::Rpar => {
                 stack.pop();
                 if !stack.is_empty() {
-                    function_args.push(Tok::Rpar);
+                    function_args.push(tok);
                 }
             }
             Tok::Lpar => {
-                stack.push(Tok::Lpar);
-                function_args.push(Tok::Lpar);
+                stack.push(tok.clone());
+                function_args.push(tok);
             }
+            Tok::Comma => {}
             _ => {
                 function_args.push(tok)
             }
@@ -269,16 +275,16 @@ pub(crate) fn create_function_expression(mut tokens: Vec<Tok>) -> JSItem {
     stack.push(tokens.pop().unwrap());
     while !stack.is_empty() {
         let tok = tokens.pop().unwrap();
-        match tok {
+        match &tok.tok {
             Tok::Rbrace => {
                 stack.pop();
                 if !stack.is_empty() {
-                    function_body.push(Tok::Rbrace);
+                    function_body.push(tok);
                 }
             }
             Tok::Lbrace => {
-                stack.push(Tok::Lbrace);
-                function_body.push(Tok::Lbrace);
+                stack.push(tok.clone());
+                function_body.push(tok);
             }
             _ => {
                 function_body.push(tok)
@@ -287,11 +293,12 @@ pub(crate) fn create_function_expression(mut tokens: Vec<Tok>) -> JSItem {
     }
 
     let mut p = Parser::new();
-    let out = p.parse(function_body);
+    let out = p.parse(function_body)?;
     let expression = Box::new(Expression::FuncEx{
-        params: function_args,
-        body: out
+        params: function_args.into_iter().map(|token| token.tok).collect(),
+        body: out,
+        span: span.clone()
     });
-    let item = JSItem::Ex { expression };
-    return item;
+    let item = JSItem::Ex { expression, span };
+    return Ok(item);
 }
\ No newline at end of file
@@ -1,108 +1,36 @@
-use crate::lexer::js_token::Tok;
+use crate::lexer::js_token::Token;
+use crate::lexer::span::Span;
 use crate::parser::symbols::{JSItem, Expression, Statement};
-use crate::parser::parser::Parser;
+use crate::parser::parser::{Parser, SyntaxError};
+use crate::parser::find::matching::find_matching_paren;
+use crate::parser::find::while_statement::find_end_of_body;
+use crate::parser::find::if_statement::find_else;
+use crate::parser::create::while_statement::create_body;
 
-pub(crate) fn create_if_statement(mut tokens: Vec<Tok>) -> JSItem {
-    tokens.reverse();
+pub(crate) fn create_if_statement(tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
 
-    // remove first if
-    tokens.pop();
-
-    // remove lpar
-    tokens.pop();
-
-    let mut test_expression_tokens = vec![];
-    let mut stack = vec!["("];
-    while !tokens.is_empty() {
-        let tok = tokens.pop().unwrap();
-        match tok {
-            Tok::Lpar => {
-                stack.push("(");
-                test_expression_tokens.push(tok);
-            }
-            Tok::Rpar => {
-                stack.pop();
-                if stack.is_empty() {
-                    break;
-                } else {
-                    test_expression_tokens.push(tok);
-                }
-            }
-            _ => {
-                test_expression_tokens.push(tok);
-            }
-        }
-    }
-
-    let mut consequent_tokens = vec![];
-    if tokens.get(tokens.len() - 1).unwrap().eq(&Tok::Lbrace) {
-        tokens.pop();
-        let mut stack = vec!["{"];
-        while !tokens.is_empty() {
-            let tok = tokens.pop().unwrap();
-            match tok {
-                Tok::Lbrace => {
-                    stack.push("{");
-                    consequent_tokens.push(tok);
-                }
-                Tok::Rbrace => {
-                    stack.pop();
-                    if stack.is_empty() {
-                        break;
-                    } else {
-                        consequent_tokens.push(tok);
-                    }
-                }
-                _ => {
-                    consequent_tokens.push(tok);
-                }
-            }
-        }
-    } else {
-        if tokens.get(tokens.len() - 1).unwrap().eq(&Tok::EndOfLine) {
-            tokens.pop();
-        }
-        while !tokens.is_empty() {
-            let tok = tokens.pop().unwrap();
-            match tok {
-                Tok::EndOfLine => {
-                    break;
-                }
-                _ => {
-                    consequent_tokens.push(tok);
-                }
-            }
-        }
-    }
+    // if (test) consequent else alternate
+    let rpar = find_matching_paren(1, &tokens);
+    let mut parser = Parser::new();
+    let test = parser.parse_one(tokens[2..rpar].to_vec())?;
 
-    let mut alternate_tokens = vec![];
-    if !tokens.is_empty() {
-        if tokens.get(tokens.len() - 1).unwrap().eq(&Tok::Else) {
-            tokens.pop();
-            while !tokens.is_empty() {
-                alternate_tokens.push(tokens.pop().unwrap());
-            }
-        }
-    }
+    let end = find_end_of_body(rpar + 1, &tokens)?;
+    let consequent = create_body(&tokens[rpar + 1..=end])?;
 
-    let mut parser = Parser::new();
-    let mut test_expression = parser.parse(test_expression_tokens);
-    let mut consequent_expression = parser.parse(consequent_tokens);
-    let mut alternate_expression = vec![JSItem::Ex { expression: Box::from(Expression::None)}];
-    if alternate_tokens.len() > 0 {
-        if alternate_tokens.get(0).unwrap().eq(&Tok::Lbrace) {
-            alternate_tokens.remove(0);
-            alternate_tokens.pop();
-        }
-        alternate_expression = parser.parse(alternate_tokens);
+    let mut alternate = vec![];
+    if let Some(else_i) = find_else(end, &tokens) {
+        let end = find_end_of_body(else_i + 1, &tokens)?;
+        alternate = create_body(&tokens[else_i + 1..=end])?;
     }
 
-
-    JSItem::St {
+    Ok(JSItem::St {
         statement: Box::from(Statement::If {
-            test: test_expression.pop().unwrap(),
-            consequent: consequent_expression,
-            alternate: alternate_expression.pop().unwrap()
-        })
-    }
-}
\ No newline at end of file
+            test,
+            consequent,
+            alternate: alternate.pop()
+                .unwrap_or(JSItem::Ex { expression: Box::from(Expression::None), span: Span::default() })
+        }),
+        span
+    })
+}
@@ -0,0 +1,36 @@
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::span::Span;
+use crate::parser::symbols::{JSItem, Statement};
+use crate::parser::parser::SyntaxError;
+use crate::parser::create::while_statement::create_body;
+
+pub(crate) fn create_labelled_statement(tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
+
+    let label = match &tokens.get(0).unwrap().tok {
+        Tok::Name { name } => name.clone(),
+        _ => String::new()
+    };
+    let start = 2 + tokens[2..].iter().take_while(|t| t.tok == Tok::EndOfLine).count();
+
+    return Ok(JSItem::St {
+        statement: Box::new(Statement::Labelled { label, body: create_body(&tokens[start..])? }),
+        span
+    });
+}
+
+/// `break` or `continue` with an optional label, `tokens` is the keyword and the label.
+pub(crate) fn create_jump_statement(tokens: Vec<Token>) -> JSItem {
+    let span = Span::of(&tokens);
+
+    let label = match tokens.get(1).map(|t| &t.tok) {
+        Some(Tok::Name { name }) => Some(name.clone()),
+        _ => None
+    };
+    let statement = match &tokens.get(0).unwrap().tok {
+        Tok::Continue => Statement::Continue { label },
+        _ => Statement::Break { label }
+    };
+
+    return JSItem::St { statement: Box::new(statement), span };
+}
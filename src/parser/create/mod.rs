@@ -1,4 +1,4 @@
-use crate::lexer::js_token::Tok;
+use crate::lexer::js_token::{Tok, Token};
 
 pub(crate) mod function;
 pub(crate) mod expression;
@@ -6,19 +6,24 @@ pub(crate) mod for_statement;
 pub(crate) mod block_statement;
 pub(crate) mod array_expression;
 pub(crate) mod if_statement;
+pub(crate) mod while_statement;
+pub(crate) mod label;
+pub(crate) mod switch_statement;
+pub(crate) mod try_statement;
+pub(crate) mod template;
 
-pub(crate) fn comma_separate_tokens(mut tokens: Vec<Tok>) -> Vec<Vec<Tok>> {
+pub(crate) fn comma_separate_tokens(mut tokens: Vec<Token>) -> Vec<Vec<Token>> {
     let mut stack = vec![];
     let mut current = vec![];
     let mut all = vec![];
     while tokens.len() > 0 {
         let tok = tokens.pop().unwrap();
-        match tok {
-            Tok::Lsqb | Tok::Lbrace | Tok::Lpar => {
+        match &tok.tok {
+            Tok::Lsqb | Tok::Lbrace | Tok::Lpar | Tok::TemplateHead { .. } => {
                 stack.push(".");
                 current.push(tok);
             }
-            Tok::Rsqb | Tok::Rbrace | Tok::Rpar => {
+            Tok::Rsqb | Tok::Rbrace | Tok::Rpar | Tok::TemplateTail { .. } => {
                 stack.pop();
                 current.push(tok);
             }
@@ -26,6 +31,8 @@ pub(crate) fn comma_separate_tokens(mut tokens: Vec<Tok>) -> Vec<Vec<Tok>> {
                 if stack.len() == 0 {
                     all.push(current.clone());
                     current = vec![];
+                } else {
+                    current.push(tok);
                 }
             }
             _ => {
@@ -0,0 +1,84 @@
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::span::Span;
+use crate::parser::symbols::{JSItem, Statement, SwitchCase};
+use crate::parser::parser::{Parser, SyntaxError};
+use crate::parser::find::matching::find_matching_paren;
+
+/// Index of the `:` ending the case test starting at `start`, skipping the `:` of any
+/// conditional expression and anything in brackets.
+fn find_case_colon(start: usize, tokens: &[Token]) -> usize {
+    let mut depth = 0;
+    let mut conditionals = 0;
+    let mut j = start;
+    while j < tokens.len() {
+        match &tokens[j].tok {
+            Tok::Lpar | Tok::Lsqb | Tok::Lbrace => depth += 1,
+            Tok::Rpar | Tok::Rsqb | Tok::Rbrace => depth -= 1,
+            Tok::Question if depth == 0 => conditionals += 1,
+            Tok::Colon if depth == 0 && conditionals > 0 => conditionals -= 1,
+            Tok::Colon if depth == 0 => return j,
+            _ => {}
+        }
+        j += 1;
+    }
+    tokens.len()
+}
+
+/// The statements of one case, up to the next `case` or `default` at the top of the block.
+fn create_consequent(start: usize, tokens: &[Token]) -> Result<(Vec<JSItem>, usize), SyntaxError> {
+    let mut depth = 0;
+    let mut end = start;
+    while end < tokens.len() {
+        match &tokens[end].tok {
+            Tok::Lpar | Tok::Lsqb | Tok::Lbrace => depth += 1,
+            Tok::Rpar | Tok::Rsqb | Tok::Rbrace => depth -= 1,
+            Tok::Case | Tok::Default if depth == 0 => break,
+            _ => {}
+        }
+        end += 1;
+    }
+    let body = &tokens[start..end];
+    if body.iter().all(|t| t.tok == Tok::EndOfLine || t.tok == Tok::Semi) {
+        return Ok((vec![], end));
+    }
+    let mut parser = Parser::new();
+    Ok((parser.parse(body.to_vec())?, end))
+}
+
+pub(crate) fn create_switch_statement(tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
+
+    // switch (discriminant) { case test: consequent default: consequent }
+    let rpar = find_matching_paren(1, &tokens);
+    let mut parser = Parser::new();
+    let discriminant = parser.parse_one(tokens[2..rpar].to_vec())?;
+
+    let lbrace = rpar + 1 + tokens[rpar + 1..].iter().position(|t| t.tok == Tok::Lbrace).unwrap();
+    let block = &tokens[lbrace + 1..tokens.len() - 1];
+    let mut cases = vec![];
+    let mut j = 0;
+    while j < block.len() {
+        match &block[j].tok {
+            Tok::Case => {
+                let colon = find_case_colon(j + 1, block);
+                let mut parser = Parser::new();
+                let test = Some(parser.parse_one(block[j + 1..colon].to_vec())?);
+                let (consequent, end) = create_consequent(colon + 1, block)?;
+                cases.push(SwitchCase { test, consequent });
+                j = end;
+            }
+            Tok::Default => {
+                let colon = find_case_colon(j + 1, block);
+                let (consequent, end) = create_consequent(colon + 1, block)?;
+                cases.push(SwitchCase { test: None, consequent });
+                j = end;
+            }
+            _ => j += 1
+        }
+    }
+
+    return Ok(JSItem::St {
+        statement: Box::new(Statement::Switch { discriminant, cases }),
+        span
+    });
+}
@@ -0,0 +1,56 @@
+use crate::lexer::span::Span;
+use crate::lexer::js_token::{Tok, Token};
+use crate::parser::symbols::Expression;
+use crate::parser::parser::{Parser, SyntaxError};
+
+/// Builds a template literal from the tokens between its opening and closing backtick, the
+/// tokens of each `${}` hole are parsed as a separate expression.
+pub(crate) fn create_template_literal(tokens: Vec<Token>) -> Result<Expression, SyntaxError> {
+    let span = Span::of(&tokens);
+    let mut cooked = vec![];
+    let mut raw = vec![];
+    let mut expressions = vec![];
+    let mut hole: Vec<Token> = vec![];
+    let mut depth = 0;
+
+    for token in tokens {
+        match &token.tok {
+            Tok::NoSubstitutionTemplate { cooked: c, raw: r } if depth == 0 => {
+                cooked.push(c.clone());
+                raw.push(r.clone());
+            }
+            Tok::TemplateHead { cooked: c, raw: r } if depth == 0 => {
+                cooked.push(c.clone());
+                raw.push(r.clone());
+                depth += 1;
+            }
+            Tok::TemplateMiddle { cooked: c, raw: r } | Tok::TemplateTail { cooked: c, raw: r } if depth == 1 => {
+                cooked.push(c.clone());
+                raw.push(r.clone());
+                expressions.push(Parser::new().parse_one(hole)?);
+                hole = vec![];
+                if let Tok::TemplateTail { .. } = token.tok {
+                    depth -= 1;
+                }
+            }
+            Tok::TemplateHead { .. } => {
+                depth += 1;
+                hole.push(token);
+            }
+            Tok::TemplateTail { .. } => {
+                depth -= 1;
+                hole.push(token);
+            }
+            _ => {
+                hole.push(token);
+            }
+        }
+    }
+
+    Ok(Expression::TemplateLiteral {
+        cooked,
+        raw,
+        expressions,
+        span
+    })
+}
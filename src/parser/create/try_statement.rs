@@ -0,0 +1,63 @@
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::span::Span;
+use crate::parser::symbols::{JSItem, Statement, CatchClause};
+use crate::parser::parser::SyntaxError;
+use crate::parser::create::while_statement::create_body;
+use crate::parser::find::matching::{find_matching_paren, find_matching_brace};
+use crate::parser::pratt::parse_expression;
+
+/// The block starting at the first `{` from `j`, and the index of its `}`.
+fn create_block(j: usize, tokens: &Vec<Token>) -> Result<(Vec<JSItem>, usize), SyntaxError> {
+    let lbrace = j + tokens[j..].iter().position(|t| t.tok == Tok::Lbrace).unwrap();
+    let rbrace = find_matching_brace(lbrace, tokens);
+    Ok((create_body(&tokens[lbrace..=rbrace])?, rbrace))
+}
+
+pub(crate) fn create_try_statement(tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
+
+    // try { block } catch (param) { body } finally { finalizer }
+    let (block, mut end) = create_block(1, &tokens)?;
+
+    let next = |end: usize| tokens[end + 1..].iter().position(|t| t.tok != Tok::EndOfLine).map(|j| end + 1 + j);
+    let mut handler = None;
+    if let Some(j) = next(end).filter(|j| tokens[*j].tok == Tok::Catch) {
+        let mut param = None;
+        let mut body_start = j + 1;
+        if let Some(lpar) = next(j).filter(|lpar| tokens[*lpar].tok == Tok::Lpar) {
+            if let Some(Tok::Name { name }) = tokens.get(lpar + 1).map(|t| &t.tok) {
+                param = Some(name.clone());
+            }
+            body_start = find_matching_paren(lpar, &tokens) + 1;
+        }
+        let (body, rbrace) = create_block(body_start, &tokens)?;
+        handler = Some(CatchClause { param, body });
+        end = rbrace;
+    }
+
+    let finalizer = match next(end).filter(|j| tokens[*j].tok == Tok::Finally) {
+        Some(j) => Some(create_block(j + 1, &tokens)?.0),
+        None => None
+    };
+
+    return Ok(JSItem::St {
+        statement: Box::new(Statement::Try { block, handler, finalizer }),
+        span
+    });
+}
+
+/// `throw value`, `tokens` runs from the keyword to the end of the statement.
+pub(crate) fn create_throw_statement(tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
+
+    let value_tokens = tokens[1..].to_vec();
+    let value = JSItem::Ex {
+        expression: Box::new(parse_expression(&value_tokens)?),
+        span: Span::of(&value_tokens)
+    };
+
+    return Ok(JSItem::St {
+        statement: Box::new(Statement::Throw { value: Box::new(value) }),
+        span
+    });
+}
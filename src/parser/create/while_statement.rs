@@ -0,0 +1,59 @@
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::span::Span;
+use crate::parser::symbols::{JSItem, Statement};
+use crate::parser::parser::{Parser, SyntaxError};
+use crate::parser::find::matching::{find_matching_paren, find_matching_brace};
+use crate::parser::find::while_statement::find_end_of_body;
+
+/// The statements of a loop body, either a block or a single statement.
+pub(crate) fn create_body(tokens: &[Token]) -> Result<Vec<JSItem>, SyntaxError> {
+    let tokens = &tokens[tokens.iter().take_while(|t| t.tok == Tok::EndOfLine).count()..];
+    let tokens = match tokens.first().map(|t| &t.tok) {
+        Some(Tok::Lbrace) => {
+            let close = find_matching_brace(0, &tokens.to_vec());
+            tokens[1..close].to_vec()
+        }
+        _ => tokens.to_vec()
+    };
+    if tokens.iter().all(|t| t.tok == Tok::EndOfLine || t.tok == Tok::Semi) {
+        return Ok(vec![]);
+    }
+    let mut parser = Parser::new();
+    parser.parse(tokens)
+}
+
+/// The condition between the parentheses opening at `lpar`.
+fn create_test(lpar: usize, tokens: &Vec<Token>) -> Result<(JSItem, usize), SyntaxError> {
+    let rpar = find_matching_paren(lpar, tokens);
+    let mut parser = Parser::new();
+    let test = parser.parse_one(tokens[lpar + 1..rpar].to_vec())?;
+    Ok((test, rpar))
+}
+
+pub(crate) fn create_while_statement(tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
+
+    // while (test) body
+    let (test, rpar) = create_test(1, &tokens)?;
+    let body = create_body(&tokens[rpar + 1..])?;
+
+    return Ok(JSItem::St {
+        statement: Box::new(Statement::While { test, body }),
+        span
+    });
+}
+
+pub(crate) fn create_do_while_statement(tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+    let span = Span::of(&tokens);
+
+    // do body while (test)
+    let end = find_end_of_body(1, &tokens)?;
+    let body = create_body(&tokens[1..=end])?;
+    let while_i = end + 1 + tokens[end + 1..].iter().position(|t| t.tok == Tok::While).unwrap();
+    let (test, _) = create_test(while_i + 1, &tokens)?;
+
+    return Ok(JSItem::St {
+        statement: Box::new(Statement::DoWhile { body, test }),
+        span
+    });
+}
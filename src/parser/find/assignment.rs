@@ -1,10 +1,10 @@
-use crate::lexer::js_token::Tok;
+use crate::lexer::js_token::{Tok, Token};
 use crate::parser::parser::{AssignmentType, SyntaxError};
-use crate::parser::find::function::{find_arrow_function, find_function_assignment, find_object_assignment, find_array_assignment};
+use crate::parser::find::function::{tok_at, find_arrow_function, find_function_assignment, find_object_assignment, find_array_assignment};
 use crate::parser::find::matching::{find_matching_paren, find_matching_brace};
 use crate::parser::find::expression::find_end_of_expression;
 
-pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Tok>) -> Result<AssignmentType, SyntaxError> {
+pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Token>) -> Result<AssignmentType, SyntaxError> {
     let mut current_type = "assignment";
     let mut j = start + 1;
 
@@ -28,30 +28,12 @@ pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Tok>) -> Result<
                     j = array_assignment + 1;
                     current_type = "array_assignment";
                 } else {
-                    let token = tokens.get(j + 2 as usize).unwrap();
-                    match token {
-                        Tok::Float { value: _ } => {
-                            let k = find_end_of_expression(j + 2, tokens, "float");
-                            current_type = "expression";
-                            j = k;
-                        }
-                        Tok::String { value: _ } => {
-                            let k = find_end_of_expression(j + 2, tokens, "string");
-                            current_type = "expression";
-                            j = k;
-                        }
-                        Tok::Name { name: _ } => {
-                            let k = find_end_of_expression(j + 2, tokens, "name");
-                            current_type = "expression";
-                            j = k;
-                        }
-                        Tok::Lpar => {
-                            let k = find_end_of_expression(j + 2, tokens, "lpar");
-                            current_type = "expression";
-                            j = k;
-                        }
-                        Tok::Lsqb => {
-                            let k = find_end_of_expression(j + 2, tokens, "lsqb");
+                    match tok_at(tokens, j + 2) {
+                        Tok::Float { .. } | Tok::String { .. } | Tok::Regex { .. } | Tok::NoSubstitutionTemplate { .. }
+                        | Tok::TemplateHead { .. } | Tok::Name { .. } | Tok::Lpar | Tok::Lsqb
+                        | Tok::True | Tok::False | Tok::Null | Tok::This | Tok::Not | Tok::Minus | Tok::Plus | Tok::Tilde
+                        | Tok::TypeOf | Tok::Void | Tok::Delete | Tok::PlusPlus | Tok::MinusMinus => {
+                            let k = find_end_of_expression(j + 2, tokens);
                             current_type = "expression";
                             j = k;
                         }
@@ -60,7 +42,7 @@ pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Tok>) -> Result<
                             current_type = "rbrace";
                             j = k + 1;
                             if j < tokens.len() - 1 {
-                                if let Tok::Semi = tokens.get(j).unwrap() {
+                                if let Tok::Semi = tok_at(tokens, j) {
                                     return Ok(AssignmentType::ObjectExpression { end: j - 1 });
                                 }
                             }
@@ -86,7 +68,7 @@ pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Tok>) -> Result<
         let token = tokens.get(j as usize).unwrap();
 
         if current_type == "assignment" {
-            match token {
+            match &token.tok {
                 Tok::Name { name: _ } => {
                     j += 1;
                     current_type = "name";
@@ -96,7 +78,7 @@ pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Tok>) -> Result<
                 }
             }
         } else if current_type == "name" {
-            match token {
+            match &token.tok {
                 Tok::Equal => {
                     j += 1;
                     current_type = "equal";
@@ -106,9 +88,9 @@ pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Tok>) -> Result<
                 }
             }
         } else if current_type == "equal" {
-            match token {
+            match &token.tok {
                 Tok::Name { name: _ } => {
-                    let k = find_end_of_expression(j, tokens, "name");
+                    let k = find_end_of_expression(j, tokens);
                     j = k + 1;
                     current_type = "expression"
                 }
@@ -126,7 +108,7 @@ pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Tok>) -> Result<
                 }
             }
         } else if current_type == "string" {
-            match token {
+            match &token.tok {
                 Tok::Plus => {
                     j = j + 1;
                     current_type = "plus";
@@ -152,7 +134,7 @@ pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Tok>) -> Result<
                 }
             }
         } else if current_type == "expression" {
-            match token {
+            match &token.tok {
                 Tok::Lpar => {
                     let k = find_matching_paren(j, tokens);
                     j = k + 1;
@@ -165,7 +147,7 @@ pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Tok>) -> Result<
                 }
             }
         } else if current_type == "arrow_function" {
-            match token {
+            match &token.tok {
                 Tok::Semi | Tok::EndOfLine => {
                     return Ok(AssignmentType::ArrowFunction { end: j });
                 }
@@ -177,7 +159,7 @@ pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Tok>) -> Result<
                 }
             }
         } else if current_type == "function_assignment" {
-            match token {
+            match &token.tok {
                 Tok::Semi | Tok::EndOfLine => {
                     return Ok(AssignmentType::FunctionAssignment { end: j });
                 }
@@ -190,9 +172,9 @@ pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Tok>) -> Result<
             }
         }
         else if current_type == "object_assignment" {
-            match token {
+            match &token.tok {
                 Tok::EndOfLine => {
-                    if let Tok::Dot = tokens.get(j + 1 as usize).unwrap() {
+                    if let Tok::Dot = tok_at(tokens, j + 1 as usize) {
                         j = j + 2;
                         current_type = "dot";
                     } else {
@@ -208,9 +190,9 @@ pub(crate) fn find_end_of_assignment(start: usize, tokens: &Vec<Tok>) -> Result<
             }
         }
         else if current_type == "array_assignment" {
-            match token {
+            match &token.tok {
                 Tok::EndOfLine => {
-                    if let Tok::Dot = tokens.get(j + 1 as usize).unwrap() {
+                    if let Tok::Dot = tok_at(tokens, j + 1 as usize) {
                         j = j + 2;
                         current_type = "dot";
                     } else {
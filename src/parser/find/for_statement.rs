@@ -1,21 +1,22 @@
-use crate::lexer::js_token::Tok;
+use crate::lexer::js_token::{Tok, Token};
+use crate::parser::find::function::{unexpected};
 use crate::parser::parser::SyntaxError;
 use crate::parser::find::matching::{find_matching_paren, find_matching_brace};
 
-pub(crate) fn find_end_of_for(start: usize, tokens: &Vec<Tok>) -> Result<usize, SyntaxError> {
+pub(crate) fn find_end_of_for(start: usize, tokens: &Vec<Token>) -> Result<usize, SyntaxError> {
     let mut j = start + 1;
 
-    if let Some(Tok::Lpar) = tokens.get(j) {
+    if let Some(Tok::Lpar) = tokens.get(j).map(|t| &t.tok) {
         let k = find_matching_paren(j, tokens);
         j = k;
-        if let Some(Tok::Lbrace) = tokens.get(j + 1) {
+        if let Some(Tok::Lbrace) = tokens.get(j + 1).map(|t| &t.tok) {
             let k = find_matching_brace(j + 1, tokens);
             j = k;
         } else {
-            return Err(SyntaxError::UnexpectedToken {tok: tokens.get(j).unwrap().clone()})
+            return Err(unexpected(tokens, j))
         }
     } else {
-        return Err(SyntaxError::UnexpectedToken {tok: tokens.get(j).unwrap().clone()})
+        return Err(unexpected(tokens, j))
     }
     return Ok(j);
 }
\ No newline at end of file
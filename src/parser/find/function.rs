@@ -1,20 +1,33 @@
-use crate::lexer::js_token::Tok;
+use crate::lexer::js_token::{Tok, Token};
 use crate::parser::find::matching::{find_matching_paren, find_matching_brace, find_matching_sqb};
 use crate::parser::parser::{FunctionType, SyntaxError};
 
-pub(crate) fn find_arrow_function(start: usize, tokens: &Vec<Tok>) -> usize {
-    return match tokens.get(start).unwrap() {
+/// The token at `i`, running off the end reads as the end of a line.
+pub(crate) fn tok_at(tokens: &Vec<Token>, i: usize) -> &Tok {
+    tokens.get(i).map_or(&Tok::EndOfLine, |token| &token.tok)
+}
+
+/// The error for the token at `i`, which may be past the end of the input.
+pub(crate) fn unexpected(tokens: &Vec<Token>, i: usize) -> SyntaxError {
+    match tokens.get(i) {
+        Some(token) => SyntaxError::UnexpectedToken { tok: token.clone() },
+        None => SyntaxError::UnexpectedEndOfInput
+    }
+}
+
+pub(crate) fn find_arrow_function(start: usize, tokens: &Vec<Token>) -> usize {
+    return match tok_at(tokens, start) {
         Tok::Let | Tok::Const | Tok::Var => {
-            match tokens.get(start + 1).unwrap() {
+            match tok_at(tokens, start + 1) {
                 Tok::Name { name: _ } => {
-                    match tokens.get(start + 2).unwrap() {
+                    match tok_at(tokens, start + 2) {
                         Tok::Equal => {
-                            match tokens.get(start + 3).unwrap() {
+                            match tok_at(tokens, start + 3) {
                                 Tok::Lpar => {
                                     let j = find_matching_paren(start + 3, tokens);
-                                    match tokens.get(j + 1).unwrap() {
+                                    match tok_at(tokens, j + 1) {
                                         Tok::RdoubleArrow => {
-                                            match tokens.get(j + 2).unwrap() {
+                                            match tok_at(tokens, j + 2) {
                                                 Tok::Lbrace => {
                                                     let j = find_matching_brace(j + 2, tokens);
                                                     j
@@ -38,19 +51,19 @@ pub(crate) fn find_arrow_function(start: usize, tokens: &Vec<Tok>) -> usize {
     };
 }
 
-pub(crate) fn find_function_assignment(start: usize, tokens: &Vec<Tok>) -> usize {
-    return match tokens.get(start).unwrap() {
+pub(crate) fn find_function_assignment(start: usize, tokens: &Vec<Token>) -> usize {
+    return match tok_at(tokens, start) {
         Tok::Let | Tok::Const | Tok::Var => {
-            match tokens.get(start + 1).unwrap() {
+            match tok_at(tokens, start + 1) {
                 Tok::Name { name: _ } => {
-                    match tokens.get(start + 2).unwrap() {
+                    match tok_at(tokens, start + 2) {
                         Tok::Equal => {
-                            match tokens.get(start + 3).unwrap() {
+                            match tok_at(tokens, start + 3) {
                                 Tok::Function => {
-                                    match tokens.get(start + 4).unwrap() {
+                                    match tok_at(tokens, start + 4) {
                                         Tok::Lpar => {
                                             let j = find_matching_paren(start + 4, tokens);
-                                            match tokens.get(j + 1).unwrap() {
+                                            match tok_at(tokens, j + 1) {
                                                 Tok::Lbrace => {
                                                     let j = find_matching_brace(j + 1, tokens);
                                                     j
@@ -74,63 +87,63 @@ pub(crate) fn find_function_assignment(start: usize, tokens: &Vec<Tok>) -> usize
     };
 }
 
-pub(crate) fn find_end_of_function(start: usize, tokens: &Vec<Tok>) -> Result<FunctionType, SyntaxError> {
-    return match tokens.get(start).unwrap() {
+pub(crate) fn find_end_of_function(start: usize, tokens: &Vec<Token>) -> Result<FunctionType, SyntaxError> {
+    return match tok_at(tokens, start) {
         Tok::Function => {
-            match tokens.get(start + 1).unwrap() {
+            match tok_at(tokens, start + 1) {
                 Tok::Name { name: _ } => {
-                    match tokens.get(start + 2).unwrap() {
+                    match tok_at(tokens, start + 2) {
                         Tok::Lpar => {
                             let j = find_matching_paren(start + 2, tokens);
-                            match tokens.get(j + 1).unwrap() {
+                            match tok_at(tokens, j + 1) {
                                 Tok::Lbrace => {
                                     let k = find_matching_brace(j, tokens);
                                     Ok(FunctionType::FunctionDeclaration { end: k })
                                 }
                                 _ => {
-                                    Err(SyntaxError::UnexpectedToken { tok: tokens.get(j + 1).unwrap().clone() })
+                                    Err(unexpected(tokens, j + 1))
                                 }
                             }
                         }
                         _ => {
-                            Err(SyntaxError::UnexpectedToken { tok: tokens.get(start + 2).unwrap().clone() })
+                            Err(unexpected(tokens, start + 2))
                         }
                     }
                 }
                 Tok::Lpar => {
                     let j = find_matching_paren(start + 1, tokens);
-                    match tokens.get(j + 1).unwrap() {
+                    match tok_at(tokens, j + 1) {
                         Tok::Lbrace => {
                             let k = find_matching_brace(j, tokens);
                             Ok(FunctionType::FunctionExpression { end: k })
                         }
                         _ => {
-                            Err(SyntaxError::UnexpectedToken { tok: tokens.get(j + 1).unwrap().clone() })
+                            Err(unexpected(tokens, j + 1))
                         }
                     }
                 }
                 _ => {
-                    Err(SyntaxError::UnexpectedToken { tok: tokens.get(start + 1).unwrap().clone() })
+                    Err(unexpected(tokens, start + 1))
                 }
             }
         }
         _ => {
-            Err(SyntaxError::UnexpectedToken { tok: tokens.get(start).unwrap().clone() })
+            Err(unexpected(tokens, start))
         }
     }
 }
 
-pub(crate) fn find_object_assignment(start: usize, tokens: &Vec<Tok>) -> usize {
-    return match tokens.get(start).unwrap() {
+pub(crate) fn find_object_assignment(start: usize, tokens: &Vec<Token>) -> usize {
+    return match tok_at(tokens, start) {
         Tok::Let | Tok::Const | Tok::Var => {
-            match tokens.get(start + 1).unwrap() {
+            match tok_at(tokens, start + 1) {
                 Tok::Name { name: _ } => {
-                    match tokens.get(start + 2).unwrap() {
+                    match tok_at(tokens, start + 2) {
                         Tok::Equal => {
-                            match tokens.get(start + 3).unwrap() {
+                            match tok_at(tokens, start + 3) {
                                 Tok::Lbrace => {
                                     let j = find_matching_brace(start + 3, tokens);
-                                    match tokens.get(j).unwrap() {
+                                    match tok_at(tokens, j) {
                                         Tok::Rbrace => {
                                             j
                                         }
@@ -150,18 +163,18 @@ pub(crate) fn find_object_assignment(start: usize, tokens: &Vec<Tok>) -> usize {
     }
 }
 
-pub(crate) fn find_array_assignment(start: usize, tokens: &Vec<Tok>) -> usize {
-    return match tokens.get(start).unwrap() {
+pub(crate) fn find_array_assignment(start: usize, tokens: &Vec<Token>) -> usize {
+    return match tok_at(tokens, start) {
         Tok::Let | Tok::Const | Tok::Var => {
-            match tokens.get(start + 1).unwrap() {
+            match tok_at(tokens, start + 1) {
                 Tok::Name { name: _ } => {
-                    match tokens.get(start + 2).unwrap() {
+                    match tok_at(tokens, start + 2) {
                         Tok::Equal => {
-                            match tokens.get(start + 3).unwrap() {
+                            match tok_at(tokens, start + 3) {
                                 Tok::Lsqb => {
                                     let j = find_matching_sqb(start + 3, tokens);
-                                    match tokens.get(j).unwrap() {
-                                        Tok::Rsqb => {
+                                    match (tok_at(tokens, j), tok_at(tokens, j + 1)) {
+                                        (Tok::Rsqb, Tok::EndOfLine) | (Tok::Rsqb, Tok::Semi) | (Tok::Rsqb, Tok::Rbrace) => {
                                             j
                                         }
                                         _ => start
@@ -1,76 +1,36 @@
-use crate::lexer::js_token::Tok;
+use crate::lexer::js_token::{Tok, Token};
+use crate::parser::find::function::{tok_at, unexpected};
 use crate::parser::parser::SyntaxError;
-use crate::parser::find::matching::{find_matching_paren, find_end_of_line_or_lbrace, find_matching_brace};
-
-
-pub(crate) fn find_end_of_if(start: usize, tokens: &Vec<Tok>) -> Result<usize, SyntaxError> {
-    let mut j = start + 1;
-
-    if let Some(Tok::Lpar) = tokens.get(j) {
-        let k = find_matching_paren(j, tokens);
-        j = k;
-
-        loop {
-
-            let k = find_end_of_line_or_lbrace(j, tokens);
-            j = k;
-
-            if j == tokens.len() {
-                j = tokens.len() - 1;
-                break;
-            }
-
-            if j == tokens.len() - 1 {
-                break;
-            }
-
-
-            match tokens.get(k).unwrap() {
-                Tok::EndOfLine => {
-                    match tokens.get(k + 1).unwrap() {
-                        Tok::Else => {
-
-                        }
-                        _ => {
-                            j = k;
-                            let k = find_end_of_line_or_lbrace(j + 1, tokens);
-                            j = k;
-                            continue;
-                        }
-                    }
-                }
-                Tok::Lbrace => {
-                    let k = find_matching_brace(j, tokens);
-                    j = k;
-                }
-                _ => {
-                    break;
-                }
-            }
-
-
-            if j == tokens.len() {
-                j = tokens.len() - 1;
-                break;
-            }
-
-            if j == tokens.len() - 1 {
-                break;
-            }
-
-            match tokens.get(j + 1).unwrap() {
-                Tok::Else => {
-                    j = j + 1;
-                    continue;
-                }
-                _ => {
-                    break;
-                }
-            }
+use crate::parser::find::matching::find_matching_paren;
+use crate::parser::find::while_statement::find_end_of_body;
+
+/// Index of the `else` following the branch that ends at `end`, if there is one.
+pub(crate) fn find_else(end: usize, tokens: &Vec<Token>) -> Option<usize> {
+    let mut j = end + 1;
+    while let Tok::EndOfLine | Tok::Semi = tok_at(tokens, j) {
+        if j >= tokens.len() {
+            return None;
         }
-    } else {
-        return Err(SyntaxError::UnexpectedToken {tok: tokens.get(j).unwrap().clone()});
+        j += 1;
+    }
+    match tok_at(tokens, j) {
+        Tok::Else => Some(j),
+        _ => None
+    }
+}
+
+/// Index of the last token of the if statement at `start`. Each branch is a block or exactly
+/// one statement, an `else if` chain is taken in through the alternate.
+pub(crate) fn find_end_of_if(start: usize, tokens: &Vec<Token>) -> Result<usize, SyntaxError> {
+    let j = start + 1;
+    if tok_at(tokens, j) != &Tok::Lpar {
+        return Err(unexpected(tokens, j));
     }
 
-    return Ok(j);
-}
\ No newline at end of file
+    let rpar = find_matching_paren(j, tokens);
+    let end = find_end_of_body(rpar + 1, tokens)?;
+    match find_else(end, tokens) {
+        Some(else_i) => find_end_of_body(else_i + 1, tokens),
+        None => Ok(end)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::lexer::js_token::{Tok, Token};
+use crate::parser::parser::SyntaxError;
+use crate::parser::find::for_statement::find_end_of_for;
+use crate::parser::find::while_statement::{find_end_of_body, find_end_of_while, find_end_of_do_while};
+
+/// Index of the last token of `label: statement` starting at `start`.
+pub(crate) fn find_end_of_labelled(start: usize, tokens: &Vec<Token>) -> Result<usize, SyntaxError> {
+    let mut j = start + 2;
+    while let Some(Tok::EndOfLine) = tokens.get(j).map(|t| &t.tok) {
+        j += 1;
+    }
+
+    match tokens.get(j).map(|t| &t.tok) {
+        Some(Tok::For) => find_end_of_for(j, tokens),
+        Some(Tok::While) => find_end_of_while(j, tokens),
+        Some(Tok::Do) => find_end_of_do_while(j, tokens),
+        _ => find_end_of_body(j, tokens)
+    }
+}
@@ -1,7 +1,7 @@
-use crate::lexer::js_token::Tok;
+use crate::lexer::js_token::{Tok, Token};
 
 
-pub(crate) fn find_matching_paren(start: usize, tokens: &Vec<Tok>) -> usize {
+pub(crate) fn find_matching_paren(start: usize, tokens: &Vec<Token>) -> usize {
     let mut j = start;
     let mut lpar = 0;
     while j < tokens.len() {
@@ -19,7 +19,7 @@ pub(crate) fn find_matching_paren(start: usize, tokens: &Vec<Tok>) -> usize {
     return j;
 }
 
-pub(crate) fn find_matching_brace(start: usize, tokens: &Vec<Tok>) -> usize {
+pub(crate) fn find_matching_brace(start: usize, tokens: &Vec<Token>) -> usize {
     let mut j = start;
     let mut lbrace = 0;
 
@@ -38,7 +38,7 @@ pub(crate) fn find_matching_brace(start: usize, tokens: &Vec<Tok>) -> usize {
     return j;
 }
 
-pub(crate) fn find_matching_sqb(start: usize, tokens: &Vec<Tok>) -> usize {
+pub(crate) fn find_matching_sqb(start: usize, tokens: &Vec<Token>) -> usize {
     let mut j = start;
     let mut lsqb = 0;
 
@@ -57,15 +57,28 @@ pub(crate) fn find_matching_sqb(start: usize, tokens: &Vec<Tok>) -> usize {
     return j;
 }
 
-pub(crate) fn find_end_of_line_or_lbrace(start: usize, tokens: &Vec<Tok>) -> usize {
+/// Index of the `TemplateTail` closing the template that starts at `start`.
+pub(crate) fn find_matching_template(start: usize, tokens: &Vec<Token>) -> usize {
     let mut j = start;
+    let mut depth = 0;
+
     while j < tokens.len() {
-        let token = tokens.get(j as usize).unwrap();
-        if token.eq(&Tok::EndOfLine) {
-            return j;
-        }
-        if token.eq(&Tok::Lbrace) {
-            return j;
+        match &tokens.get(j as usize).unwrap().tok {
+            Tok::NoSubstitutionTemplate { .. } => {
+                if depth == 0 {
+                    break;
+                }
+            }
+            Tok::TemplateHead { .. } => {
+                depth += 1;
+            }
+            Tok::TemplateTail { .. } => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            _ => {}
         }
         j += 1;
     }
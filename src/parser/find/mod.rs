@@ -3,4 +3,8 @@ pub(crate) mod assignment;
 pub(crate) mod matching;
 pub(crate) mod expression;
 pub(crate) mod for_statement;
-pub(crate) mod if_statement;
\ No newline at end of file
+pub(crate) mod if_statement;
+pub(crate) mod while_statement;
+pub(crate) mod label;
+pub(crate) mod switch_statement;
+pub(crate) mod try_statement;
\ No newline at end of file
@@ -0,0 +1,22 @@
+use crate::lexer::js_token::{Tok, Token};
+use crate::parser::parser::SyntaxError;
+use crate::parser::find::matching::{find_matching_paren, find_matching_brace};
+
+/// Index of the `}` closing the switch statement at `start`.
+pub(crate) fn find_end_of_switch(start: usize, tokens: &Vec<Token>) -> Result<usize, SyntaxError> {
+    match tokens.get(start + 1).map(|t| &t.tok) {
+        Some(Tok::Lpar) => {}
+        Some(_) => return Err(SyntaxError::UnexpectedToken {tok: tokens.get(start + 1).unwrap().clone()}),
+        None => return Err(SyntaxError::UnexpectedEndOfInput)
+    }
+    let mut j = find_matching_paren(start + 1, tokens) + 1;
+    while let Some(Tok::EndOfLine) = tokens.get(j).map(|t| &t.tok) {
+        j += 1;
+    }
+
+    match tokens.get(j).map(|t| &t.tok) {
+        Some(Tok::Lbrace) => Ok(find_matching_brace(j, tokens)),
+        Some(_) => Err(SyntaxError::UnexpectedToken {tok: tokens.get(j).unwrap().clone()}),
+        None => Err(SyntaxError::UnexpectedEndOfInput)
+    }
+}
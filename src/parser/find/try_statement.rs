@@ -0,0 +1,50 @@
+use crate::lexer::js_token::{Tok, Token};
+use crate::parser::parser::SyntaxError;
+use crate::parser::find::matching::{find_matching_paren, find_matching_brace};
+
+fn skip_line_ends(mut j: usize, tokens: &Vec<Token>) -> usize {
+    while let Some(Tok::EndOfLine) = tokens.get(j).map(|t| &t.tok) {
+        j += 1;
+    }
+    j
+}
+
+/// Index of the `}` closing the block starting at `j`, after any line breaks.
+fn find_end_of_block(j: usize, tokens: &Vec<Token>) -> Result<usize, SyntaxError> {
+    let j = skip_line_ends(j, tokens);
+    match tokens.get(j).map(|t| &t.tok) {
+        Some(Tok::Lbrace) => Ok(find_matching_brace(j, tokens)),
+        Some(_) => Err(SyntaxError::UnexpectedToken {tok: tokens.get(j).unwrap().clone()}),
+        None => Err(SyntaxError::UnexpectedEndOfInput)
+    }
+}
+
+/// Index of the last `}` of the try statement at `start`, the end of its finally block or of
+/// its catch block when there is no finally.
+pub(crate) fn find_end_of_try(start: usize, tokens: &Vec<Token>) -> Result<usize, SyntaxError> {
+    let mut end = find_end_of_block(start + 1, tokens)?;
+    let mut handled = false;
+
+    let mut j = skip_line_ends(end + 1, tokens);
+    if let Some(Tok::Catch) = tokens.get(j).map(|t| &t.tok) {
+        j = skip_line_ends(j + 1, tokens);
+        if let Some(Tok::Lpar) = tokens.get(j).map(|t| &t.tok) {
+            j = find_matching_paren(j, tokens) + 1;
+        }
+        end = find_end_of_block(j, tokens)?;
+        handled = true;
+        j = skip_line_ends(end + 1, tokens);
+    }
+    if let Some(Tok::Finally) = tokens.get(j).map(|t| &t.tok) {
+        end = find_end_of_block(j + 1, tokens)?;
+        handled = true;
+    }
+
+    if !handled {
+        return match tokens.get(j) {
+            Some(token) => Err(SyntaxError::UnexpectedToken {tok: token.clone()}),
+            None => Err(SyntaxError::UnexpectedEndOfInput)
+        };
+    }
+    Ok(end)
+}
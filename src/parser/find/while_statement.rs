@@ -0,0 +1,61 @@
+use crate::lexer::js_token::{Tok, Token};
+use crate::parser::parser::SyntaxError;
+use crate::parser::find::matching::{find_matching_paren, find_matching_brace};
+use crate::parser::find::if_statement::find_end_of_if;
+
+/// Index of the last token of a loop body starting at `start`, the closing brace of a block or
+/// the end of a single statement.
+pub(crate) fn find_end_of_body(start: usize, tokens: &Vec<Token>) -> Result<usize, SyntaxError> {
+    let start = start + tokens.iter().skip(start).take_while(|t| t.tok == Tok::EndOfLine).count();
+    match tokens.get(start).map(|t| &t.tok) {
+        None => return Err(SyntaxError::UnexpectedEndOfInput),
+        Some(Tok::Lbrace) => return Ok(find_matching_brace(start, tokens)),
+        Some(Tok::If) => return find_end_of_if(start, tokens),
+        Some(_) => {}
+    }
+
+    let mut depth = 0;
+    let mut j = start;
+    while j < tokens.len() {
+        match &tokens.get(j).unwrap().tok {
+            Tok::Lpar | Tok::Lsqb | Tok::Lbrace => depth += 1,
+            Tok::Rpar | Tok::Rsqb | Tok::Rbrace => depth -= 1,
+            Tok::Semi | Tok::EndOfLine if depth == 0 => return Ok(j),
+            _ => {}
+        }
+        j += 1;
+    }
+    return Ok(tokens.len() - 1);
+}
+
+pub(crate) fn find_end_of_while(start: usize, tokens: &Vec<Token>) -> Result<usize, SyntaxError> {
+    let j = start + 1;
+
+    if let Some(Tok::Lpar) = tokens.get(j).map(|t| &t.tok) {
+        let k = find_matching_paren(j, tokens);
+        return find_end_of_body(k + 1, tokens);
+    }
+    match tokens.get(j) {
+        Some(token) => Err(SyntaxError::UnexpectedToken {tok: token.clone()}),
+        None => Err(SyntaxError::UnexpectedEndOfInput)
+    }
+}
+
+/// Index of the `)` closing the condition of the `do` statement at `start`.
+pub(crate) fn find_end_of_do_while(start: usize, tokens: &Vec<Token>) -> Result<usize, SyntaxError> {
+    let mut j = find_end_of_body(start + 1, tokens)? + 1;
+    while let Some(Tok::EndOfLine) = tokens.get(j).map(|t| &t.tok) {
+        j += 1;
+    }
+
+    match tokens.get(j).map(|t| &t.tok) {
+        Some(Tok::While) => {}
+        Some(_) => return Err(SyntaxError::UnexpectedToken {tok: tokens.get(j).unwrap().clone()}),
+        None => return Err(SyntaxError::UnexpectedEndOfInput)
+    }
+    match tokens.get(j + 1).map(|t| &t.tok) {
+        Some(Tok::Lpar) => Ok(find_matching_paren(j + 1, tokens)),
+        Some(_) => Err(SyntaxError::UnexpectedToken {tok: tokens.get(j + 1).unwrap().clone()}),
+        None => Err(SyntaxError::UnexpectedEndOfInput)
+    }
+}
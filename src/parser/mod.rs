@@ -1,6 +1,9 @@
 pub(crate) mod symbols;
+pub(crate) mod property_map;
 pub(crate) mod parser;
 mod tests;
-pub(crate) mod combine;
 mod find;
-mod create;
\ No newline at end of file
+mod create;
+pub(crate) mod asi;
+pub(crate) mod statements;
+pub(crate) mod pratt;
\ No newline at end of file
@@ -1,4 +1,5 @@
-use crate::lexer::js_token::Tok;
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::span::Span;
 use crate::parser::symbols::{Expression, JSItem, Statement};
 use crate::parser::find::assignment::{find_end_of_assignment};
 use crate::parser::find::matching::{find_matching_brace};
@@ -11,17 +12,40 @@ use crate::parser::find::function::find_end_of_function;
 use crate::parser::create::block_statement::create_object_expression;
 use crate::parser::find::if_statement::find_end_of_if;
 use crate::parser::create::if_statement::create_if_statement;
+use crate::parser::find::while_statement::{find_end_of_while, find_end_of_do_while};
+use crate::parser::create::while_statement::{create_while_statement, create_do_while_statement};
+use crate::parser::find::label::find_end_of_labelled;
+use crate::parser::create::label::{create_labelled_statement, create_jump_statement};
+use crate::parser::find::switch_statement::find_end_of_switch;
+use crate::parser::create::switch_statement::create_switch_statement;
+use crate::parser::find::try_statement::find_end_of_try;
+use crate::parser::create::try_statement::{create_try_statement, create_throw_statement};
+use crate::parser::create::template::create_template_literal;
+use crate::parser::asi::{insert_semicolons, SemicolonInserter};
+use crate::parser::statements::Statements;
+use std::fmt;
 
 pub(crate) struct Parser {}
 
 #[derive(Debug, PartialEq, Clone)]
 pub(crate) enum SyntaxError {
     UnexpectedToken {
-        tok: Tok
+        tok: Token
     },
     UnexpectedIdentifier {
         name: String
     },
+    UnexpectedEndOfInput,
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyntaxError::UnexpectedToken { tok } => write!(f, "{}: SyntaxError: Unexpected token {:?}", tok.span, tok.tok),
+            SyntaxError::UnexpectedIdentifier { name } => write!(f, "SyntaxError: Unexpected identifier '{}'", name),
+            SyntaxError::UnexpectedEndOfInput => write!(f, "SyntaxError: Unexpected end of input")
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -59,33 +83,91 @@ pub(crate) enum FunctionType {
 
 
 impl Parser {
-    pub fn parse(&mut self, mut tokens: Vec<Tok>) -> Vec<JSItem> {
-        if tokens.get(0).unwrap().eq(&Tok::EndOfLine) {
+    /// Parses a script while its tokens are pulled, one top level statement at a time, so only
+    /// the tokens of the statement being parsed are held.
+    pub fn parse_statements<I: Iterator<Item = Token>>(&mut self, tokens: I) -> impl Iterator<Item = Result<Vec<JSItem>, SyntaxError>> {
+        Statements::new(SemicolonInserter::new(tokens)).map(|statement| Parser::new().parse(statement))
+    }
+
+    /// `parse_statements` collected into one list, stopping at the first syntax error.
+    #[allow(dead_code)]
+    pub fn parse_tokens<I: Iterator<Item = Token>>(&mut self, tokens: I) -> Result<Vec<JSItem>, SyntaxError> {
+        let mut js_items = vec![];
+        for items in self.parse_statements(tokens) {
+            js_items.extend(items?);
+        }
+        Ok(js_items)
+    }
+
+    pub fn parse(&mut self, tokens: Vec<Token>) -> Result<Vec<JSItem>, SyntaxError> {
+        let mut tokens = insert_semicolons(tokens);
+        if tokens.first().map_or(false, |t| t.eq(&Tok::EndOfLine)) {
             tokens.remove(0);
         }
+        if tokens.is_empty() {
+            return Ok(vec![]);
+        }
 
         if tokens.len() == 1 {
             let token = tokens.get(0).unwrap();
-            match token {
+            let span = token.span.clone();
+            match &token.tok {
                 Tok::Null => {
-                    return vec![JSItem::Ex {
-                        expression: Box::new(Expression::Null)
-                    }]
+                    return Ok(vec![JSItem::Ex {
+                        expression: Box::new(Expression::Null { span: span.clone() }),
+                        span
+                    }]);
+                }
+                Tok::True => {
+                    return Ok(vec![JSItem::Ex {
+                        expression: Box::new(Expression::True { span: span.clone() }),
+                        span
+                    }]);
+                }
+                Tok::False => {
+                    return Ok(vec![JSItem::Ex {
+                        expression: Box::new(Expression::False { span: span.clone() }),
+                        span
+                    }]);
+                }
+                Tok::Break | Tok::Continue => {
+                    return Ok(vec![create_jump_statement(tokens)]);
                 }
                 Tok::Float { mut value } => {
-                    return vec![JSItem::Ex {
-                        expression: Box::new(Expression::Number { value })
-                    }];
+                    return Ok(vec![JSItem::Ex {
+                        expression: Box::new(Expression::Number { value, span: span.clone() }),
+                        span
+                    }]);
                 }
                 Tok::Name { name } => {
-                    return vec![JSItem::Ex {
-                        expression: Box::new(Expression::Identifier { name: name.clone() })
-                    }];
+                    return Ok(vec![JSItem::Ex {
+                        expression: Box::new(Expression::Identifier { name: name.clone(), span: span.clone() }),
+                        span
+                    }]);
+                }
+                Tok::This => {
+                    return Ok(vec![JSItem::Ex {
+                        expression: Box::new(Expression::Identifier { name: "this".to_string(), span: span.clone() }),
+                        span
+                    }]);
                 }
                 Tok::String {value} => {
-                    return vec![JSItem::Ex {
-                        expression: Box::new(Expression::String {value: value.clone()})
-                    }];
+                    return Ok(vec![JSItem::Ex {
+                        expression: Box::new(Expression::String {value: value.clone(), span: span.clone()}),
+                        span
+                    }]);
+                }
+                Tok::Regex { pattern, flags } => {
+                    return Ok(vec![JSItem::Ex {
+                        expression: Box::new(Expression::RegExp { pattern: pattern.clone(), flags: flags.clone(), span: span.clone() }),
+                        span
+                    }]);
+                }
+                Tok::NoSubstitutionTemplate { .. } => {
+                    return Ok(vec![JSItem::Ex {
+                        expression: Box::new(create_template_literal(tokens.clone())?),
+                        span
+                    }]);
                 }
                 _ => {}
             }
@@ -95,17 +177,17 @@ impl Parser {
         let mut i = 0;
         while i < tokens.len() - 1 {
             let token = tokens.get(i).unwrap();
-            match token {
+            match &token.tok {
                 Tok::If => {
                     let result = find_end_of_if(i, &tokens);
                     match result {
                         Ok(j) => {
                             let t = tokens[i..=j].to_vec();
-                            let f = create_if_statement(t);
+                            let f = create_if_statement(t)?;
                             js_items.push(f);
                             i = j;
                         }
-                        Err(_) => {}
+                        Err(e) => return Err(e)
                     }
                 }
                 Tok::For => {
@@ -113,54 +195,139 @@ impl Parser {
                     match result {
                         Ok(j) => {
                             let t = tokens[i..=j].to_vec();
-                            let f = create_for_statement(t);
+                            let f = create_for_statement(t)?;
+                            js_items.push(f);
+                            i = j;
+                        }
+                        Err(e) => return Err(e)
+                    }
+                }
+                Tok::While => {
+                    let result = find_end_of_while(i, &tokens);
+                    match result {
+                        Ok(j) => {
+                            let t = tokens[i..=j].to_vec();
+                            let f = create_while_statement(t)?;
+                            js_items.push(f);
+                            i = j;
+                        }
+                        Err(e) => return Err(e)
+                    }
+                }
+                Tok::Do => {
+                    let result = find_end_of_do_while(i, &tokens);
+                    match result {
+                        Ok(j) => {
+                            let t = tokens[i..=j].to_vec();
+                            let f = create_do_while_statement(t)?;
+                            js_items.push(f);
+                            i = j;
+                        }
+                        Err(e) => return Err(e)
+                    }
+                }
+                Tok::Switch => {
+                    let result = find_end_of_switch(i, &tokens);
+                    match result {
+                        Ok(j) => {
+                            let t = tokens[i..=j].to_vec();
+                            let f = create_switch_statement(t)?;
                             js_items.push(f);
                             i = j;
                         }
-                        Err(_) => {}
+                        Err(e) => return Err(e)
                     }
                 }
-                Tok::Float { value: _ } => {
-                    let j = find_end_of_expression(i, &tokens, "float");
+                Tok::Try => {
+                    let result = find_end_of_try(i, &tokens);
+                    match result {
+                        Ok(j) => {
+                            let t = tokens[i..=j].to_vec();
+                            let f = create_try_statement(t)?;
+                            js_items.push(f);
+                            i = j;
+                        }
+                        Err(e) => return Err(e)
+                    }
+                }
+                Tok::Throw => {
+                    let j = find_end_of_expression(i + 1, &tokens);
                     let t = tokens[i..=j].to_vec();
-                    let ex = create_expression(t);
+                    let f = create_throw_statement(t)?;
+                    js_items.push(f);
+                    i = j;
+                }
+                Tok::String { .. } | Tok::Regex { .. } | Tok::True | Tok::False | Tok::Null | Tok::This
+                | Tok::Not | Tok::Minus | Tok::Plus | Tok::Tilde | Tok::TypeOf | Tok::Void | Tok::Delete
+                | Tok::PlusPlus | Tok::MinusMinus => {
+                    let j = find_end_of_expression(i, &tokens);
+                    let t = tokens[i..=j].to_vec();
+                    let ex = create_expression(t)?;
+                    js_items.push(ex);
+                    i = j;
+                }
+                Tok::Float { value: _ } | Tok::Lsqb => {
+                    let j = find_end_of_expression(i, &tokens);
+                    let t = tokens[i..=j].to_vec();
+                    let ex = create_expression(t)?;
                     js_items.push(ex);
                     i = j;
                 }
                 Tok::Let | Tok::Const => {
                     //assignment
-                    let assignment_type = find_end_of_assignment(i, &tokens).unwrap();
+                    let assignment_type = find_end_of_assignment(i, &tokens)?;
                     match assignment_type {
                         AssignmentType::Unknown { end: _ } => {}
                         AssignmentType::ArrowFunction { end } => {
                             let t = tokens[i..=end].to_vec();
-                            let assign = create_arrow_function(t);
+                            let assign = create_arrow_function(t)?;
                             js_items.push(assign);
                             i = end;
                         }
                         AssignmentType::Expression { end } => {
                             let t = tokens[i..=end].to_vec();
-                            let ex = create_assignment_expression(t).unwrap();
+                            let ex = create_assignment_expression(t)?;
                             js_items.push(ex);
                             i = end;
                         }
                         AssignmentType::FunctionAssignment { end } => {
                             let t = tokens[i..=end].to_vec();
-                            let assign = create_function_assignment(t);
+                            let assign = create_function_assignment(t)?;
                             js_items.push(assign);
                             i = end;
                         }
                         AssignmentType::ArrayAssignment {end} | AssignmentType::ObjectExpression {end} => {
                             let t = tokens[i..=end].to_vec();
-                            let exr = create_assignment_expression(t).unwrap();
+                            let exr = create_assignment_expression(t)?;
                             js_items.push(exr);
                             i = end;
                         }
                     }
                 }
+                Tok::Name { .. } if tokens.get(i + 1).map_or(false, |t| t.tok == Tok::Colon) => {
+                    let result = find_end_of_labelled(i, &tokens);
+                    match result {
+                        Ok(j) => {
+                            let t = tokens[i..=j].to_vec();
+                            let f = create_labelled_statement(t)?;
+                            js_items.push(f);
+                            i = j;
+                        }
+                        Err(e) => return Err(e)
+                    }
+                }
+                Tok::Break | Tok::Continue => {
+                    let j = match tokens.get(i + 1).map(|t| &t.tok) {
+                        Some(Tok::Name { .. }) => i + 1,
+                        _ => i
+                    };
+                    let t = tokens[i..=j].to_vec();
+                    js_items.push(create_jump_statement(t));
+                    i = j + 1;
+                }
                 Tok::Name { name: _ } => {
                     //expression
-                    let j = find_end_of_expression(i, &tokens, "name");
+                    let j = find_end_of_expression(i, &tokens);
                     let t = tokens[i..=j].to_vec();
                     let exr = create_assignment_expression(t.clone());
                     match exr {
@@ -168,39 +335,46 @@ impl Parser {
                             js_items.push(ex);
                         }
                         Err(..) => {
-                            let ex = create_expression(t);
+                            let ex = create_expression(t)?;
                             js_items.push(ex);
                         }
                     }
                     i = j;
                 }
+                Tok::NoSubstitutionTemplate { .. } | Tok::TemplateHead { .. } => {
+                    let j = find_end_of_expression(i, &tokens);
+                    let t = tokens[i..=j].to_vec();
+                    let ex = create_expression(t)?;
+                    js_items.push(ex);
+                    i = j;
+                }
                 Tok::Lpar => {
-                    let j = find_end_of_expression(i, &tokens, "lpar");
+                    let j = find_end_of_expression(i, &tokens);
                     let t = tokens[i..=j].to_vec();
-                    let ex = create_expression(t);
+                    let ex = create_expression(t)?;
                     js_items.push(ex);
                     i = j;
                 }
                 Tok::Lbrace => {
                     let j = find_matching_brace(i, &tokens);
                     let t = tokens[i..=j].to_vec();
-                    let st = create_object_expression(t).unwrap();
+                    let st = create_object_expression(t)?;
                     js_items.push(st);
                     i = j;
                 }
                 Tok::Function => {
                     //function
-                    let function_type = find_end_of_function(i, &tokens).unwrap();
+                    let function_type = find_end_of_function(i, &tokens)?;
                     match function_type {
                         FunctionType::FunctionDeclaration { end } => {
                             let t = tokens[i..=end].to_vec();
-                            let func = create_function(t);
+                            let func = create_function(t)?;
                             js_items.push(func);
                             i = end;
                         }
                         FunctionType::FunctionExpression {end} => {
                             let t = tokens[i..=end].to_vec();
-                            let func = create_function_expression(t);
+                            let func = create_function_expression(t)?;
                             js_items.push(func);
                             i = end;
                         }
@@ -208,18 +382,27 @@ impl Parser {
                 }
                 Tok::Return => {
                     let t = tokens[i+1..=tokens.len() - 1].to_vec();
+                    let span = Span::of(&tokens[i..]);
+                    // the semicolon ASI put after `return` ends it, what follows is a new statement
+                    let has_value = match t.first().map(|token| &token.tok) {
+                        None | Some(Tok::Semi) | Some(Tok::EndOfLine) | Some(Tok::Rbrace) => false,
+                        _ => true
+                    };
                     let mut p = Parser::new();
-                    let mut items = p.parse(t);
-                    let return_item = items.remove(0);
+                    let mut items = p.parse(t)?;
+                    let value = if has_value && !items.is_empty() {
+                        Some(Box::new(items.remove(0)))
+                    } else {
+                        None
+                    };
                     js_items.push(JSItem::St {
-                        statement: Box::from(Statement::Return{
-                            value: Box::new(return_item)
-                        })
+                        statement: Box::from(Statement::Return { value }),
+                        span
                     });
                     for item in items {
                         js_items.push(item);
                     }
-                    return js_items;
+                    return Ok(js_items);
                 }
                 _ => {
                     i += 1;
@@ -227,7 +410,23 @@ impl Parser {
             }
         }
 
-        return js_items;
+        // the loop stops before the last token, which is only a whole statement on its own for
+        // a bare `break` or `continue`
+        if let Some(token) = tokens.get(i) {
+            if i == tokens.len() - 1 && (token.tok == Tok::Break || token.tok == Tok::Continue) {
+                js_items.push(create_jump_statement(vec![token.clone()]));
+            }
+        }
+
+        return Ok(js_items);
+    }
+
+    /// Parses tokens that should hold a single expression, like a condition or an argument.
+    pub(crate) fn parse_one(&mut self, tokens: Vec<Token>) -> Result<JSItem, SyntaxError> {
+        match self.parse(tokens)?.pop() {
+            Some(item) => Ok(item),
+            None => Err(SyntaxError::UnexpectedEndOfInput)
+        }
     }
 
     pub fn new() -> Parser {
@@ -0,0 +1,410 @@
+use crate::parser::property_map::PropertyMap;
+use crate::lexer::js_token::{Tok, Token};
+use crate::lexer::span::Span;
+use crate::parser::symbols::{Expression, JSItem, Operator, UnaryOperator};
+use crate::parser::parser::{Parser, SyntaxError};
+use crate::parser::create::comma_separate_tokens;
+use crate::parser::create::block_statement::create_object_expression;
+use crate::parser::create::function::create_function_expression;
+use crate::parser::create::template::create_template_literal;
+use crate::parser::find::matching::{find_matching_paren, find_matching_brace, find_matching_sqb, find_matching_template};
+
+// Binding powers, loosest first. They go up in twos so a left associative operator can parse
+// its right hand side one tighter than itself.
+const ASSIGNMENT: u8 = 2;
+const CONDITIONAL: u8 = 4;
+const LOGICAL_OR: u8 = 6;
+const LOGICAL_AND: u8 = 8;
+const BITWISE_OR: u8 = 10;
+const BITWISE_XOR: u8 = 12;
+const BITWISE_AND: u8 = 14;
+const EQUALITY: u8 = 16;
+const RELATIONAL: u8 = 18;
+const SHIFT: u8 = 20;
+const ADDITIVE: u8 = 22;
+const MULTIPLICATIVE: u8 = 24;
+const EXPONENT: u8 = 26;
+const UNARY: u8 = 28;
+
+/// The binary operator a token stands for, with how tightly it binds.
+fn infix_operator(tok: &Tok) -> Option<(Operator, u8)> {
+    let operator = match tok {
+        Tok::VbarVbar => (Operator::Or, LOGICAL_OR),
+        // ?? sits with || but the two can't be mixed without parentheses
+        Tok::QuestionQuestion => (Operator::Nullish, LOGICAL_OR),
+        Tok::AmpAmp => (Operator::And, LOGICAL_AND),
+        Tok::Vbar => (Operator::BitOr, BITWISE_OR),
+        Tok::Circumflex => (Operator::BitXor, BITWISE_XOR),
+        Tok::Amper => (Operator::BitAnd, BITWISE_AND),
+        Tok::EqEqual => (Operator::EqEq, EQUALITY),
+        Tok::EqEqEual => (Operator::EqEqEq, EQUALITY),
+        Tok::NotEqual => (Operator::NotEq, EQUALITY),
+        Tok::NotDoubleEqual => (Operator::NotEqEq, EQUALITY),
+        Tok::Less => (Operator::Less, RELATIONAL),
+        Tok::Greater => (Operator::Greater, RELATIONAL),
+        Tok::LessEqual => (Operator::LessEqual, RELATIONAL),
+        Tok::GreaterEqual => (Operator::GreaterEqual, RELATIONAL),
+        Tok::In => (Operator::In, RELATIONAL),
+        Tok::InstanceOf => (Operator::InstanceOf, RELATIONAL),
+        Tok::LeftShift => (Operator::LShift, SHIFT),
+        Tok::RightShift => (Operator::RShift, SHIFT),
+        Tok::RightShiftUnsigned => (Operator::URShift, SHIFT),
+        Tok::Plus => (Operator::Add, ADDITIVE),
+        Tok::Minus => (Operator::Sub, ADDITIVE),
+        Tok::Star => (Operator::Mult, MULTIPLICATIVE),
+        Tok::Bslash => (Operator::Div, MULTIPLICATIVE),
+        Tok::Percent => (Operator::Mod, MULTIPLICATIVE),
+        Tok::StarStar => (Operator::Exp, EXPONENT),
+        _ => return None
+    };
+    Some(operator)
+}
+
+/// The operator an assignment token combines with, `Operator::None` for a plain `=`.
+fn assignment_operator(tok: &Tok) -> Option<Operator> {
+    let operator = match tok {
+        Tok::Equal => Operator::None,
+        Tok::AmpAmpEqual => Operator::And,
+        Tok::VbarVbarEqual => Operator::Or,
+        Tok::QuestionQuestionEqual => Operator::Nullish,
+        Tok::PlusEqual => Operator::Add,
+        Tok::MinusEqual => Operator::Sub,
+        Tok::StarEqual => Operator::Mult,
+        Tok::BslashEqual => Operator::Div,
+        Tok::PercentEqual => Operator::Mod,
+        Tok::StarStarEqual => Operator::Exp,
+        Tok::LeftShiftEqual => Operator::LShift,
+        Tok::RightShiftEqual => Operator::RShift,
+        Tok::RightShiftUnsignedEqual => Operator::URShift,
+        Tok::AmperEqual => Operator::BitAnd,
+        Tok::VbarEqual => Operator::BitOr,
+        Tok::CircumflexEqual => Operator::BitXor,
+        _ => return None
+    };
+    Some(operator)
+}
+
+/// The operator `++` or `--` adds or takes away one with.
+fn update_operator(tok: &Tok) -> Option<Operator> {
+    match tok {
+        Tok::PlusPlus => Some(Operator::Add),
+        Tok::MinusMinus => Some(Operator::Sub),
+        _ => None
+    }
+}
+
+/// Whether `ex` can be assigned to, the left of `=` or the operand of `++`.
+fn is_assignment_target(ex: &Expression) -> bool {
+    match ex {
+        Expression::Identifier { .. } | Expression::MemberExpression { .. }
+        | Expression::ComputedMemberExpression { .. } => true,
+        _ => false
+    }
+}
+
+/// The prefix operator a token stands for.
+fn prefix_operator(tok: &Tok) -> Option<UnaryOperator> {
+    let operator = match tok {
+        Tok::Not => UnaryOperator::Not,
+        Tok::Minus => UnaryOperator::Minus,
+        Tok::Plus => UnaryOperator::Plus,
+        Tok::Tilde => UnaryOperator::BitNot,
+        Tok::TypeOf => UnaryOperator::TypeOf,
+        Tok::Void => UnaryOperator::Void,
+        Tok::Delete => UnaryOperator::Delete,
+        _ => return None
+    };
+    Some(operator)
+}
+
+fn is_logical_or_and(ex: &Expression) -> bool {
+    match ex {
+        Expression::Binop { op: Operator::Or, .. } | Expression::Binop { op: Operator::And, .. } => true,
+        _ => false
+    }
+}
+
+/// Parses the comma separated items between a pair of brackets, each one as its own statement.
+fn parse_items(tokens: &[Token]) -> Result<Vec<JSItem>, SyntaxError> {
+    let mut tokens = tokens.to_vec();
+    tokens.reverse();
+    comma_separate_tokens(tokens).into_iter()
+        .map(|item| Parser::new().parse_one(item))
+        .collect()
+}
+
+/// Operator precedence parser for one expression, reading from `pos` up to `end`. When
+/// `skim` is set it is only finding where the expression stops, brackets are stepped over
+/// without parsing what is inside them.
+pub(crate) struct ExpressionParser<'a> {
+    tokens: &'a Vec<Token>,
+    pos: usize,
+    end: usize,
+    skim: bool
+}
+
+impl<'a> ExpressionParser<'a> {
+
+    pub(crate) fn new(tokens: &'a Vec<Token>, start: usize) -> ExpressionParser<'a> {
+        ExpressionParser { tokens, pos: start, end: tokens.len(), skim: false }
+    }
+
+    /// Parser that only moves `pos` past the expression.
+    pub(crate) fn skimmer(tokens: &'a Vec<Token>, start: usize) -> ExpressionParser<'a> {
+        ExpressionParser { tokens, pos: start, end: tokens.len(), skim: true }
+    }
+
+    /// Index of the first token the expression didn't use.
+    pub(crate) fn pos(&self) -> usize {
+        self.pos
+    }
+
+    fn peek(&self) -> Option<&'a Token> {
+        if self.pos < self.end {
+            self.tokens.get(self.pos)
+        } else {
+            None
+        }
+    }
+
+    fn next(&mut self) -> Result<&'a Token, SyntaxError> {
+        match self.peek() {
+            Some(token) => {
+                self.pos += 1;
+                Ok(token)
+            }
+            None => Err(SyntaxError::UnexpectedEndOfInput)
+        }
+    }
+
+    /// Span of the tokens from `start` up to the one `pos` is at.
+    fn span_from(&self, start: usize) -> Span {
+        Span::of(&self.tokens[start..self.pos])
+    }
+
+    /// Index of the bracket closing the one at `open`, an error if it isn't closed before `end`.
+    fn closing(&self, open: usize, find: fn(usize, &Vec<Token>) -> usize) -> Result<usize, SyntaxError> {
+        let close = find(open, self.tokens);
+        if close >= self.end {
+            return Err(SyntaxError::UnexpectedEndOfInput);
+        }
+        Ok(close)
+    }
+
+    /// Parses the whole of `tokens[start..close]` as one expression.
+    fn group(&self, start: usize, close: usize) -> Result<Expression, SyntaxError> {
+        let mut inner = ExpressionParser { tokens: self.tokens, pos: start, end: close, skim: self.skim };
+        let expression = inner.parse_expression(0)?;
+        match inner.peek() {
+            None => Ok(expression),
+            Some(token) => Err(SyntaxError::UnexpectedToken { tok: token.clone() })
+        }
+    }
+
+    fn primary(&mut self) -> Result<Expression, SyntaxError> {
+        let start = self.pos;
+        let token = self.next()?;
+        let expression = match &token.tok {
+            Tok::Float { value } => Expression::Number { value: *value, span: self.span_from(start) },
+            Tok::String { value } => Expression::Literal { value: value.clone(), span: self.span_from(start) },
+            Tok::Name { name } => Expression::Identifier { name: name.clone(), span: self.span_from(start) },
+            Tok::True => Expression::True { span: self.span_from(start) },
+            Tok::False => Expression::False { span: self.span_from(start) },
+            Tok::Null => Expression::Null { span: self.span_from(start) },
+            Tok::This => Expression::Identifier { name: "this".to_string(), span: self.span_from(start) },
+            Tok::Regex { pattern, flags } => Expression::RegExp { pattern: pattern.clone(), flags: flags.clone(), span: self.span_from(start) },
+            Tok::NoSubstitutionTemplate { .. } | Tok::TemplateHead { .. } => {
+                let close = self.closing(start, find_matching_template)?;
+                self.pos = close + 1;
+                if self.skim {
+                    return Ok(Expression::None);
+                }
+                create_template_literal(self.tokens[start..=close].to_vec())?
+            }
+            Tok::Lpar => {
+                let close = self.closing(start, find_matching_paren)?;
+                self.pos = close + 1;
+                Expression::SubExpression { expression: Box::new(self.group(start + 1, close)?), span: self.span_from(start) }
+            }
+            Tok::Lsqb => {
+                let close = self.closing(start, find_matching_sqb)?;
+                self.pos = close + 1;
+                if self.skim {
+                    return Ok(Expression::None);
+                }
+                let items = parse_items(&self.tokens[start + 1..close])?;
+                let length = JSItem::Number { value: items.len() as f64 };
+                Expression::ArrayExpression { items, properties: PropertyMap::from(vec![("length".to_string(), length)]), span: self.span_from(start) }
+            }
+            Tok::Lbrace => {
+                let close = self.closing(start, find_matching_brace)?;
+                self.pos = close + 1;
+                if self.skim {
+                    return Ok(Expression::None);
+                }
+                match create_object_expression(self.tokens[start..=close].to_vec())? {
+                    JSItem::Object { mutable, properties } => Expression::Object { mutable, properties, span: self.span_from(start) },
+                    _ => Expression::None
+                }
+            }
+            Tok::Function => {
+                let params = self.closing(start, find_matching_paren)?;
+                let close = self.closing(params + 1, find_matching_brace)?;
+                self.pos = close + 1;
+                if self.skim {
+                    return Ok(Expression::None);
+                }
+                match create_function_expression(self.tokens[start..=close].to_vec())? {
+                    JSItem::Ex { expression, .. } => *expression,
+                    _ => Expression::None
+                }
+            }
+            tok if update_operator(tok).is_some() => {
+                let expression = self.parse_expression(UNARY)?;
+                if !is_assignment_target(&expression) {
+                    return Err(SyntaxError::UnexpectedToken { tok: token.clone() });
+                }
+                Expression::UpdateExpression {
+                    operator: update_operator(tok).unwrap(),
+                    prefix: true,
+                    expression: Box::new(expression),
+                    span: self.span_from(start)
+                }
+            }
+            tok => match prefix_operator(tok) {
+                Some(op) => {
+                    let argument = self.parse_expression(UNARY)?;
+                    Expression::Unary { op, argument: Box::new(argument), span: self.span_from(start) }
+                }
+                None => return Err(SyntaxError::UnexpectedToken { tok: token.clone() })
+            }
+        };
+        Ok(expression)
+    }
+
+    /// Parses an expression made of operators binding at least as tightly as `min_bp`.
+    pub(crate) fn parse_expression(&mut self, min_bp: u8) -> Result<Expression, SyntaxError> {
+        let start = self.pos;
+        let mut left = self.primary()?;
+
+        while let Some(token) = self.peek() {
+            match &token.tok {
+                Tok::Dot => {
+                    self.pos += 1;
+                    let property = match &self.next()?.tok {
+                        Tok::Name { name } => Expression::Identifier { name: name.clone(), span: self.span_from(self.pos - 1) },
+                        _ => return Err(SyntaxError::UnexpectedToken { tok: self.tokens[self.pos - 1].clone() })
+                    };
+                    left = Expression::MemberExpression { object: Box::new(left), property: Box::new(property), span: self.span_from(start) };
+                }
+                Tok::Lsqb => {
+                    let close = self.closing(self.pos, find_matching_sqb)?;
+                    let property = self.group(self.pos + 1, close)?;
+                    self.pos = close + 1;
+                    left = Expression::ComputedMemberExpression { object: Box::new(left), property: Box::new(property), span: self.span_from(start) };
+                }
+                Tok::Lpar => {
+                    let close = self.closing(self.pos, find_matching_paren)?;
+                    let arguments = if self.skim {
+                        vec![]
+                    } else {
+                        parse_items(&self.tokens[self.pos + 1..close])?
+                    };
+                    self.pos = close + 1;
+                    left = Expression::CallExpression { callee: Box::new(left), arguments, span: self.span_from(start) };
+                }
+                tok if update_operator(tok).is_some() => {
+                    if !is_assignment_target(&left) {
+                        return Err(SyntaxError::UnexpectedToken { tok: token.clone() });
+                    }
+                    self.pos += 1;
+                    left = Expression::UpdateExpression {
+                        operator: update_operator(tok).unwrap(),
+                        prefix: false,
+                        expression: Box::new(left),
+                        span: self.span_from(start)
+                    };
+                }
+                tok if assignment_operator(tok).is_some() => {
+                    if ASSIGNMENT < min_bp {
+                        break;
+                    }
+                    let operator = assignment_operator(tok).unwrap();
+                    if !is_assignment_target(&left) {
+                        return Err(SyntaxError::UnexpectedToken { tok: token.clone() });
+                    }
+                    self.pos += 1;
+                    // right associative, a = b = c assigns c to b first
+                    let right = self.parse_expression(ASSIGNMENT)?;
+                    left = Expression::Assign { operator, left: Box::new(left), right: Box::new(right), span: self.span_from(start) };
+                }
+                Tok::Question => {
+                    if CONDITIONAL < min_bp {
+                        break;
+                    }
+                    self.pos += 1;
+                    let consequent = self.parse_expression(ASSIGNMENT)?;
+                    let colon = self.next()?;
+                    if colon.tok != Tok::Colon {
+                        return Err(SyntaxError::UnexpectedToken { tok: colon.clone() });
+                    }
+                    // right associative, a ? b : c ? d : e is a ? b : (c ? d : e)
+                    let alternate = self.parse_expression(ASSIGNMENT)?;
+                    left = Expression::Conditional {
+                        test: Box::new(left),
+                        consequent: Box::new(consequent),
+                        alternate: Box::new(alternate),
+                        span: self.span_from(start)
+                    };
+                }
+                tok => {
+                    let (op, bp) = match infix_operator(tok) {
+                        Some(operator) => operator,
+                        None => break
+                    };
+                    if bp < min_bp {
+                        break;
+                    }
+                    self.pos += 1;
+                    let right = if op == Operator::Exp {
+                        self.parse_expression(bp)?
+                    } else {
+                        self.parse_expression(bp + 1)?
+                    };
+                    let mixed = match op {
+                        // -a ** b has to be written (-a) ** b
+                        Operator::Exp => match left {
+                            Expression::Unary { .. } => true,
+                            _ => false
+                        },
+                        Operator::Nullish => is_logical_or_and(&left) || is_logical_or_and(&right),
+                        Operator::Or | Operator::And => {
+                            [&left, &right].iter().any(|ex| match ex {
+                                Expression::Binop { op: Operator::Nullish, .. } => true,
+                                _ => false
+                            })
+                        }
+                        _ => false
+                    };
+                    if mixed {
+                        return Err(SyntaxError::UnexpectedToken { tok: token.clone() });
+                    }
+                    left = Expression::Binop { a: Box::new(left), op, b: Box::new(right), span: self.span_from(start) };
+                }
+            }
+        }
+
+        Ok(left)
+    }
+}
+
+/// Parses `tokens` as a single expression. Line ends and semicolons after it are allowed,
+/// anything else left over is an error.
+pub(crate) fn parse_expression(tokens: &Vec<Token>) -> Result<Expression, SyntaxError> {
+    let mut parser = ExpressionParser::new(tokens, 0);
+    let expression = parser.parse_expression(0)?;
+    match tokens[parser.pos()..].iter().find(|token| token.tok != Tok::Semi && token.tok != Tok::EndOfLine) {
+        None => Ok(expression),
+        Some(token) => Err(SyntaxError::UnexpectedToken { tok: token.clone() })
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use crate::parser::symbols::{JSItem, array_index};
+
+/// The properties of an object or array, kept in the order JavaScript enumerates them:
+/// integer keys ascending, then string keys in insertion order. There are no symbol keys
+/// yet, when there are they go after the strings. `slots` maps each key to its place in
+/// `entries` so lookups don't scan.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct PropertyMap {
+    entries: Vec<(String, JSItem)>,
+    slots: HashMap<String, usize>
+}
+
+impl PropertyMap {
+    pub(crate) fn new() -> PropertyMap {
+        PropertyMap { entries: vec![], slots: HashMap::new() }
+    }
+
+    fn position(&self, key: &str) -> Option<usize> {
+        self.slots.get(key).copied()
+    }
+
+    pub(crate) fn contains_key(&self, key: &str) -> bool {
+        self.position(key).is_some()
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<&JSItem> {
+        self.position(key).map(|i| &self.entries[i].1)
+    }
+
+    pub(crate) fn get_mut(&mut self, key: &str) -> Option<&mut JSItem> {
+        match self.position(key) {
+            Some(i) => Some(&mut self.entries[i].1),
+            None => None
+        }
+    }
+
+    /// Sets `key`, an existing key keeps its place and a new one goes where it enumerates.
+    pub(crate) fn insert(&mut self, key: String, value: JSItem) -> Option<JSItem> {
+        if let Some(i) = self.position(&key) {
+            return Some(std::mem::replace(&mut self.entries[i].1, value));
+        }
+        // the integer keys are a sorted run at the front
+        let at = match array_index(&key) {
+            Some(index) => self.entries
+                .partition_point(|(name, _)| array_index(name).map_or(false, |other| other < index)),
+            None => self.entries.len()
+        };
+        if at < self.entries.len() {
+            for slot in self.slots.values_mut().filter(|slot| **slot >= at) {
+                *slot += 1;
+            }
+        }
+        self.slots.insert(key.clone(), at);
+        self.entries.insert(at, (key, value));
+        None
+    }
+
+    pub(crate) fn remove(&mut self, key: &str) -> Option<JSItem> {
+        let at = self.slots.remove(key)?;
+        for slot in self.slots.values_mut().filter(|slot| **slot > at) {
+            *slot -= 1;
+        }
+        Some(self.entries.remove(at).1)
+    }
+
+    pub(crate) fn keys(&self) -> impl Iterator<Item = &String> {
+        self.entries.iter().map(|(name, _)| name)
+    }
+}
+
+impl IntoIterator for PropertyMap {
+    type Item = (String, JSItem);
+    type IntoIter = std::vec::IntoIter<(String, JSItem)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl std::iter::FromIterator<(String, JSItem)> for PropertyMap {
+    fn from_iter<I: IntoIterator<Item = (String, JSItem)>>(iter: I) -> PropertyMap {
+        let mut map = PropertyMap::new();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+impl From<Vec<(String, JSItem)>> for PropertyMap {
+    fn from(entries: Vec<(String, JSItem)>) -> PropertyMap {
+        entries.into_iter().collect()
+    }
+}
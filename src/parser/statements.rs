@@ -0,0 +1,69 @@
+use std::collections::VecDeque;
+use crate::lexer::js_token::{Tok, Token};
+
+/// Splits tokens that have been through semicolon insertion into top level statements, so a
+/// script can be parsed while it is read. A statement ends at a `;`, or at the line end after
+/// a block, outside any brackets. It carries on when the next line starts with `else`,
+/// `catch`, `finally` or the `while` of a `do`.
+pub(crate) struct Statements<I: Iterator<Item = Token>> {
+    tokens: I,
+    // line ends read ahead, with the token after them last
+    ahead: VecDeque<Token>
+}
+
+impl<I: Iterator<Item = Token>> Statements<I> {
+    pub(crate) fn new(tokens: I) -> Statements<I> {
+        Statements { tokens, ahead: VecDeque::new() }
+    }
+
+    fn pull(&mut self) -> Option<Token> {
+        self.ahead.pop_front().or_else(|| self.tokens.next())
+    }
+
+    /// Whether the first token that isn't a line end carries on the statement before it.
+    fn next_continues(&mut self) -> bool {
+        loop {
+            if let Some(token) = self.ahead.iter().find(|token| token.tok != Tok::EndOfLine) {
+                return match token.tok {
+                    Tok::Else | Tok::Catch | Tok::Finally | Tok::While => true,
+                    _ => false
+                };
+            }
+            match self.tokens.next() {
+                Some(token) => self.ahead.push_back(token),
+                None => return false
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = Token>> Iterator for Statements<I> {
+    type Item = Vec<Token>;
+
+    fn next(&mut self) -> Option<Vec<Token>> {
+        let mut statement: Vec<Token> = vec![];
+        let mut depth = 0usize;
+        while let Some(token) = self.pull() {
+            match token.tok {
+                Tok::Lpar | Tok::Lsqb | Tok::Lbrace | Tok::TemplateHead { .. } => depth += 1,
+                Tok::Rpar | Tok::Rsqb | Tok::Rbrace | Tok::TemplateTail { .. } => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            let ends = depth == 0 && match token.tok {
+                Tok::Semi => true,
+                // semicolon insertion only keeps a line end with no `;` before it after a block
+                Tok::EndOfLine => statement.last().map_or(false, |last| last.tok == Tok::Rbrace),
+                _ => false
+            };
+            statement.push(token);
+            if ends && !self.next_continues() {
+                break;
+            }
+        }
+        if statement.is_empty() {
+            None
+        } else {
+            Some(statement)
+        }
+    }
+}
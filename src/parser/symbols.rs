@@ -1,57 +1,121 @@
 use crate::lexer::js_token::Tok;
-use std::collections::HashMap;
+use crate::lexer::span::Span;
+use crate::parser::property_map::PropertyMap;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter, Result};
 
+/// An expression node. Every node but `None` carries the span of the source it was parsed
+/// from, operands included, so `a + b` knows where `a` and `b` each are.
 #[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) enum Expression {
     Object {
         mutable: bool,
-        properties: HashMap<String, JSItem>
+        properties: PropertyMap,
+        span: Span
     },
     Binop {
         a: Box<Expression>,
         op: Operator,
         b: Box<Expression>,
+        span: Span
     },
     // A numeric literal.
     Number {
         value: f64,
+        span: Span
     },
     String {
-        value: String
+        value: String,
+        span: Span
     },
     Identifier {
-        name: String
+        name: String,
+        span: Span
     },
     Literal {
-        value: String
+        value: String,
+        span: Span
+    },
+    Null {
+        span: Span
     },
-    Null,
     None,
-    True, // The literal 'True'.
-    False, // The literal 'False'.
+    // The literal 'True'.
+    True {
+        span: Span
+    },
+    // The literal 'False'.
+    False {
+        span: Span
+    },
     CallExpression {
         callee: Box<Expression>,
-        arguments: Vec<JSItem>
+        arguments: Vec<JSItem>,
+        span: Span
     },
     MemberExpression {
         object: Box<Expression>,
-        property: Box<Expression>
+        property: Box<Expression>,
+        span: Span
+    },
+    // `object[property]`, the property is any expression and becomes a key when evaluated.
+    ComputedMemberExpression {
+        object: Box<Expression>,
+        property: Box<Expression>,
+        span: Span
     },
     SubExpression {
-       expression: Box<Expression>
+       expression: Box<Expression>,
+       span: Span
     },
+    // `++x` or `x--`, `operator` is Add or Sub.
     UpdateExpression {
-        expression: Box<Expression>
+        operator: Operator,
+        prefix: bool,
+        expression: Box<Expression>,
+        span: Span
+    },
+    Unary {
+        op: UnaryOperator,
+        argument: Box<Expression>,
+        span: Span
+    },
+    // `test ? consequent : alternate`, only the chosen branch is evaluated.
+    Conditional {
+        test: Box<Expression>,
+        consequent: Box<Expression>,
+        alternate: Box<Expression>,
+        span: Span
+    },
+    // `left = right` used as a value, `operator` is None for a plain `=`.
+    Assign {
+        operator: Operator,
+        left: Box<Expression>,
+        right: Box<Expression>,
+        span: Span
     },
     ArrayExpression {
         items: Vec<JSItem>,
-        properties: HashMap<String, JSItem>
+        properties: PropertyMap,
+        span: Span
     },
     FuncEx {
         params: Vec<Tok>,
-        body: Vec<JSItem>
+        body: Vec<JSItem>,
+        span: Span
+    },
+    // `cooked[0]${expressions[0]}cooked[1]...`, there is always one more chunk than expression.
+    TemplateLiteral {
+        cooked: Vec<String>,
+        raw: Vec<String>,
+        expressions: Vec<JSItem>,
+        span: Span
+    },
+    RegExp {
+        pattern: String,
+        flags: String,
+        span: Span
     },
 }
 
@@ -67,15 +131,37 @@ pub(crate) enum Operator {
     Mod, // %
     LShift, // <<
     RShift, // >>
+    URShift, // >>>
+    Exp, // **
     BitOr, // |
-    BitXor, //
-    BitAnd,
+    BitXor, // ^
+    BitAnd, // &
     FloorDiv,
     Less, // <
     Greater, // >
+    LessEqual, // <=
+    GreaterEqual, // >=
+    In, // in
+    InstanceOf, // instanceof
     And, // &&
+    Or, // ||
+    Nullish, // ??
     EqEq, // ==
-    EqEqEq // ===
+    EqEqEq, // ===
+    NotEq, // !=
+    NotEqEq // !==
+}
+
+/// An operator written before its one operand.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum UnaryOperator {
+    Not, // !
+    Minus, // -
+    Plus, // +
+    BitNot, // ~
+    TypeOf, // typeof
+    Void, // void
+    Delete // delete
 }
 
 #[allow(dead_code)]
@@ -93,14 +179,37 @@ pub(crate) enum Statement {
     //temporary non filled in statement
     None,
 
-    Break,
+    Break {
+        label: Option<String>
+    },
+
+    Continue {
+        label: Option<String>
+    },
 
-    Continue,
+    /// `label: body`, the body is the statements of a block or a single statement.
+    Labelled {
+        label: String,
+        body: Vec<JSItem>
+    },
 
+    /// `return` with no value, or a line end right after it, has `None`.
     Return {
+        value: Option<Box<JSItem>>
+    },
+
+    Throw {
         value: Box<JSItem>
     },
 
+    /// `try { block } catch (param) { .. } finally { finalizer }`, at least one of the handler
+    /// and finalizer is there.
+    Try {
+        block: Vec<JSItem>,
+        handler: Option<CatchClause>,
+        finalizer: Option<Vec<JSItem>>
+    },
+
     AssignmentExpression {
         operator: AssignOp,
         left: JSItem,
@@ -125,8 +234,18 @@ pub(crate) enum Statement {
     },
 
     While {
-        test: Box<Expression>,
-        body: Box<Expression>,
+        test: JSItem,
+        body: Vec<JSItem>
+    },
+
+    DoWhile {
+        body: Vec<JSItem>,
+        test: JSItem
+    },
+
+    Switch {
+        discriminant: JSItem,
+        cases: Vec<SwitchCase>
     },
 
     FunctionDef {
@@ -153,6 +272,20 @@ pub(crate) enum Statement {
     }
 }
 
+/// `catch (param) { body }`, the binding can be left out.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct CatchClause {
+    pub(crate) param: Option<String>,
+    pub(crate) body: Vec<JSItem>
+}
+
+/// One `case test:` of a switch, `default:` has no test.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SwitchCase {
+    pub(crate) test: Option<JSItem>,
+    pub(crate) consequent: Vec<JSItem>
+}
+
 #[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) enum StdFun {
@@ -189,16 +322,18 @@ pub(crate) enum JSItem {
     },
 
     Ex {
-        expression: Box<Expression>
+        expression: Box<Expression>,
+        span: Span
     },
 
     St {
-        statement: Box<Statement>
+        statement: Box<Statement>,
+        span: Span
     },
 
     Object {
         mutable: bool,
-        properties: HashMap<String, JSItem>
+        properties: PropertyMap
     },
 
     Located {
@@ -209,7 +344,12 @@ pub(crate) enum JSItem {
 
     Array {
         items: Vec<JSItem>,
-        properties: HashMap<String, JSItem>
+        properties: PropertyMap
+    },
+
+    RegExp {
+        pattern: String,
+        flags: String
     },
 
     Variable {
@@ -220,20 +360,442 @@ pub(crate) enum JSItem {
     Function {
         mutable: bool,
         params: Vec<Tok>,
-        properties: HashMap<String, JSItem>,
+        properties: PropertyMap,
         body: Vec<JSItem>
     },
 
     BcFunction {
         start: usize,
         params: Vec<String>
-    },
+    }
+}
+
+/// ECMAScript Number::toString for the radix 10 case.
+pub(crate) fn number_to_string(value: f64) -> String {
+    if value.is_nan() {
+        return "NaN".to_string();
+    }
+    if value.is_infinite() {
+        return if value > 0. { "Infinity".to_string() } else { "-Infinity".to_string() };
+    }
+    if value == 0. {
+        return "0".to_string();
+    }
+    let abs = value.abs();
+    if abs >= 1e21 || abs < 1e-6 {
+        let exponential = format!("{:e}", value);
+        if exponential.contains("e-") {
+            return exponential;
+        }
+        return exponential.replace('e', "e+");
+    }
+    value.to_string()
+}
+
+/// ECMAScript StringToNumber, anything that isn't a whole numeric literal is NaN.
+pub(crate) fn string_to_number(value: &str) -> f64 {
+    let value = value.trim();
+    if value.is_empty() {
+        return 0.;
+    }
+    let radix = match value.get(..2) {
+        Some("0x") | Some("0X") => 16,
+        Some("0o") | Some("0O") => 8,
+        Some("0b") | Some("0B") => 2,
+        _ => 10
+    };
+    if radix != 10 {
+        return u64::from_str_radix(&value[2..], radix).map_or(f64::NAN, |value| value as f64);
+    }
+    match value.trim_start_matches(|ch| ch == '+' || ch == '-') {
+        "Infinity" => return if value.starts_with('-') { f64::NEG_INFINITY } else { f64::INFINITY },
+        digits if digits.chars().all(|ch| ch.is_ascii_digit() || ch == '.' || ch == 'e' || ch == 'E' || ch == '+' || ch == '-') => {}
+        _ => return f64::NAN
+    }
+    value.parse::<f64>().unwrap_or(f64::NAN)
+}
+
+/// ECMAScript ToInt32, wraps the integer part of `value` into 32 bits.
+pub(crate) fn to_int32(value: f64) -> i32 {
+    if !value.is_finite() {
+        return 0;
+    }
+    value.trunc().rem_euclid(4294967296.) as u32 as i32
+}
+
+/// `a op b` for an operator that only works on numbers, `+` here is numeric addition.
+pub(crate) fn number_binop(op: &Operator, a: f64, b: f64) -> f64 {
+    match op {
+        Operator::Add => a + b,
+        Operator::Sub => a - b,
+        Operator::Mult => a * b,
+        Operator::Div => a / b,
+        Operator::Mod => a % b,
+        // powf has 1 ** NaN and 1 ** Infinity as 1, JavaScript has them as NaN
+        Operator::Exp if b.is_nan() || (a.abs() == 1. && b.is_infinite()) => f64::NAN,
+        Operator::Exp => a.powf(b),
+        Operator::LShift => to_int32(a).wrapping_shl(to_int32(b) as u32 & 31) as f64,
+        Operator::RShift => (to_int32(a) >> (to_int32(b) as u32 & 31)) as f64,
+        Operator::URShift => ((to_int32(a) as u32) >> (to_int32(b) as u32 & 31)) as f64,
+        Operator::BitAnd => (to_int32(a) & to_int32(b)) as f64,
+        Operator::BitOr => (to_int32(a) | to_int32(b)) as f64,
+        Operator::BitXor => (to_int32(a) ^ to_int32(b)) as f64,
+        _ => f64::NAN
+    }
+}
+
+/// `name` read off an array, an element, `length` or one of its other properties.
+pub(crate) fn array_property(items: &Vec<JSItem>, properties: &PropertyMap, name: &str) -> JSItem {
+    if name == "length" {
+        return JSItem::Number { value: items.len() as f64 };
+    }
+    let item = match array_index(name) {
+        Some(index) => items.get(index),
+        None => properties.get(name)
+    };
+    item.cloned().unwrap_or(JSItem::Undefined)
+}
+
+/// The own properties of a RegExp instance, `lastIndex` always starts at 0.
+pub(crate) fn regexp_property(pattern: &str, flags: &str, name: &str) -> JSItem {
+    let flag = |flag: char| JSItem::Bool { value: flags.contains(flag) };
+    match name {
+        "source" => JSItem::String { value: pattern.to_string() },
+        "flags" => JSItem::String { value: flags.to_string() },
+        "lastIndex" => JSItem::Number { value: 0. },
+        "hasIndices" => flag('d'),
+        "global" => flag('g'),
+        "ignoreCase" => flag('i'),
+        "multiline" => flag('m'),
+        "dotAll" => flag('s'),
+        "unicode" => flag('u'),
+        "unicodeSets" => flag('v'),
+        "sticky" => flag('y'),
+        _ => JSItem::Undefined
+    }
+}
+
+impl Expression {
+    /// Where the expression was parsed from, the default span for `None` and for nodes the
+    /// interpreter builds itself.
+    pub(crate) fn span(&self) -> Span {
+        match self {
+            Expression::None => Span::default(),
+            Expression::Object { span, .. } | Expression::Binop { span, .. }
+            | Expression::Number { span, .. } | Expression::String { span, .. }
+            | Expression::Identifier { span, .. } | Expression::Literal { span, .. }
+            | Expression::Null { span } | Expression::True { span } | Expression::False { span }
+            | Expression::CallExpression { span, .. } | Expression::MemberExpression { span, .. }
+            | Expression::ComputedMemberExpression { span, .. } | Expression::SubExpression { span, .. }
+            | Expression::UpdateExpression { span, .. } | Expression::Unary { span, .. }
+            | Expression::Conditional { span, .. } | Expression::Assign { span, .. }
+            | Expression::ArrayExpression { span, .. } | Expression::FuncEx { span, .. }
+            | Expression::TemplateLiteral { span, .. } | Expression::RegExp { span, .. } => span.clone()
+        }
+    }
+}
+
+impl JSItem {
+    /// ECMAScript ToBoolean.
+    pub(crate) fn is_truthy(&self) -> bool {
+        match self {
+            JSItem::Bool { value } => *value,
+            JSItem::Number { value } => *value != 0. && !value.is_nan(),
+            JSItem::String { value } => !value.is_empty(),
+            JSItem::Null | JSItem::Undefined | JSItem::NaN => false,
+            JSItem::Located { object, .. } => object.is_truthy(),
+            JSItem::Variable { value, .. } => expression_is_truthy(value),
+            JSItem::Ex { expression, .. } => expression_is_truthy(expression),
+            _ => true
+        }
+    }
+
+    /// True for `null` and `undefined`, the values `??` skips.
+    pub(crate) fn is_nullish(&self) -> bool {
+        match self {
+            JSItem::Null | JSItem::Undefined => true,
+            JSItem::Located { object, .. } => object.is_nullish(),
+            JSItem::Variable { value: Expression::Null { .. }, .. } => true,
+            JSItem::Ex { expression, .. } => match **expression {
+                Expression::Null { .. } => true,
+                _ => false
+            },
+            _ => false
+        }
+    }
+
+    /// ECMAScript ToNumber.
+    pub(crate) fn to_number(&self) -> f64 {
+        match self {
+            JSItem::Number { value } => *value,
+            JSItem::Bool { value } => if *value { 1. } else { 0. },
+            JSItem::Null => 0.,
+            JSItem::String { value } => string_to_number(value),
+            JSItem::Array { .. } => string_to_number(&self.to_js_string()),
+            JSItem::Located { object, .. } => object.to_number(),
+            JSItem::Variable { value, .. } => expression_to_number(value),
+            JSItem::Ex { expression, .. } => expression_to_number(expression),
+            _ => f64::NAN
+        }
+    }
+
+    /// What `typeof` gives for the value.
+    pub(crate) fn type_of(&self) -> &'static str {
+        match self {
+            JSItem::Undefined => "undefined",
+            JSItem::Bool { .. } => "boolean",
+            JSItem::Number { .. } | JSItem::NaN => "number",
+            JSItem::String { .. } => "string",
+            JSItem::Std { .. } | JSItem::Function { .. } | JSItem::BcFunction { .. } => "function",
+            JSItem::Located { object, .. } => object.type_of(),
+            JSItem::Variable { value, .. } => expression_type_of(value),
+            JSItem::Ex { expression, .. } => expression_type_of(expression),
+            _ => "object"
+        }
+    }
+
+    /// ECMAScript IsStrictlyEqual, `NaN` is never equal and numbers compare by value.
+    pub(crate) fn strictly_equals(&self, other: &JSItem) -> bool {
+        match (self, other) {
+            (JSItem::Located { object, .. }, other) => object.strictly_equals(other),
+            (item, JSItem::Located { object, .. }) => item.strictly_equals(object),
+            (JSItem::NaN, _) | (_, JSItem::NaN) => false,
+            (JSItem::Number { value: a }, JSItem::Number { value: b }) => a == b,
+            (a, b) => a == b
+        }
+    }
+
+    /// ECMAScript IsLooselyEqual, `null` and `undefined` only equal each other, two objects
+    /// compare like `===` and anything else is compared as strings or numbers.
+    pub(crate) fn loosely_equals(&self, other: &JSItem) -> bool {
+        let (a, b) = (self.unwrapped(), other.unwrapped());
+        if a.is_nullish() || b.is_nullish() {
+            return a.is_nullish() && b.is_nullish();
+        }
+        if a.is_object() && b.is_object() {
+            return self.strictly_equals(other);
+        }
+        match (a.to_primitive(), b.to_primitive()) {
+            (JSItem::String { value: a }, JSItem::String { value: b }) => a == b,
+            (a, b) => a.to_number() == b.to_number()
+        }
+    }
+
+    /// ECMAScript ToPrimitive, objects become their string form.
+    pub(crate) fn to_primitive(&self) -> JSItem {
+        let item = self.unwrapped();
+        if item.is_object() {
+            JSItem::String { value: item.to_js_string() }
+        } else {
+            item
+        }
+    }
+
+    /// Whether `key in self` holds, `None` when the value isn't an object and `in` throws.
+    pub(crate) fn has_property(&self, key: &str) -> Option<bool> {
+        match self {
+            JSItem::Located { object, .. } => object.has_property(key),
+            JSItem::Object { properties, .. } | JSItem::Function { properties, .. } => {
+                Some(properties.contains_key(key))
+            }
+            JSItem::Array { items, properties } => {
+                Some(key == "length" || array_index(key).map_or(false, |i| i < items.len()) || properties.contains_key(key))
+            }
+            JSItem::RegExp { pattern, flags } => Some(regexp_property(pattern, flags, key) != JSItem::Undefined),
+            JSItem::Std { .. } | JSItem::BcFunction { .. } => Some(false),
+            JSItem::Variable { value, .. } => expression_has_property(value, key),
+            JSItem::Ex { expression, .. } => expression_has_property(expression, key),
+            _ => None
+        }
+    }
+
+    /// `Object.keys(self)` as an array, `None` for null and undefined, which can't be made into
+    /// objects.
+    pub(crate) fn own_keys(&self) -> Option<JSItem> {
+        let keys = match self {
+            JSItem::Located { object, .. } => return object.own_keys(),
+            JSItem::Null | JSItem::Undefined => return None,
+            JSItem::Object { properties, .. } | JSItem::Function { properties, .. } => {
+                properties.keys().cloned().collect()
+            }
+            JSItem::Array { items, properties } => {
+                (0..items.len()).map(|i| i.to_string()).chain(properties.keys().cloned()).collect()
+            }
+            JSItem::String { value } => (0..value.encode_utf16().count()).map(|i| i.to_string()).collect(),
+            _ => vec![]
+        };
+        Some(JSItem::Array {
+            items: keys.into_iter().map(|value| JSItem::String { value }).collect(),
+            properties: PropertyMap::new()
+        })
+    }
+
+    /// `self instanceof target`, `None` when the target can't be called and `instanceof` throws.
+    /// Nothing is made with `new` so the only prototypes an object can have are the built in
+    /// ones, and of those only `Array` can be called.
+    pub(crate) fn instance_of(&self, target: &JSItem) -> Option<bool> {
+        if target.type_of() != "function" {
+            return None;
+        }
+        let is_array = match self.unwrapped() {
+            JSItem::Array { .. } => true,
+            JSItem::Ex { expression, .. } => matches!(*expression, Expression::ArrayExpression { .. }),
+            JSItem::Variable { value: Expression::ArrayExpression { .. }, .. } => true,
+            _ => false
+        };
+        Some(is_array && matches!(target.unwrapped(), JSItem::Std { func: StdFun::ArrayConstructor, .. }))
+    }
+
+    /// The value without the `Located` wrapper, and a literal `Variable`/`Ex` as the plain
+    /// value it holds.
+    fn unwrapped(&self) -> JSItem {
+        let expression = match self {
+            JSItem::Located { object, .. } => return object.unwrapped(),
+            JSItem::Variable { value, .. } => value,
+            JSItem::Ex { expression, .. } => &**expression,
+            _ => return self.clone()
+        };
+        match expression {
+            Expression::Number { value, .. } => JSItem::Number { value: *value },
+            Expression::String { value, .. } | Expression::Literal { value, .. } => JSItem::String { value: value.clone() },
+            Expression::True { .. } => JSItem::Bool { value: true },
+            Expression::False { .. } => JSItem::Bool { value: false },
+            Expression::Null { .. } => JSItem::Null,
+            Expression::None => JSItem::Undefined,
+            _ => self.clone()
+        }
+    }
+
+    fn is_object(&self) -> bool {
+        match self {
+            JSItem::Undefined | JSItem::Null | JSItem::NaN | JSItem::Bool { .. }
+            | JSItem::Number { .. } | JSItem::String { .. } => false,
+            _ => true
+        }
+    }
+
+    /// ECMAScript ToString, objects and functions use their default tags.
+    pub(crate) fn to_js_string(&self) -> String {
+        match self {
+            JSItem::String { value } => value.clone(),
+            JSItem::Number { value } => number_to_string(*value),
+            JSItem::NaN => "NaN".to_string(),
+            JSItem::Bool { value } => value.to_string(),
+            JSItem::Null => "null".to_string(),
+            JSItem::Undefined => "undefined".to_string(),
+            JSItem::Array { items, .. } => array_to_string(items),
+            JSItem::RegExp { pattern, flags } => format!("/{}/{}", pattern, flags),
+            JSItem::Object { .. } | JSItem::ObjectReference { .. } => "[object Object]".to_string(),
+            JSItem::Located { object, .. } => object.to_js_string(),
+            JSItem::Std { .. } | JSItem::Function { .. } | JSItem::BcFunction { .. } => {
+                "function () { [native code] }".to_string()
+            }
+            JSItem::Variable { value, .. } => expression_to_string(value),
+            JSItem::Ex { expression, .. } => expression_to_string(expression),
+            _ => "undefined".to_string()
+        }
+    }
+
+    /// The key `object[value]` looks up, there are no symbols so it is the string value.
+    pub(crate) fn to_property_key(&self) -> String {
+        self.to_js_string()
+    }
+}
+
+/// `a op b` for `<`, `>`, `<=` and `>=`. Two strings compare by UTF-16 code units and
+/// anything else as numbers, where a NaN makes all four false.
+pub(crate) fn compare_values(op: &Operator, a: &JSItem, b: &JSItem) -> bool {
+    let ordering = match (a.to_primitive(), b.to_primitive()) {
+        (JSItem::String { value: a }, JSItem::String { value: b }) => Some(a.encode_utf16().cmp(b.encode_utf16())),
+        (a, b) => a.to_number().partial_cmp(&b.to_number())
+    };
+    match (op, ordering) {
+        (_, None) => false,
+        (Operator::Less, Some(ordering)) => ordering == Ordering::Less,
+        (Operator::Greater, Some(ordering)) => ordering == Ordering::Greater,
+        (Operator::LessEqual, Some(ordering)) => ordering != Ordering::Greater,
+        (Operator::GreaterEqual, Some(ordering)) => ordering != Ordering::Less,
+        _ => false
+    }
+}
 
-    ReturnJump {
-        to: usize
+/// The position `key` names when it is an array index, the canonical form of a whole
+/// number below 2 ** 32 - 1.
+pub(crate) fn array_index(key: &str) -> Option<usize> {
+    match key.parse::<u32>() {
+        Ok(index) if index < u32::MAX && index.to_string() == key => Some(index as usize),
+        _ => None
     }
 }
 
+fn expression_is_truthy(value: &Expression) -> bool {
+    match value {
+        Expression::Number { value, .. } => *value != 0. && !value.is_nan(),
+        Expression::String { value, .. } | Expression::Literal { value, .. } => !value.is_empty(),
+        Expression::False { .. } | Expression::Null { .. } | Expression::None => false,
+        _ => true
+    }
+}
+
+fn expression_has_property(value: &Expression, key: &str) -> Option<bool> {
+    match value {
+        Expression::Object { properties, .. } => Some(properties.contains_key(key)),
+        Expression::ArrayExpression { items, properties, .. } => {
+            Some(key == "length" || array_index(key).map_or(false, |i| i < items.len()) || properties.contains_key(key))
+        }
+        Expression::FuncEx { .. } => Some(key == "prototype"),
+        Expression::RegExp { pattern, flags, .. } => Some(regexp_property(pattern, flags, key) != JSItem::Undefined),
+        _ => None
+    }
+}
+
+fn expression_to_number(value: &Expression) -> f64 {
+    match value {
+        Expression::Number { value, .. } => *value,
+        Expression::True { .. } => 1.,
+        Expression::False { .. } | Expression::Null { .. } => 0.,
+        Expression::String { value, .. } | Expression::Literal { value, .. } => string_to_number(value),
+        Expression::ArrayExpression { .. } => string_to_number(&expression_to_string(value)),
+        _ => f64::NAN
+    }
+}
+
+fn expression_type_of(value: &Expression) -> &'static str {
+    match value {
+        Expression::Number { .. } => "number",
+        Expression::String { .. } | Expression::Literal { .. } | Expression::TemplateLiteral { .. } => "string",
+        Expression::True { .. } | Expression::False { .. } => "boolean",
+        Expression::FuncEx { .. } => "function",
+        Expression::Null { .. } | Expression::Object { .. } | Expression::ArrayExpression { .. } | Expression::RegExp { .. } => "object",
+        _ => "undefined"
+    }
+}
+
+fn expression_to_string(value: &Expression) -> String {
+    match value {
+        Expression::String { value, .. } | Expression::Literal { value, .. } => value.clone(),
+        Expression::Number { value, .. } => number_to_string(*value),
+        Expression::True { .. } => "true".to_string(),
+        Expression::False { .. } => "false".to_string(),
+        Expression::Null { .. } => "null".to_string(),
+        Expression::ArrayExpression { items, .. } => array_to_string(items),
+        Expression::RegExp { pattern, flags, .. } => format!("/{}/{}", pattern, flags),
+        Expression::Object { .. } => "[object Object]".to_string(),
+        Expression::FuncEx { .. } => "function () { [native code] }".to_string(),
+        _ => "undefined".to_string()
+    }
+}
+
+fn array_to_string(items: &Vec<JSItem>) -> String {
+    items.iter()
+        .map(|item| match item {
+            JSItem::Null | JSItem::Undefined => "".to_string(),
+            _ => item.to_js_string()
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
 impl Display for JSItem {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
@@ -247,7 +809,7 @@ impl Display for JSItem {
                     StdFun::ArrayPush => write!(f, "f push(){{ [native code] }}")
                 }
             }
-            JSItem::St { statement:_ } => {
+            JSItem::St { .. } => {
                 write!(f, "statement")
             }
             JSItem::Number {value} => {
@@ -256,31 +818,34 @@ impl Display for JSItem {
             JSItem::String {value} => {
                 write!(f, "{}", value)
             }
+            JSItem::RegExp { pattern, flags } => {
+                write!(f, "/{}/{}", pattern, flags)
+            }
             JSItem::Variable { mutable: _, value } => {
                 match value {
-                    Expression::String {value} => {
+                    Expression::String {value, ..} => {
                         write!(f, "{}", value)
                     }
-                    Expression::Number { value } => {
+                    Expression::Number { value, .. } => {
                         write!(f, "{}", value)
                     }
-                    Expression::Literal { value } => {
+                    Expression::Literal { value, .. } => {
                         write!(f, "{}", value)
                     }
-                    Expression::Null => {
+                    Expression::Null { .. } => {
                         write!(f, "null")
                     }
-                    Expression::True => {
+                    Expression::True { .. } => {
                         write!(f, "true")
                     }
-                    Expression::False => {
+                    Expression::False { .. } => {
                         write!(f, "false")
                     }
                     _ => write!(f, "")
                 }
             }
             _ => {
-                write!(f, "")
+                write!(f, "{}", self.to_js_string())
             }
         }
     }
@@ -4,7 +4,8 @@ use crate::parser::parser::Parser;
 use crate::parser::symbols::{Expression, Statement, Operator, AssignOp};
 use crate::parser::symbols::JSItem;
 use crate::lexer::js_token::Tok;
-use std::collections::HashMap;
+use crate::lexer::span::Span;
+use crate::parser::property_map::PropertyMap;
 use crate::parser::symbols::JSItem::Ex;
 
 #[test]
@@ -12,32 +13,34 @@ fn test_number_array() {
     let file = fs::read_to_string("js/arrays/number_array.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
     assert!(function.eq(&JSItem::St {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Let,
-            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string()})},
+            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string(), span: Span::default()}), span: Span::default()},
             right: JSItem::Ex {expression: Box::new(Expression::ArrayExpression {
                 items: vec![
-                    JSItem::Ex { expression: Box::new(Expression::Number {value: 1.}) },
-                    JSItem::Ex { expression: Box::new(Expression::Number {value: 2.}) },
-                    JSItem::Ex { expression: Box::new(Expression::Number {value: 3.}) },
-                    JSItem::Ex { expression: Box::new(Expression::Number {value: 4.}) },
-                    JSItem::Ex { expression: Box::new(Expression::Number {value: 5.}) },
-                    JSItem::Ex { expression: Box::new(Expression::Number {value: 6.}) },
-                    JSItem::Ex { expression: Box::new(Expression::Number {value: 7.}) },
-                    JSItem::Ex { expression: Box::new(Expression::Number {value: 8.}) },
-                    JSItem::Ex { expression: Box::new(Expression::Number {value: 9.}) },
-                    JSItem::Ex { expression: Box::new(Expression::Number {value: 10.}) },
-                    JSItem::Ex { expression: Box::new(Expression::Number {value: 11.}) }
+                    JSItem::Ex { expression: Box::new(Expression::Number {value: 1., span: Span::default()}), span: Span::default() },
+                    JSItem::Ex { expression: Box::new(Expression::Number {value: 2., span: Span::default()}), span: Span::default() },
+                    JSItem::Ex { expression: Box::new(Expression::Number {value: 3., span: Span::default()}), span: Span::default() },
+                    JSItem::Ex { expression: Box::new(Expression::Number {value: 4., span: Span::default()}), span: Span::default() },
+                    JSItem::Ex { expression: Box::new(Expression::Number {value: 5., span: Span::default()}), span: Span::default() },
+                    JSItem::Ex { expression: Box::new(Expression::Number {value: 6., span: Span::default()}), span: Span::default() },
+                    JSItem::Ex { expression: Box::new(Expression::Number {value: 7., span: Span::default()}), span: Span::default() },
+                    JSItem::Ex { expression: Box::new(Expression::Number {value: 8., span: Span::default()}), span: Span::default() },
+                    JSItem::Ex { expression: Box::new(Expression::Number {value: 9., span: Span::default()}), span: Span::default() },
+                    JSItem::Ex { expression: Box::new(Expression::Number {value: 10., span: Span::default()}), span: Span::default() },
+                    JSItem::Ex { expression: Box::new(Expression::Number {value: 11., span: Span::default()}), span: Span::default() }
                 ],
-                properties: hashmap!{"length".to_string() => JSItem::Number{value: 11. }}
-            })}
-        })
+                properties: PropertyMap::from(vec![("length".to_string(), JSItem::Number{value: 11. })]),
+                span: Span::default()
+            }), span: Span::default()}
+        }),
+        span: Span::default()
     }))
 }
 
@@ -46,31 +49,35 @@ fn test_array_object_apply() {
     let file = fs::read_to_string("js/arrays/array_object_apply.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
     assert_eq!(js_items.len(), 1);
     let object = js_items.get(0).unwrap();
-    let mut object_properties = HashMap::new();
+    let mut object_properties = PropertyMap::new();
     object_properties.insert("length".to_string(), JSItem::Ex {
-        expression: Box::from(Expression::Number {value: 50.})
+        expression: Box::from(Expression::Number {value: 50., span: Span::default()}),
+        span: Span::default()
     });
     assert!(object.eq(&JSItem::St {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Const,
-            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "real_numbers".to_string()})},
+            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "real_numbers".to_string(), span: Span::default()}), span: Span::default()},
             right: JSItem::Ex {expression: Box::new(Expression::CallExpression {
                 callee: Box::new(Expression::MemberExpression {
                     object: Box::new(Expression::CallExpression {
                         callee: Box::new(Expression::MemberExpression {
-                            object: Box::new(Expression::Identifier {name: "Array".to_string()}),
-                            property: Box::new(Expression::Identifier {name: "apply".to_string()})
+                            object: Box::new(Expression::Identifier {name: "Array".to_string(), span: Span::default()}),
+                            property: Box::new(Expression::Identifier {name: "apply".to_string(), span: Span::default()}),
+                            span: Span::default()
                         }),
                         arguments: vec![
-                            JSItem::Ex {expression: Box::from(Expression::Null)},
+                            JSItem::Ex {expression: Box::from(Expression::Null { span: Span::default() }), span: Span::default()},
                             JSItem::Object {mutable: true, properties: object_properties}
-                        ]
+                        ],
+                        span: Span::default()
                     }),
-                    property: Box::new(Expression::Identifier {name: "map".to_string()})
+                    property: Box::new(Expression::Identifier {name: "map".to_string(), span: Span::default()}),
+                    span: Span::default()
                 }),
                 arguments: vec![JSItem::Ex {
                     expression: Box::from(Expression::FuncEx {
@@ -80,15 +87,21 @@ fn test_array_object_apply() {
                         ],
                         body: vec![JSItem::St {
                             statement: Box::from(Statement::Return {
-                                value: Box::new(JSItem::Ex {
-                                    expression: Box::new(Expression::Identifier {name: "current".to_string()})
-                                })
-                            })
-                        }]
-                    })
-                }]
-            })}
-        })
+                                value: Some(Box::new(JSItem::Ex {
+                                    expression: Box::new(Expression::Identifier {name: "current".to_string(), span: Span::default()}),
+                                    span: Span::default()
+                                }))
+                            }),
+                            span: Span::default()
+                        }],
+                        span: Span::default()
+                    }),
+                    span: Span::default()
+                }],
+                span: Span::default()
+            }), span: Span::default()}
+        }),
+        span: Span::default()
     }));
 }
 
@@ -98,8 +111,8 @@ fn test_array_push() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 3)
 }
@@ -110,8 +123,8 @@ fn test_var_array() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
 
@@ -120,28 +133,135 @@ fn test_var_array() {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Let,
             left: JSItem::Ex {
-                expression: Box::new(Expression::Literal {value: "a".to_string()})
+                expression: Box::new(Expression::Literal {value: "a".to_string(), span: Span::default()}),
+                span: Span::default()
             },
             right: JSItem::Ex {
                 expression: Box::new(Expression::ArrayExpression {
                     items: vec![
-                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "b".to_string()})},
-                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "c".to_string()})},
-                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "d".to_string()})},
-                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "e".to_string()})},
-                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "f".to_string()})},
-                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "g".to_string()})},
-                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "h".to_string()})},
-                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "i".to_string()})},
-                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "j".to_string()})},
-                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "k".to_string()})},
-                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "l".to_string()})},
+                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}), span: Span::default()},
+                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "c".to_string(), span: Span::default()}), span: Span::default()},
+                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "d".to_string(), span: Span::default()}), span: Span::default()},
+                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "e".to_string(), span: Span::default()}), span: Span::default()},
+                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "f".to_string(), span: Span::default()}), span: Span::default()},
+                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "g".to_string(), span: Span::default()}), span: Span::default()},
+                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "h".to_string(), span: Span::default()}), span: Span::default()},
+                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "i".to_string(), span: Span::default()}), span: Span::default()},
+                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "j".to_string(), span: Span::default()}), span: Span::default()},
+                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "k".to_string(), span: Span::default()}), span: Span::default()},
+                        JSItem::Ex {expression: Box::new(Expression::Identifier {name: "l".to_string(), span: Span::default()}), span: Span::default()},
                     ],
-                    properties: hashmap!{
-                        "length".to_string() => JSItem::Number{value: 11.0}
-                    }
-                })
+                    properties: PropertyMap::from(vec![("length".to_string(), JSItem::Number{value: 11.0})]),
+                    span: Span::default()
+                }),
+                span: Span::default()
             }
-        })
+        }),
+        span: Span::default()
     }));
-}
\ No newline at end of file
+}
+#[test]
+fn test_array_object_value() {
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex("let o = {a: [1, 2]}\n".to_string()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    assert_eq!(js_items.len(), 1);
+    assert!(js_items.get(0).unwrap().eq(&JSItem::St {
+        statement: Box::new(Statement::AssignmentExpression {
+            operator: AssignOp::Let,
+            left: JSItem::Ex { expression: Box::from(Expression::Literal { value: "o".to_string(), span: Span::default() }), span: Span::default() },
+            right: JSItem::Object {
+                mutable: true,
+                properties: PropertyMap::from(vec![
+                    ("a".to_string(), JSItem::Ex { expression: Box::new(Expression::ArrayExpression {
+                        items: vec![
+                            JSItem::Ex { expression: Box::new(Expression::Number { value: 1., span: Span::default() }), span: Span::default() },
+                            JSItem::Ex { expression: Box::new(Expression::Number { value: 2., span: Span::default() }), span: Span::default() }
+                        ],
+                        properties: PropertyMap::from(vec![("length".to_string(), JSItem::Number { value: 2. })]),
+                        span: Span::default()
+                    }), span: Span::default() })
+                ])
+            }
+        }),
+        span: Span::default()
+    }))
+}
+
+#[test]
+fn test_array_member() {
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex("let n = [1, 2].length\n".to_string()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    assert_eq!(js_items.len(), 1);
+    assert!(js_items.get(0).unwrap().eq(&JSItem::St {
+        statement: Box::new(Statement::AssignmentExpression {
+            operator: AssignOp::Let,
+            left: JSItem::Ex { expression: Box::from(Expression::Literal { value: "n".to_string(), span: Span::default() }), span: Span::default() },
+            right: JSItem::Ex {
+                expression: Box::new(Expression::MemberExpression {
+                    object: Box::new(Expression::ArrayExpression {
+                        items: vec![
+                            JSItem::Ex { expression: Box::new(Expression::Number { value: 1., span: Span::default() }), span: Span::default() },
+                            JSItem::Ex { expression: Box::new(Expression::Number { value: 2., span: Span::default() }), span: Span::default() }
+                        ],
+                        properties: PropertyMap::from(vec![("length".to_string(), JSItem::Number { value: 2. })]),
+                        span: Span::default()
+                    }),
+                    property: Box::new(Expression::Identifier { name: "length".to_string(), span: Span::default() }),
+                    span: Span::default()
+                }),
+                span: Span::default()
+            }
+        }),
+        span: Span::default()
+    }))
+}
+
+#[test]
+fn test_array_statement() {
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex("[1, 2]\n".to_string()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    assert_eq!(js_items.len(), 1);
+    assert!(js_items.get(0).unwrap().eq(&JSItem::Ex { expression: Box::new(Expression::ArrayExpression {
+        items: vec![
+            JSItem::Ex { expression: Box::new(Expression::Number { value: 1., span: Span::default() }), span: Span::default() },
+            JSItem::Ex { expression: Box::new(Expression::Number { value: 2., span: Span::default() }), span: Span::default() }
+        ],
+        properties: PropertyMap::from(vec![("length".to_string(), JSItem::Number { value: 2. })]),
+        span: Span::default()
+    }), span: Span::default() }))
+}
+
+#[test]
+fn test_array_template_hole() {
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex("`${[1,2]}`\n".to_string()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    assert_eq!(js_items.len(), 1);
+    assert!(js_items.get(0).unwrap().eq(&JSItem::Ex {
+        expression: Box::new(Expression::TemplateLiteral {
+            cooked: vec!["".to_string(), "".to_string()],
+            raw: vec!["".to_string(), "".to_string()],
+            expressions: vec![JSItem::Ex { expression: Box::new(Expression::ArrayExpression {
+                items: vec![
+                    JSItem::Ex { expression: Box::new(Expression::Number { value: 1., span: Span::default() }), span: Span::default() },
+                    JSItem::Ex { expression: Box::new(Expression::Number { value: 2., span: Span::default() }), span: Span::default() }
+                ],
+                properties: PropertyMap::from(vec![("length".to_string(), JSItem::Number { value: 2. })]),
+                span: Span::default()
+            }), span: Span::default() }],
+            span: Span::default()
+        }),
+        span: Span::default()
+    }))
+}
@@ -0,0 +1,119 @@
+use std::fs;
+use crate::lexer::lexer::Lexer;
+use crate::lexer::js_token::{Tok, Token};
+use crate::parser::parser::Parser;
+use crate::parser::asi::insert_semicolons;
+use crate::parser::symbols::{JSItem, Statement};
+use crate::parser::tests::parse;
+
+#[cfg(test)]
+fn asi(source: &str) -> Vec<Token> {
+    let mut lex = Lexer::new();
+    insert_semicolons(lex.lex(String::from(source)).unwrap())
+}
+
+#[cfg(test)]
+fn name(name: &str) -> Tok {
+    Tok::Name { name: String::from(name) }
+}
+
+#[test]
+fn test_newline_between_statements() {
+    assert_eq!(asi("let a = 1\nlet b = 2"), vec![
+        Tok::Let, name("a"), Tok::Equal, Tok::Float { value: 1. }, Tok::Semi, Tok::EndOfLine,
+        Tok::Let, name("b"), Tok::Equal, Tok::Float { value: 2. }
+    ]);
+    assert_eq!(asi("a = 1;\nb = 2"), vec![
+        name("a"), Tok::Equal, Tok::Float { value: 1. }, Tok::Semi, Tok::EndOfLine,
+        name("b"), Tok::Equal, Tok::Float { value: 2. }
+    ]);
+}
+
+#[test]
+fn test_expression_continues_on_next_line() {
+    assert_eq!(asi("a = b\n  + c"), vec![name("a"), Tok::Equal, name("b"), Tok::Plus, name("c")]);
+    assert_eq!(asi("a =\n  b"), vec![name("a"), Tok::Equal, name("b")]);
+    assert_eq!(asi("a\n  .b\n  ?.c"), vec![name("a"), Tok::Dot, name("b"), Tok::QuestionDot, name("c")]);
+    assert_eq!(asi("f(\n  a,\n  b\n)"), vec![
+        name("f"), Tok::Lpar, name("a"), Tok::Comma, name("b"), Tok::Rpar
+    ]);
+}
+
+#[test]
+fn test_no_semicolon_before_paren_bracket_or_template() {
+    assert_eq!(asi("a = b\n(c)"), vec![name("a"), Tok::Equal, name("b"), Tok::Lpar, name("c"), Tok::Rpar]);
+    assert_eq!(asi("a = b\n[c]"), vec![name("a"), Tok::Equal, name("b"), Tok::Lsqb, name("c"), Tok::Rsqb]);
+    assert_eq!(asi("a = b\n`c`"), vec![
+        name("a"), Tok::Equal, name("b"), Tok::NoSubstitutionTemplate { cooked: String::from("c"), raw: String::from("c") }
+    ]);
+}
+
+#[test]
+fn test_restricted_productions() {
+    assert_eq!(asi("return\na + b"), vec![
+        Tok::Return, Tok::Semi, Tok::EndOfLine, name("a"), Tok::Plus, name("b")
+    ]);
+    assert_eq!(asi("throw\ne"), vec![Tok::Throw, Tok::Semi, Tok::EndOfLine, name("e")]);
+    assert_eq!(asi("break\nouter"), vec![Tok::Break, Tok::Semi, Tok::EndOfLine, name("outer")]);
+    assert_eq!(asi("continue\nouter"), vec![Tok::Continue, Tok::Semi, Tok::EndOfLine, name("outer")]);
+}
+
+#[test]
+fn test_postfix_across_lines() {
+    assert_eq!(asi("a\n++b"), vec![name("a"), Tok::Semi, Tok::EndOfLine, Tok::PlusPlus, name("b")]);
+    assert_eq!(asi("a\n--\nb"), vec![name("a"), Tok::Semi, Tok::EndOfLine, Tok::MinusMinus, name("b")]);
+    assert_eq!(asi("a++\nb"), vec![name("a"), Tok::PlusPlus, Tok::Semi, Tok::EndOfLine, name("b")]);
+    assert_eq!(asi("--a\nb"), vec![Tok::MinusMinus, name("a"), Tok::Semi, Tok::EndOfLine, name("b")]);
+}
+
+#[test]
+fn test_blocks_and_object_literals() {
+    assert_eq!(asi("if (a)\nb()\nelse {\nc()\n}\n(d)"), vec![
+        Tok::If, Tok::Lpar, name("a"), Tok::Rpar, name("b"), Tok::Lpar, Tok::Rpar, Tok::Semi, Tok::EndOfLine,
+        Tok::Else, Tok::Lbrace, name("c"), Tok::Lpar, Tok::Rpar, Tok::Semi, Tok::EndOfLine, Tok::Rbrace,
+        Tok::EndOfLine, Tok::Lpar, name("d"), Tok::Rpar
+    ]);
+    assert_eq!(asi("let o = {\n  a: 1\n}\n(f)"), vec![
+        Tok::Let, name("o"), Tok::Equal, Tok::Lbrace, name("a"), Tok::Colon, Tok::Float { value: 1. },
+        Tok::Rbrace, Tok::Lpar, name("f"), Tok::Rpar
+    ]);
+    assert_eq!(asi("let f = function () {\n}\nf()"), vec![
+        Tok::Let, name("f"), Tok::Equal, Tok::Function, Tok::Lpar, Tok::Rpar, Tok::Lbrace, Tok::Rbrace,
+        Tok::Semi, Tok::EndOfLine, name("f"), Tok::Lpar, Tok::Rpar
+    ]);
+    assert_eq!(asi("do x++\nwhile (x < 5)\nx"), vec![
+        Tok::Do, name("x"), Tok::PlusPlus, Tok::Semi, Tok::EndOfLine, Tok::While, Tok::Lpar, name("x"),
+        Tok::Less, Tok::Float { value: 5. }, Tok::Rpar, Tok::Semi, Tok::EndOfLine, name("x")
+    ]);
+}
+
+#[test]
+fn test_insert_semicolons_twice() {
+    let tokens = asi("const a = 1\nlet b = {\n  c: a\n}\nfunction f () {\n  return\n}\n");
+    assert_eq!(insert_semicolons(tokens.clone()), tokens);
+}
+
+#[test]
+fn test_standard_style_parses_like_semicolons() {
+    let mut lex = Lexer::new();
+    let standard = Parser::new().parse(lex.lex(fs::read_to_string("js/asi/standard.js").unwrap()).unwrap()).unwrap();
+    let mut lex = Lexer::new();
+    let semi = Parser::new().parse(lex.lex(fs::read_to_string("js/asi/standard_semi.js").unwrap()).unwrap()).unwrap();
+    assert_eq!(standard, semi);
+}
+
+#[test]
+fn test_return_ends_at_line_end() {
+    let items = parse("return\nf()");
+    assert_eq!(items.len(), 2);
+    match &items[0] {
+        JSItem::St { statement, .. } => assert_eq!(**statement, Statement::Return { value: None }),
+        item => panic!("expected a return statement, got {:?}", item)
+    }
+}
+
+#[test]
+fn test_throw_needs_a_value_on_the_same_line() {
+    let tokens = Lexer::new().lex(String::from("throw\ne")).unwrap();
+    assert!(Parser::new().parse(tokens).is_err());
+}
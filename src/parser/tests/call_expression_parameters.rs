@@ -4,46 +4,58 @@ use crate::parser::parser::Parser;
 use crate::parser::symbols::{Expression, Statement, Operator, AssignOp};
 use crate::parser::symbols::JSItem;
 use crate::lexer::js_token::Tok;
+use crate::lexer::span::Span;
+use crate::parser::property_map::PropertyMap;
 
 #[test]
 fn test_exp_params() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex("console.log((1 + 2), (2 + 2), 3)".to_string());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex("console.log((1 + 2), (2 + 2), 3)".to_string()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
     assert!(function.eq(&JSItem::Ex {
         expression: Box::new(Expression::CallExpression {
             callee: Box::new(Expression::MemberExpression {
-                object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                property: Box::new(Expression::Identifier { name: "log".to_string() }),
+                object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                property: Box::new(Expression::Identifier { name: "log".to_string(), span: Span::default() }),
+                span: Span::default()
             }),
             arguments: vec![
                 JSItem::Ex {
                     expression: Box::new(Expression::SubExpression {
                         expression: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Number { value: 1. }),
+                            a: Box::new(Expression::Number { value: 1., span: Span::default() }),
                             op: Operator::Add,
-                            b: Box::new(Expression::Number { value: 2. }),
-                        })
-                    })
+                            b: Box::new(Expression::Number { value: 2., span: Span::default() }),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
+                    }),
+                    span: Span::default()
                 },
                 JSItem::Ex {
                     expression: Box::new(Expression::SubExpression {
                         expression: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Number { value: 2. }),
+                            a: Box::new(Expression::Number { value: 2., span: Span::default() }),
                             op: Operator::Add,
-                            b: Box::new(Expression::Number { value: 2. }),
-                        })
-                    })
+                            b: Box::new(Expression::Number { value: 2., span: Span::default() }),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
+                    }),
+                    span: Span::default()
                 },
                 JSItem::Ex {
-                    expression: Box::new(Expression::Number { value: 3. })
+                    expression: Box::new(Expression::Number { value: 3., span: Span::default() }),
+                    span: Span::default()
                 }
             ],
-        })
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
 }
 
@@ -51,27 +63,32 @@ fn test_exp_params() {
 fn test_var_plus_number() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex("console.log(a + 2)".to_string());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex("console.log(a + 2)".to_string()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
     assert!(function.eq(&JSItem::Ex {
         expression: Box::new(Expression::CallExpression {
             callee: Box::new(Expression::MemberExpression {
-                object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                property: Box::new(Expression::Identifier { name: "log".to_string() }),
+                object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                property: Box::new(Expression::Identifier { name: "log".to_string(), span: Span::default() }),
+                span: Span::default()
             }),
             arguments: vec![
                 JSItem::Ex {
                     expression: Box::new(Expression::Binop {
-                        a: Box::new(Expression::Identifier { name: "a".to_string() }),
+                        a: Box::new(Expression::Identifier { name: "a".to_string(), span: Span::default() }),
                         op: Operator::Add,
-                        b: Box::new(Expression::Number { value: 2. }),
-                    })
+                        b: Box::new(Expression::Number { value: 2., span: Span::default() }),
+                        span: Span::default()
+                    }),
+                    span: Span::default()
                 }
             ],
-        })
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
 }
 
@@ -79,30 +96,65 @@ fn test_var_plus_number() {
 fn test_var_plus_space_plus_var() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex("console.log(a + \" \" + b)".to_string());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex("console.log(a + \" \" + b)".to_string()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
     assert!(function.eq(&JSItem::Ex {
         expression: Box::new(Expression::CallExpression {
             callee: Box::new(Expression::MemberExpression {
-                object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                property: Box::new(Expression::Identifier { name: "log".to_string() }),
+                object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                property: Box::new(Expression::Identifier { name: "log".to_string(), span: Span::default() }),
+                span: Span::default()
             }),
             arguments: vec![
                 JSItem::Ex {
                     expression: Box::new(Expression::Binop {
                         a: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Identifier {name: "a".to_string()}),
+                            a: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
                             op: Operator::Add,
-                            b: Box::new(Expression::Literal {value: " ".to_string()})
+                            b: Box::new(Expression::Literal {value: " ".to_string(), span: Span::default()}),
+                            span: Span::default()
                         }),
                         op: Operator::Add,
-                        b: Box::new(Expression::Identifier {name: "b".to_string()}),
-                    })
+                        b: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
+                        span: Span::default()
+                    }),
+                    span: Span::default()
                 }
             ],
-        })
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
-}
\ No newline at end of file
+}
+#[test]
+fn test_array_params() {
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex("f([1, 2])".to_string()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    assert_eq!(js_items.len(), 1);
+    assert!(js_items.get(0).unwrap().eq(&JSItem::Ex {
+        expression: Box::new(Expression::CallExpression {
+            callee: Box::new(Expression::Identifier { name: "f".to_string(), span: Span::default() }),
+            arguments: vec![
+                JSItem::Ex {
+                    expression: Box::new(Expression::ArrayExpression {
+                        items: vec![
+                            JSItem::Ex { expression: Box::new(Expression::Number { value: 1., span: Span::default() }), span: Span::default() },
+                            JSItem::Ex { expression: Box::new(Expression::Number { value: 2., span: Span::default() }), span: Span::default() }
+                        ],
+                        properties: PropertyMap::from(vec![("length".to_string(), JSItem::Number { value: 2. })]),
+                        span: Span::default()
+                    }),
+                    span: Span::default()
+                }
+            ],
+            span: Span::default()
+        }),
+        span: Span::default()
+    }))
+}
@@ -4,6 +4,7 @@ use crate::parser::parser::Parser;
 use crate::parser::symbols::{Expression, Statement, Operator, AssignOp};
 use crate::parser::symbols::JSItem;
 use crate::lexer::js_token::Tok;
+use crate::lexer::span::Span;
 
 #[test]
 fn test_for() {
@@ -11,8 +12,8 @@ fn test_for() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
 
 
@@ -23,34 +24,46 @@ fn test_for() {
             init: JSItem::St {
                 statement: Box::new(Statement::AssignmentExpression {
                     operator: AssignOp::Let,
-                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "i".to_string()})},
-                    right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0.})}
-                })
+                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "i".to_string(), span: Span::default()}), span: Span::default()},
+                    right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0., span: Span::default()}), span: Span::default()}
+                }),
+                span: Span::default()
             },
             test: JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "i".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "i".to_string(), span: Span::default()}),
                     op: Operator::Less,
-                    b: Box::new(Expression::Number {value: 10.})
-                })
+                    b: Box::new(Expression::Number {value: 10., span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             update: JSItem::Ex {
                 expression: Box::new(Expression::UpdateExpression {
-                    expression: Box::new(Expression::Identifier {name: "i".to_string()})
-                })
+                    operator: Operator::Add,
+                    prefix: false,
+                    expression: Box::new(Expression::Identifier {name: "i".to_string(), span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             body: vec![JSItem::Ex {
                 expression: Box::new(Expression::CallExpression {
                     callee: Box::new(Expression::MemberExpression {
-                        object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                        property: Box::new(Expression::Identifier {name: "log".to_string()})
+                        object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                        property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                        span: Span::default()
                     }),
                     arguments: vec![JSItem::Ex {
-                        expression: Box::new(Expression::Identifier {name: "i".to_string()})
-                    }]
-                })
+                        expression: Box::new(Expression::Identifier {name: "i".to_string(), span: Span::default()}),
+                        span: Span::default()
+                    }],
+                    span: Span::default()
+                }),
+                span: Span::default()
             }]
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -60,8 +73,8 @@ fn test_for_test_ident() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
@@ -70,34 +83,46 @@ fn test_for_test_ident() {
             init: JSItem::St {
                 statement: Box::new(Statement::AssignmentExpression {
                     operator: AssignOp::Let,
-                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "i".to_string()})},
-                    right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0.})}
-                })
+                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "i".to_string(), span: Span::default()}), span: Span::default()},
+                    right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0., span: Span::default()}), span: Span::default()}
+                }),
+                span: Span::default()
             },
             test: JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "i".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "i".to_string(), span: Span::default()}),
                     op: Operator::Less,
-                    b: Box::new(Expression::Identifier {name: "j".to_string()})
-                })
+                    b: Box::new(Expression::Identifier {name: "j".to_string(), span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             update: JSItem::Ex {
                 expression: Box::new(Expression::UpdateExpression {
-                    expression: Box::new(Expression::Identifier {name: "i".to_string()})
-                })
+                    operator: Operator::Add,
+                    prefix: false,
+                    expression: Box::new(Expression::Identifier {name: "i".to_string(), span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             body: vec![JSItem::Ex {
                 expression: Box::new(Expression::CallExpression {
                     callee: Box::new(Expression::MemberExpression {
-                        object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                        property: Box::new(Expression::Identifier {name: "log".to_string()})
+                        object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                        property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                        span: Span::default()
                     }),
                     arguments: vec![JSItem::Ex {
-                        expression: Box::new(Expression::Identifier {name: "i".to_string()})
-                    }]
-                })
+                        expression: Box::new(Expression::Identifier {name: "i".to_string(), span: Span::default()}),
+                        span: Span::default()
+                    }],
+                    span: Span::default()
+                }),
+                span: Span::default()
             }]
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -107,8 +132,8 @@ fn test_for_test_ident_plus_ident() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
@@ -117,43 +142,57 @@ fn test_for_test_ident_plus_ident() {
             init: JSItem::St {
                 statement: Box::new(Statement::AssignmentExpression {
                     operator: AssignOp::Let,
-                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "j".to_string()})},
-                    right: JSItem::Ex {expression: Box::from(Expression::Identifier {name: "a".to_string()})}
-                })
+                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "j".to_string(), span: Span::default()}), span: Span::default()},
+                    right: JSItem::Ex {expression: Box::from(Expression::Identifier {name: "a".to_string(), span: Span::default()}), span: Span::default()}
+                }),
+                span: Span::default()
             },
             test: JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "j".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "j".to_string(), span: Span::default()}),
                     op: Operator::Less,
                     b: Box::new(Expression::Binop {
-                        a: Box::new(Expression::Identifier { name: "cols".to_string() }),
+                        a: Box::new(Expression::Identifier { name: "cols".to_string(), span: Span::default() }),
                         op: Operator::Add,
-                        b: Box::new(Expression::Identifier {name: "a".to_string()})
-                    })
-                })
+                        b: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
+                        span: Span::default()
+                    }),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             update: JSItem::Ex {
                 expression: Box::new(Expression::UpdateExpression {
-                    expression: Box::new(Expression::Identifier {name: "j".to_string()})
-                })
+                    operator: Operator::Add,
+                    prefix: false,
+                    expression: Box::new(Expression::Identifier {name: "j".to_string(), span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             body: vec![JSItem::Ex {
                 expression: Box::new(Expression::CallExpression {
                     callee: Box::new(Expression::MemberExpression {
-                        object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                        property: Box::new(Expression::Identifier {name: "log".to_string()})
+                        object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                        property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                        span: Span::default()
                     }),
                     arguments: vec![
                         JSItem::Ex {
-                            expression: Box::new(Expression::Identifier {name: "i".to_string()})
+                            expression: Box::new(Expression::Identifier {name: "i".to_string(), span: Span::default()}),
+                            span: Span::default()
                         },
                         JSItem::Ex {
-                            expression: Box::new(Expression::Identifier {name: "j".to_string()})
+                            expression: Box::new(Expression::Identifier {name: "j".to_string(), span: Span::default()}),
+                            span: Span::default()
                         }
-                    ]
-                })
+                    ],
+                    span: Span::default()
+                }),
+                span: Span::default()
             }]
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -163,8 +202,8 @@ fn test_for_if() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
 
@@ -174,88 +213,114 @@ fn test_for_if() {
             init: JSItem::St {
                 statement: Box::new(Statement::AssignmentExpression {
                     operator: AssignOp::Let,
-                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "b".to_string()})},
-                    right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0.})}
-                })
+                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "b".to_string(), span: Span::default()}), span: Span::default()},
+                    right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0., span: Span::default()}), span: Span::default()}
+                }),
+                span: Span::default()
             },
             test: JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "b".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
                     op: Operator::Less,
-                    b: Box::new(Expression::Number {value: 20.})
-                })
+                    b: Box::new(Expression::Number {value: 20., span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             update: JSItem::Ex {
                 expression: Box::new(Expression::UpdateExpression {
-                    expression: Box::new(Expression::Identifier {name: "b".to_string()})
-                })
+                    operator: Operator::Add,
+                    prefix: false,
+                    expression: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             body: vec![JSItem::St {
                 statement: Box::new(Statement::If {
                     test: JSItem::Ex {
                         expression: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Identifier {name: "b".to_string()}),
+                            a: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
                             op: Operator::Less,
-                            b: Box::new(Expression::Number {value: 5.})
-                        })
+                            b: Box::new(Expression::Number {value: 5., span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     },
                     consequent: vec![JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
-                            arguments: vec![JSItem::Ex { expression: Box::new(Expression::String {value: "less than 5".to_string()}) }]
-                        })
+                            arguments: vec![JSItem::Ex { expression: Box::new(Expression::String {value: "less than 5".to_string(), span: Span::default()}), span: Span::default() }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }],
                     alternate: JSItem::St {
                         statement: Box::new(Statement::If {
                             test: JSItem::Ex {
                                 expression: Box::new(Expression::Binop {
                                     a: Box::new(Expression::Binop {
-                                        a: Box::new(Expression::Identifier {name: "b".to_string()}),
+                                        a: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
                                         op: Operator::Greater,
-                                        b: Box::new(Expression::Number {value: 5.})
+                                        b: Box::new(Expression::Number {value: 5., span: Span::default()}),
+                                        span: Span::default()
                                     }),
                                     op: Operator::And,
                                     b: Box::new(Expression::Binop {
-                                        a: Box::new(Expression::Identifier {name: "b".to_string()}),
+                                        a: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
                                         op: Operator::Less,
-                                        b: Box::new(Expression::Number {value: 10.})
-                                    })
-                                })
+                                        b: Box::new(Expression::Number {value: 10., span: Span::default()}),
+                                        span: Span::default()
+                                    }),
+                                    span: Span::default()
+                                }),
+                                span: Span::default()
                             },
                             consequent: vec![JSItem::Ex {
                                 expression: Box::new(Expression::CallExpression {
                                     callee: Box::new(Expression::MemberExpression {
-                                        object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                        property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                        object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                        property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                        span: Span::default()
                                     }),
-                                    arguments: vec![JSItem::Ex { expression: Box::new(Expression::String {value: "between 5 and 10".to_string()}) }]
-                                })
+                                    arguments: vec![JSItem::Ex { expression: Box::new(Expression::String {value: "between 5 and 10".to_string(), span: Span::default()}), span: Span::default() }],
+                                    span: Span::default()
+                                }),
+                                span: Span::default()
                             }],
                             alternate: JSItem::St { statement: Box::new(Statement::If {
                                 test: JSItem::Ex { expression: Box::new(Expression::Binop {
-                                    a: Box::new(Expression::Identifier {name: "b".to_string()}),
+                                    a: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
                                     op: Operator::Greater,
-                                    b: Box::new(Expression::Number {value: 10.})
-                                }) },
+                                    b: Box::new(Expression::Number {value: 10., span: Span::default()}),
+                                    span: Span::default()
+                                }), span: Span::default() },
                                 consequent: vec![JSItem::Ex {
                                     expression: Box::new(Expression::CallExpression {
                                         callee: Box::new(Expression::MemberExpression {
-                                            object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                            property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                            object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                            property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                            span: Span::default()
                                         }),
-                                        arguments: vec![JSItem::Ex { expression: Box::new(Expression::String {value: "greater than 10".to_string()}) }]
-                                    })
+                                        arguments: vec![JSItem::Ex { expression: Box::new(Expression::String {value: "greater than 10".to_string(), span: Span::default()}), span: Span::default() }],
+                                        span: Span::default()
+                                    }),
+                                    span: Span::default()
                                 }],
-                                alternate: JSItem::Ex {expression: Box::from(Expression::None)}
-                            }) }
-                        })
+                                alternate: JSItem::Ex {expression: Box::from(Expression::None), span: Span::default()}
+                            }), span: Span::default() }
+                        }),
+                        span: Span::default()
                     }
-                })
+                }),
+                span: Span::default()
             }]
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -265,8 +330,8 @@ fn test_for_if1() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
 
@@ -276,88 +341,114 @@ fn test_for_if1() {
             init: JSItem::St {
                 statement: Box::new(Statement::AssignmentExpression {
                     operator: AssignOp::Let,
-                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "b".to_string()})},
-                    right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0.})}
-                })
+                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "b".to_string(), span: Span::default()}), span: Span::default()},
+                    right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0., span: Span::default()}), span: Span::default()}
+                }),
+                span: Span::default()
             },
             test: JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "b".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
                     op: Operator::Less,
-                    b: Box::new(Expression::Number {value: 20.})
-                })
+                    b: Box::new(Expression::Number {value: 20., span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             update: JSItem::Ex {
                 expression: Box::new(Expression::UpdateExpression {
-                    expression: Box::new(Expression::Identifier {name: "b".to_string()})
-                })
+                    operator: Operator::Add,
+                    prefix: false,
+                    expression: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             body: vec![JSItem::St {
                 statement: Box::new(Statement::If {
                     test: JSItem::Ex {
                         expression: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Identifier {name: "b".to_string()}),
+                            a: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
                             op: Operator::Less,
-                            b: Box::new(Expression::Number {value: 5.})
-                        })
+                            b: Box::new(Expression::Number {value: 5., span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     },
                     consequent: vec![JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
-                            arguments: vec![JSItem::Ex { expression: Box::new(Expression::String {value: "less than 5".to_string()}) }]
-                        })
+                            arguments: vec![JSItem::Ex { expression: Box::new(Expression::String {value: "less than 5".to_string(), span: Span::default()}), span: Span::default() }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }],
                     alternate: JSItem::St {
                         statement: Box::new(Statement::If {
                             test: JSItem::Ex {
                                 expression: Box::new(Expression::Binop {
                                     a: Box::new(Expression::Binop {
-                                        a: Box::new(Expression::Number {value: 5.}),
+                                        a: Box::new(Expression::Number {value: 5., span: Span::default()}),
                                         op: Operator::Less,
-                                        b: Box::new(Expression::Identifier {name: "b".to_string()})
+                                        b: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
+                                        span: Span::default()
                                     }),
                                     op: Operator::And,
                                     b: Box::new(Expression::Binop {
-                                        a: Box::new(Expression::Identifier {name: "b".to_string()}),
+                                        a: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
                                         op: Operator::Less,
-                                        b: Box::new(Expression::Number {value: 10.})
-                                    })
-                                })
+                                        b: Box::new(Expression::Number {value: 10., span: Span::default()}),
+                                        span: Span::default()
+                                    }),
+                                    span: Span::default()
+                                }),
+                                span: Span::default()
                             },
                             consequent: vec![JSItem::Ex {
                                 expression: Box::new(Expression::CallExpression {
                                     callee: Box::new(Expression::MemberExpression {
-                                        object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                        property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                        object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                        property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                        span: Span::default()
                                     }),
-                                    arguments: vec![JSItem::Ex { expression: Box::new(Expression::String {value: "between 5 and 10".to_string()}) }]
-                                })
+                                    arguments: vec![JSItem::Ex { expression: Box::new(Expression::String {value: "between 5 and 10".to_string(), span: Span::default()}), span: Span::default() }],
+                                    span: Span::default()
+                                }),
+                                span: Span::default()
                             }],
                             alternate: JSItem::St { statement: Box::new(Statement::If {
                                 test: JSItem::Ex { expression: Box::new(Expression::Binop {
-                                    a: Box::new(Expression::Identifier {name: "b".to_string()}),
+                                    a: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
                                     op: Operator::Greater,
-                                    b: Box::new(Expression::Number {value: 10.})
-                                }) },
+                                    b: Box::new(Expression::Number {value: 10., span: Span::default()}),
+                                    span: Span::default()
+                                }), span: Span::default() },
                                 consequent: vec![JSItem::Ex {
                                     expression: Box::new(Expression::CallExpression {
                                         callee: Box::new(Expression::MemberExpression {
-                                            object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                            property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                            object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                            property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                            span: Span::default()
                                         }),
-                                        arguments: vec![JSItem::Ex { expression: Box::new(Expression::String {value: "greater than 10".to_string()}) }]
-                                    })
+                                        arguments: vec![JSItem::Ex { expression: Box::new(Expression::String {value: "greater than 10".to_string(), span: Span::default()}), span: Span::default() }],
+                                        span: Span::default()
+                                    }),
+                                    span: Span::default()
                                 }],
-                                alternate: JSItem::Ex {expression: Box::from(Expression::None)}
-                            }) }
-                        })
+                                alternate: JSItem::Ex {expression: Box::from(Expression::None), span: Span::default()}
+                            }), span: Span::default() }
+                        }),
+                        span: Span::default()
                     }
-                })
+                }),
+                span: Span::default()
             }]
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -367,8 +458,8 @@ fn test_for_div_nan1() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
 
@@ -378,61 +469,82 @@ fn test_for_div_nan1() {
             init: JSItem::St {
                 statement: Box::new(Statement::AssignmentExpression {
                     operator: AssignOp::Let,
-                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string()})},
-                    right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0.})}
-                })
+                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string(), span: Span::default()}), span: Span::default()},
+                    right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0., span: Span::default()}), span: Span::default()}
+                }),
+                span: Span::default()
             },
             test: JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "a".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
                     op: Operator::Less,
-                    b: Box::new(Expression::Number {value: 3.})
-                })
+                    b: Box::new(Expression::Number {value: 3., span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             update: JSItem::Ex {
                 expression: Box::new(Expression::UpdateExpression {
-                    expression: Box::new(Expression::Identifier {name: "a".to_string()})
-                })
+                    operator: Operator::Add,
+                    prefix: false,
+                    expression: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             body: vec![JSItem::St {
                 statement: Box::new(Statement::ForStatement {
                     init: JSItem::St {
                         statement: Box::new(Statement::AssignmentExpression {
                             operator: AssignOp::Let,
-                            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "b".to_string()})},
-                            right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0.})}
-                        })
+                            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "b".to_string(), span: Span::default()}), span: Span::default()},
+                            right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0., span: Span::default()}), span: Span::default()}
+                        }),
+                        span: Span::default()
                     },
                     test: JSItem::Ex {
                         expression: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Identifier {name: "b".to_string()}),
+                            a: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
                             op: Operator::Less,
-                            b: Box::new(Expression::Number {value: 3.})
-                        })
+                            b: Box::new(Expression::Number {value: 3., span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     },
                     update: JSItem::Ex {
                         expression: Box::new(Expression::UpdateExpression {
-                            expression: Box::new(Expression::Identifier {name: "b".to_string()})
-                        })
+                            operator: Operator::Add,
+                            prefix: false,
+                            expression: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     },
                     body: vec![JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
                             arguments: vec![JSItem::Ex {
                                 expression: Box::new(Expression::Binop {
-                                    a: Box::new(Expression::Identifier {name: "a".to_string()}),
+                                    a: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
                                     op: Operator::Div,
-                                    b: Box::new(Expression::Identifier {name: "b".to_string()})
-                                })
-                            }]
-                        })
+                                    b: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
+                                    span: Span::default()
+                                }),
+                                span: Span::default()
+                            }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }]
-                })
+                }),
+                span: Span::default()
             }]
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -442,8 +554,8 @@ fn test_nested_for_mul() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
 
@@ -453,61 +565,82 @@ fn test_nested_for_mul() {
             init: JSItem::St {
                 statement: Box::new(Statement::AssignmentExpression {
                     operator: AssignOp::Let,
-                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string()})},
-                    right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0.})}
-                })
+                    left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string(), span: Span::default()}), span: Span::default()},
+                    right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0., span: Span::default()}), span: Span::default()}
+                }),
+                span: Span::default()
             },
             test: JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "a".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
                     op: Operator::Less,
-                    b: Box::new(Expression::Number {value: 3.})
-                })
+                    b: Box::new(Expression::Number {value: 3., span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             update: JSItem::Ex {
                 expression: Box::new(Expression::UpdateExpression {
-                    expression: Box::new(Expression::Identifier {name: "a".to_string()})
-                })
+                    operator: Operator::Add,
+                    prefix: false,
+                    expression: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             body: vec![JSItem::St {
                 statement: Box::new(Statement::ForStatement {
                     init: JSItem::St {
                         statement: Box::new(Statement::AssignmentExpression {
                             operator: AssignOp::Let,
-                            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "b".to_string()})},
-                            right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0.})}
-                        })
+                            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "b".to_string(), span: Span::default()}), span: Span::default()},
+                            right: JSItem::Ex {expression: Box::from(Expression::Number {value: 0., span: Span::default()}), span: Span::default()}
+                        }),
+                        span: Span::default()
                     },
                     test: JSItem::Ex {
                         expression: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Identifier {name: "b".to_string()}),
+                            a: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
                             op: Operator::Less,
-                            b: Box::new(Expression::Number {value: 3.})
-                        })
+                            b: Box::new(Expression::Number {value: 3., span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     },
                     update: JSItem::Ex {
                         expression: Box::new(Expression::UpdateExpression {
-                            expression: Box::new(Expression::Identifier {name: "b".to_string()})
-                        })
+                            operator: Operator::Add,
+                            prefix: false,
+                            expression: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     },
                     body: vec![JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
                             arguments: vec![JSItem::Ex {
                                 expression: Box::new(Expression::Binop {
-                                    a: Box::new(Expression::Identifier {name: "a".to_string()}),
+                                    a: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
                                     op: Operator::Mult,
-                                    b: Box::new(Expression::Identifier {name: "b".to_string()})
-                                })
-                            }]
-                        })
+                                    b: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
+                                    span: Span::default()
+                                }),
+                                span: Span::default()
+                            }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }]
-                })
+                }),
+                span: Span::default()
             }]
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -1,6 +1,7 @@
 use std::fs;
 use crate::lexer::lexer::Lexer;
 use crate::lexer::js_token::Tok;
+use crate::lexer::span::Span;
 use crate::parser::parser::Parser;
 use crate::parser::symbols::{JSItem, Statement, AssignOp, Expression, Operator};
 
@@ -10,8 +11,8 @@ fn test_if1() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 2);
     let assign = js_items.get(0).unwrap();
@@ -20,36 +21,46 @@ fn test_if1() {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Const,
             left: JSItem::Ex {
-                expression: Box::new(Expression::Literal { value: "x".to_string() })
+                expression: Box::new(Expression::Literal { value: "x".to_string(), span: Span::default() }),
+                span: Span::default()
             },
             right: JSItem::Ex {
-                expression: Box::new(Expression::Number { value: 5. })
+                expression: Box::new(Expression::Number { value: 5., span: Span::default() }),
+                span: Span::default()
             }
-        })
+        }),
+        span: Span::default()
     }));
 
     assert!(ist.eq(&JSItem::St {
         statement: Box::new(Statement::If {
             test: JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "x".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "x".to_string(), span: Span::default()}),
                     op: Operator::Greater,
-                    b: Box::new(Expression::Number {value: 2.})
-                })
+                    b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             consequent: vec![JSItem::Ex {
                 expression: Box::new(Expression::CallExpression {
                     callee: Box::new(Expression::MemberExpression {
-                        object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                        property: Box::new(Expression::Identifier {name: "log".to_string()})
+                        object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                        property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                        span: Span::default()
                     }),
                     arguments: vec![JSItem::Ex {
-                        expression: Box::new(Expression::String {value: "hi".to_string()})
-                    }]
-                })
+                        expression: Box::new(Expression::String {value: "hi".to_string(), span: Span::default()}),
+                        span: Span::default()
+                    }],
+                    span: Span::default()
+                }),
+                span: Span::default()
             }],
-            alternate: JSItem::Ex { expression: Box::from(Expression::None)}
-        })
+            alternate: JSItem::Ex { expression: Box::from(Expression::None), span: Span::default()}
+        }),
+        span: Span::default()
     }));
 }
 
@@ -59,8 +70,8 @@ fn test_if2() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 2);
     let assign = js_items.get(0).unwrap();
@@ -70,68 +81,89 @@ fn test_if2() {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Const,
             left: JSItem::Ex {
-                expression: Box::new(Expression::Literal { value: "x".to_string() })
+                expression: Box::new(Expression::Literal { value: "x".to_string(), span: Span::default() }),
+                span: Span::default()
             },
             right: JSItem::Ex {
-                expression: Box::new(Expression::Number { value: 5. })
+                expression: Box::new(Expression::Number { value: 5., span: Span::default() }),
+                span: Span::default()
             }
-        })
+        }),
+        span: Span::default()
     }));
 
     assert!(ist.eq(&JSItem::St {
         statement: Box::new(Statement::If {
             test: JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "x".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "x".to_string(), span: Span::default()}),
                     op: Operator::Greater,
-                    b: Box::new(Expression::Number {value: 2.})
-                })
+                    b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             consequent: vec![JSItem::Ex {
                 expression: Box::new(Expression::CallExpression {
                     callee: Box::new(Expression::MemberExpression {
-                        object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                        property: Box::new(Expression::Identifier {name: "log".to_string()})
+                        object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                        property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                        span: Span::default()
                     }),
                     arguments: vec![JSItem::Ex {
-                        expression: Box::new(Expression::String {value: "hi".to_string()})
-                    }]
-                })
+                        expression: Box::new(Expression::String {value: "hi".to_string(), span: Span::default()}),
+                        span: Span::default()
+                    }],
+                    span: Span::default()
+                }),
+                span: Span::default()
             }],
             alternate: JSItem::St {
                 statement: Box::new(Statement::If {
                     test: JSItem::Ex {
                         expression: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Identifier {name: "x".to_string()}),
+                            a: Box::new(Expression::Identifier {name: "x".to_string(), span: Span::default()}),
                             op: Operator::Less,
-                            b: Box::new(Expression::Number {value: 2.})
-                        })
+                            b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     },
                     consequent: vec![JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
                             arguments: vec![JSItem::Ex {
-                                expression: Box::new(Expression::String {value: "gt 2".to_string()})
-                            }]
-                        })
+                                expression: Box::new(Expression::String {value: "gt 2".to_string(), span: Span::default()}),
+                                span: Span::default()
+                            }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }],
                     alternate: JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
                             arguments: vec![JSItem::Ex {
-                                expression: Box::new(Expression::String {value: "there".to_string()})
-                            }]
-                        })
+                                expression: Box::new(Expression::String {value: "there".to_string(), span: Span::default()}),
+                                span: Span::default()
+                            }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }
-                })
+                }),
+                span: Span::default()
             }
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -141,8 +173,8 @@ fn test_if3() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 2);
     let assign = js_items.get(0).unwrap();
@@ -152,68 +184,89 @@ fn test_if3() {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Const,
             left: JSItem::Ex {
-                expression: Box::new(Expression::Literal { value: "x".to_string() })
+                expression: Box::new(Expression::Literal { value: "x".to_string(), span: Span::default() }),
+                span: Span::default()
             },
             right: JSItem::Ex {
-                expression: Box::new(Expression::Number { value: 5. })
+                expression: Box::new(Expression::Number { value: 5., span: Span::default() }),
+                span: Span::default()
             }
-        })
+        }),
+        span: Span::default()
     }));
 
     assert!(ist.eq(&JSItem::St {
         statement: Box::new(Statement::If {
             test: JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "x".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "x".to_string(), span: Span::default()}),
                     op: Operator::Greater,
-                    b: Box::new(Expression::Number {value: 2.})
-                })
+                    b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             consequent: vec![JSItem::Ex {
                 expression: Box::new(Expression::CallExpression {
                     callee: Box::new(Expression::MemberExpression {
-                        object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                        property: Box::new(Expression::Identifier {name: "log".to_string()})
+                        object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                        property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                        span: Span::default()
                     }),
                     arguments: vec![JSItem::Ex {
-                        expression: Box::new(Expression::String {value: "hi".to_string()})
-                    }]
-                })
+                        expression: Box::new(Expression::String {value: "hi".to_string(), span: Span::default()}),
+                        span: Span::default()
+                    }],
+                    span: Span::default()
+                }),
+                span: Span::default()
             }],
             alternate: JSItem::St {
                 statement: Box::new(Statement::If {
                     test: JSItem::Ex {
                         expression: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Identifier {name: "x".to_string()}),
+                            a: Box::new(Expression::Identifier {name: "x".to_string(), span: Span::default()}),
                             op: Operator::Less,
-                            b: Box::new(Expression::Number {value: 2.})
-                        })
+                            b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     },
                     consequent: vec![JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
                             arguments: vec![JSItem::Ex {
-                                expression: Box::new(Expression::String {value: "gt 2".to_string()})
-                            }]
-                        })
+                                expression: Box::new(Expression::String {value: "gt 2".to_string(), span: Span::default()}),
+                                span: Span::default()
+                            }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }],
                     alternate: JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
                             arguments: vec![JSItem::Ex {
-                                expression: Box::new(Expression::String {value: "there".to_string()})
-                            }]
-                        })
+                                expression: Box::new(Expression::String {value: "there".to_string(), span: Span::default()}),
+                                span: Span::default()
+                            }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }
-                })
+                }),
+                span: Span::default()
             }
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -223,8 +276,8 @@ fn test_if3_1() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 2);
     let assign = js_items.get(0).unwrap();
@@ -234,68 +287,89 @@ fn test_if3_1() {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Const,
             left: JSItem::Ex {
-                expression: Box::new(Expression::Literal { value: "x".to_string() })
+                expression: Box::new(Expression::Literal { value: "x".to_string(), span: Span::default() }),
+                span: Span::default()
             },
             right: JSItem::Ex {
-                expression: Box::new(Expression::Number { value: 5. })
+                expression: Box::new(Expression::Number { value: 5., span: Span::default() }),
+                span: Span::default()
             }
-        })
+        }),
+        span: Span::default()
     }));
 
     assert!(ist.eq(&JSItem::St {
         statement: Box::new(Statement::If {
             test: JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "x".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "x".to_string(), span: Span::default()}),
                     op: Operator::EqEq,
-                    b: Box::new(Expression::Number {value: 2.})
-                })
+                    b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             consequent: vec![JSItem::Ex {
                 expression: Box::new(Expression::CallExpression {
                     callee: Box::new(Expression::MemberExpression {
-                        object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                        property: Box::new(Expression::Identifier {name: "log".to_string()})
+                        object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                        property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                        span: Span::default()
                     }),
                     arguments: vec![JSItem::Ex {
-                        expression: Box::new(Expression::String {value: "hi".to_string()})
-                    }]
-                })
+                        expression: Box::new(Expression::String {value: "hi".to_string(), span: Span::default()}),
+                        span: Span::default()
+                    }],
+                    span: Span::default()
+                }),
+                span: Span::default()
             }],
             alternate: JSItem::St {
                 statement: Box::new(Statement::If {
                     test: JSItem::Ex {
                         expression: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Identifier {name: "x".to_string()}),
+                            a: Box::new(Expression::Identifier {name: "x".to_string(), span: Span::default()}),
                             op: Operator::Less,
-                            b: Box::new(Expression::Number {value: 2.})
-                        })
+                            b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     },
                     consequent: vec![JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
                             arguments: vec![JSItem::Ex {
-                                expression: Box::new(Expression::String {value: "lt 2".to_string()})
-                            }]
-                        })
+                                expression: Box::new(Expression::String {value: "lt 2".to_string(), span: Span::default()}),
+                                span: Span::default()
+                            }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }],
                     alternate: JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
                             arguments: vec![JSItem::Ex {
-                                expression: Box::new(Expression::String {value: "there".to_string()})
-                            }]
-                        })
+                                expression: Box::new(Expression::String {value: "there".to_string(), span: Span::default()}),
+                                span: Span::default()
+                            }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }
-                })
+                }),
+                span: Span::default()
             }
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -305,8 +379,8 @@ fn test_if3_2() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 2);
     let assign = js_items.get(0).unwrap();
@@ -316,67 +390,111 @@ fn test_if3_2() {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Const,
             left: JSItem::Ex {
-                expression: Box::new(Expression::Literal { value: "x".to_string() })
+                expression: Box::new(Expression::Literal { value: "x".to_string(), span: Span::default() }),
+                span: Span::default()
             },
             right: JSItem::Ex {
-                expression: Box::new(Expression::Number { value: 3. })
+                expression: Box::new(Expression::Number { value: 3., span: Span::default() }),
+                span: Span::default()
             }
-        })
+        }),
+        span: Span::default()
     }));
 
     assert!(ist.eq(&JSItem::St {
         statement: Box::new(Statement::If {
             test: JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "x".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "x".to_string(), span: Span::default()}),
                     op: Operator::EqEqEq,
-                    b: Box::new(Expression::Number {value: 2.})
-                })
+                    b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
             },
             consequent: vec![JSItem::Ex {
                 expression: Box::new(Expression::CallExpression {
                     callee: Box::new(Expression::MemberExpression {
-                        object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                        property: Box::new(Expression::Identifier {name: "log".to_string()})
+                        object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                        property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                        span: Span::default()
                     }),
                     arguments: vec![JSItem::Ex {
-                        expression: Box::new(Expression::String {value: "hi".to_string()})
-                    }]
-                })
+                        expression: Box::new(Expression::String {value: "hi".to_string(), span: Span::default()}),
+                        span: Span::default()
+                    }],
+                    span: Span::default()
+                }),
+                span: Span::default()
             }],
             alternate: JSItem::St {
                 statement: Box::new(Statement::If {
                     test: JSItem::Ex {
                         expression: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Identifier {name: "x".to_string()}),
+                            a: Box::new(Expression::Identifier {name: "x".to_string(), span: Span::default()}),
                             op: Operator::Less,
-                            b: Box::new(Expression::Number {value: 2.})
-                        })
+                            b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     },
                     consequent: vec![JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
                             arguments: vec![JSItem::Ex {
-                                expression: Box::new(Expression::String {value: "lt 2".to_string()})
-                            }]
-                        })
+                                expression: Box::new(Expression::String {value: "lt 2".to_string(), span: Span::default()}),
+                                span: Span::default()
+                            }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }],
                     alternate: JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
                             arguments: vec![JSItem::Ex {
-                                expression: Box::new(Expression::String {value: "there".to_string()})
-                            }]
-                        })
+                                expression: Box::new(Expression::String {value: "there".to_string(), span: Span::default()}),
+                                span: Span::default()
+                            }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }
-                })
+                }),
+                span: Span::default()
             }
-        })
+        }),
+        span: Span::default()
     }))
-}
\ No newline at end of file
+}
+#[test]
+fn test_braceless_if_takes_one_statement() {
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex(String::from("if (a) b; c\n")).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    assert_eq!(js_items.len(), 2);
+    assert!(js_items.get(0).unwrap().eq(&JSItem::St {
+        statement: Box::new(Statement::If {
+            test: JSItem::Ex { expression: Box::new(Expression::Identifier { name: "a".to_string(), span: Span::default() }), span: Span::default() },
+            consequent: vec![
+                JSItem::Ex { expression: Box::new(Expression::Identifier { name: "b".to_string(), span: Span::default() }), span: Span::default() }
+            ],
+            alternate: JSItem::Ex { expression: Box::new(Expression::None), span: Span::default() }
+        }),
+        span: Span::default()
+    }));
+    assert!(js_items.get(1).unwrap().eq(&JSItem::Ex {
+        expression: Box::new(Expression::Identifier { name: "c".to_string(), span: Span::default() }),
+        span: Span::default()
+    }));
+}
@@ -0,0 +1,42 @@
+use crate::lexer::span::Span;
+use crate::parser::symbols::{Expression, JSItem, Statement};
+use crate::parser::tests::{parse, st};
+
+#[test]
+fn test_break_and_continue() {
+    let js_items = parse("while (x) {\n    continue\n    break\n}\n");
+    assert_eq!(js_items, vec![st(Statement::While {
+        test: JSItem::Ex { expression: Box::new(Expression::Identifier { name: "x".to_string(), span: Span::default() }), span: Span::default() },
+        body: vec![st(Statement::Continue { label: None }), st(Statement::Break { label: None })]
+    })]);
+}
+
+#[test]
+fn test_labelled_loop() {
+    let js_items = parse("outer:\nwhile (x) { continue outer; break outer }");
+    assert_eq!(js_items, vec![st(Statement::Labelled {
+        label: "outer".to_string(),
+        body: vec![st(Statement::While {
+            test: JSItem::Ex { expression: Box::new(Expression::Identifier { name: "x".to_string(), span: Span::default() }), span: Span::default() },
+            body: vec![
+                st(Statement::Continue { label: Some("outer".to_string()) }),
+                st(Statement::Break { label: Some("outer".to_string()) })
+            ]
+        })]
+    })]);
+}
+
+#[test]
+fn test_labelled_block() {
+    let js_items = parse("done: {\n    break done\n}\nx\n");
+    assert_eq!(js_items.len(), 2);
+    assert_eq!(js_items[0], st(Statement::Labelled {
+        label: "done".to_string(),
+        body: vec![st(Statement::Break { label: Some("done".to_string()) })]
+    }));
+}
+
+#[test]
+fn test_trailing_break() {
+    assert_eq!(parse("break"), vec![st(Statement::Break { label: None })]);
+}
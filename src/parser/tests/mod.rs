@@ -7,4 +7,32 @@ mod call_expression_parameters;
 mod arrays;
 mod object_expressions;
 mod objects;
-mod if_statement;
\ No newline at end of file
+mod if_statement;
+mod spans;
+mod templates;
+mod asi;
+mod precedence;
+mod while_statements;
+mod jump_statements;
+mod switch_statements;
+mod try_statements;
+mod property_map;
+mod statements;
+
+
+use crate::lexer::lexer::Lexer;
+use crate::parser::parser::Parser;
+use crate::lexer::span::Span;
+use crate::parser::symbols::{JSItem, Statement};
+
+#[allow(dead_code)]
+pub(crate) fn parse(source: &str) -> Vec<JSItem> {
+    let tokens = Lexer::new().lex(String::from(source)).unwrap();
+    Parser::new().parse(tokens).unwrap()
+}
+
+/// A statement item with no span, spans are ignored when items are compared.
+#[allow(dead_code)]
+pub(crate) fn st(statement: Statement) -> JSItem {
+    JSItem::St { statement: Box::new(statement), span: Span::default() }
+}
@@ -2,25 +2,28 @@ use std::fs;
 use crate::lexer::lexer::Lexer;
 use crate::parser::parser::Parser;
 use crate::parser::symbols::{JSItem, Expression, Operator, Statement, AssignOp};
-use std::collections::HashMap;
+use crate::parser::property_map::PropertyMap;
 use crate::lexer::js_token::Tok;
+use crate::lexer::span::Span;
 
 #[test]
 fn test_object_expression_0() {
     let file = fs::read_to_string("js/objects/object_expression_0.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
 
-    let mut properties = HashMap::new();
+    let mut properties = PropertyMap::new();
     properties.insert("a".to_string(), JSItem::Ex {
-        expression: Box::new(Expression::Number { value: 1. })
+        expression: Box::new(Expression::Number { value: 1., span: Span::default() }),
+        span: Span::default()
     });
     properties.insert("b".to_string(), JSItem::Ex {
-        expression: Box::new(Expression::Number { value: 2. })
+        expression: Box::new(Expression::Number { value: 2., span: Span::default() }),
+        span: Span::default()
     });
 
     let object = js_items.get(0).unwrap();
@@ -35,18 +38,20 @@ fn test_object_expression_1() {
     let file = fs::read_to_string("js/objects/object_expression_1.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
 
-    let mut properties = HashMap::new();
+    let mut properties = PropertyMap::new();
     properties.insert("a".to_string(), JSItem::Ex {
         expression: Box::new(Expression::Binop {
-            a: Box::new(Expression::Number {value: 1.}),
+            a: Box::new(Expression::Number {value: 1., span: Span::default()}),
             op: Operator::Add,
-            b: Box::new(Expression::Number {value: 2.})
-        })
+            b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+            span: Span::default()
+        }),
+        span: Span::default()
     });
     properties.insert("b".to_string(), JSItem::Ex {
         expression: Box::new(Expression::FuncEx {
@@ -57,20 +62,26 @@ fn test_object_expression_1() {
             ],
             body: vec![JSItem::St {
                 statement: Box::from(Statement::Return {
-                    value: Box::new(JSItem::Ex {
+                    value: Some(Box::new(JSItem::Ex {
                         expression: Box::new(Expression::Binop {
                             a: Box::new(Expression::Binop {
-                                a: Box::new(Expression::Identifier {name: "a".to_string()}),
+                                a: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
                                 op: Operator::Add,
-                                b: Box::new(Expression::Identifier {name: "b".to_string()})
+                                b: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
                             op: Operator::Add,
-                            b: Box::new(Expression::Identifier {name: "c".to_string()})
-                        })
-                    })
-                })
-            }]
-        })
+                            b: Box::new(Expression::Identifier {name: "c".to_string(), span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
+                    }))
+                }),
+                span: Span::default()
+            }],
+            span: Span::default()
+        }),
+        span: Span::default()
     });
 
     let object = js_items.get(0).unwrap();
@@ -81,3 +92,24 @@ fn test_object_expression_1() {
 }
 
 
+
+
+#[test]
+fn test_reserved_word_keys() {
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex(String::from("{\n    default: 1,\n    new: 2\n}")).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    let mut properties = PropertyMap::new();
+    properties.insert("default".to_string(), JSItem::Ex {
+        expression: Box::new(Expression::Number { value: 1., span: Span::default() }),
+        span: Span::default()
+    });
+    properties.insert("new".to_string(), JSItem::Ex {
+        expression: Box::new(Expression::Number { value: 2., span: Span::default() }),
+        span: Span::default()
+    });
+
+    assert_eq!(js_items, vec![JSItem::Object { mutable: true, properties }]);
+}
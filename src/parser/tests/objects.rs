@@ -1,38 +1,43 @@
 use std::fs;
 use crate::lexer::lexer::Lexer;
 use crate::parser::parser::Parser;
-use std::collections::HashMap;
+use crate::parser::property_map::PropertyMap;
 use crate::parser::symbols::{JSItem, Expression, Statement, AssignOp, Operator};
 use crate::lexer::js_token::Tok::Static;
+use crate::lexer::span::Span;
 
 #[test]
 fn test_object_object_call_property() {
     let file = fs::read_to_string("js/objects/object_object_call_property.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 2);
     let object = js_items.get(0).unwrap();
-    let mut properties1 = HashMap::new();
-    let mut properties2 = HashMap::new();
-    let mut properties3 = HashMap::new();
+    let mut properties1 = PropertyMap::new();
+    let mut properties2 = PropertyMap::new();
+    let mut properties3 = PropertyMap::new();
     properties3.insert("foo".to_string(), JSItem::Ex {
-        expression: Box::new(Expression::Number {value: 1.0})
+        expression: Box::new(Expression::Number {value: 1.0, span: Span::default()}),
+        span: Span::default()
     });
     properties2.insert("baz".to_string(), JSItem::Ex {
         expression: Box::new(Expression::FuncEx {
             params: vec![],
             body: vec![JSItem::St {
                 statement: Box::new(Statement::Return {
-                    value: Box::from(JSItem::Object {
+                    value: Some(Box::from(JSItem::Object {
                         mutable: true,
                         properties: properties3
-                    })
-                })
-            }]
-        })
+                    }))
+                }),
+                span: Span::default()
+            }],
+            span: Span::default()
+        }),
+        span: Span::default()
     });
     properties1.insert("bar".to_string(), JSItem::Object {
         mutable: true,
@@ -42,13 +47,15 @@ fn test_object_object_call_property() {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Let,
             left: JSItem::Ex {
-                expression: Box::new(Expression::Literal {value: "foo".to_string()})
+                expression: Box::new(Expression::Literal {value: "foo".to_string(), span: Span::default()}),
+                span: Span::default()
             },
             right: JSItem::Object {
                 mutable: true,
                 properties: properties1
             }
-        })
+        }),
+        span: Span::default()
     }));
 
     let expression = js_items.get(1).unwrap();
@@ -57,15 +64,20 @@ fn test_object_object_call_property() {
             object: Box::new(Expression::CallExpression {
                 callee: Box::new(Expression::MemberExpression {
                     object: Box::new(Expression::MemberExpression {
-                        object: Box::new(Expression::Identifier {name: "foo".to_string()}),
-                        property: Box::new(Expression::Identifier {name: "bar".to_string()})
+                        object: Box::new(Expression::Identifier {name: "foo".to_string(), span: Span::default()}),
+                        property: Box::new(Expression::Identifier {name: "bar".to_string(), span: Span::default()}),
+                        span: Span::default()
                     }),
-                    property: Box::new(Expression::Identifier {name: "baz".to_string()})
+                    property: Box::new(Expression::Identifier {name: "baz".to_string(), span: Span::default()}),
+                    span: Span::default()
                 }),
-                arguments: vec![]
+                arguments: vec![],
+                span: Span::default()
             }),
-            property: Box::new(Expression::Identifier {name: "foo".to_string()})
-        })
+            property: Box::new(Expression::Identifier {name: "foo".to_string(), span: Span::default()}),
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
 }
 
@@ -74,31 +86,34 @@ fn test_edit_object_and_log_from_another_object() {
     let file = fs::read_to_string("js/objects/edit_object_and_log_from_another_object.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 3);
 
     let statement1 = js_items.get(0).unwrap();
-    let mut properties1 = HashMap::new();
+    let mut properties1 = PropertyMap::new();
     properties1.insert("a".to_string(), JSItem::Ex {
-        expression: Box::from(Expression::Number { value: 1.0 })
+        expression: Box::from(Expression::Number { value: 1.0, span: Span::default() }),
+        span: Span::default()
     });
     assert!(statement1.eq(&JSItem::St {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Let,
             left: JSItem::Ex {
-                expression: Box::from(Expression::Literal { value: "b".to_string() })
+                expression: Box::from(Expression::Literal { value: "b".to_string(), span: Span::default() }),
+                span: Span::default()
             },
             right: JSItem::Object {
                 mutable: true,
                 properties: properties1
             }
-        })
+        }),
+        span: Span::default()
     }));
 
     let statement2 = js_items.get(1).unwrap();
-    let mut properties2 = HashMap::new();
+    let mut properties2 = PropertyMap::new();
     properties2.insert("run".to_string(), JSItem::Ex {
         expression: Box::new(Expression::FuncEx {
             params: vec![],
@@ -108,70 +123,94 @@ fn test_edit_object_and_log_from_another_object() {
                         statement: Box::new(Statement::AssignmentExpression {
                             operator: AssignOp::Let,
                             left: JSItem::Ex {
-                                expression: Box::new(Expression::Literal {value: "a".to_string()})
+                                expression: Box::new(Expression::Literal {value: "a".to_string(), span: Span::default()}),
+                                span: Span::default()
                             },
                             right: JSItem::Ex {
-                                expression: Box::new(Expression::Number {value: 0.0})
+                                expression: Box::new(Expression::Number {value: 0.0, span: Span::default()}),
+                                span: Span::default()
                             }
-                        })
+                        }),
+                        span: Span::default()
                     },
                     test: JSItem::Ex {
                         expression: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Identifier {name: "a".to_string()}),
+                            a: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
                             op: Operator::Less,
-                            b: Box::new(Expression::Number {value: 10.0})
-                        })
+                            b: Box::new(Expression::Number {value: 10.0, span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     },
                     update: JSItem::Ex {
                         expression: Box::new(Expression::UpdateExpression {
-                            expression: Box::new(Expression::Identifier {name: "a".to_string()})
-                        })
+                            operator: Operator::Add,
+                            prefix: false,
+                            expression: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     },
                     body: vec![JSItem::St {
                         statement: Box::new(Statement::AssignmentExpression {
                             operator: AssignOp::None,
                             left: JSItem::Ex {
                                 expression: Box::new(Expression::MemberExpression {
-                                    object: Box::new(Expression::Identifier {name: "b".to_string()}),
-                                    property: Box::new(Expression::Identifier {name: "a".to_string()})
-                                })
+                                    object: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
+                                    property: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
+                                    span: Span::default()
+                                }),
+                                span: Span::default()
                             },
                             right: JSItem::Ex {
                                 expression: Box::new(Expression::Binop {
-                                    a: Box::new(Expression::Identifier {name: "a".to_string()}),
+                                    a: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
                                     op: Operator::Mult,
-                                    b: Box::new(Expression::Number {value: 2.0})
-                                })
+                                    b: Box::new(Expression::Number {value: 2.0, span: Span::default()}),
+                                    span: Span::default()
+                                }),
+                                span: Span::default()
                             }
-                        })
+                        }),
+                        span: Span::default()
                     }, JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier {name: "console".to_string()}),
-                                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                                object: Box::new(Expression::Identifier {name: "console".to_string(), span: Span::default()}),
+                                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                                span: Span::default()
                             }),
                             arguments: vec![JSItem::Ex {
                                 expression: Box::new(Expression::MemberExpression {
-                                    object: Box::new(Expression::Identifier {name: "b".to_string()}),
-                                    property: Box::new(Expression::Identifier {name: "a".to_string()})
-                                })
-                            }]
-                        })
+                                    object: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
+                                    property: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
+                                    span: Span::default()
+                                }),
+                                span: Span::default()
+                            }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
                     }]
-                })
-            }]
-        })
+                }),
+                span: Span::default()
+            }],
+            span: Span::default()
+        }),
+        span: Span::default()
     });
     assert!(statement2.eq(&JSItem::St {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Let,
             left: JSItem::Ex {
-                expression: Box::new(Expression::Literal {value: "a".to_string()})
+                expression: Box::new(Expression::Literal {value: "a".to_string(), span: Span::default()}),
+                span: Span::default()
             },
             right: JSItem::Object {
                 mutable: true,
                 properties: properties2
             }
-        })
+        }),
+        span: Span::default()
     }))
 }
\ No newline at end of file
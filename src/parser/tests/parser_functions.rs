@@ -2,6 +2,7 @@ use std::fs;
 
 use crate::lexer::js_token::Tok;
 use crate::lexer::lexer::Lexer;
+use crate::lexer::span::Span;
 use crate::parser::parser::Parser;
 use crate::parser::symbols::{Expression, Statement};
 use crate::parser::symbols::JSItem;
@@ -12,8 +13,8 @@ fn test_simple_function_declaration() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
@@ -24,15 +25,20 @@ fn test_simple_function_declaration() {
             body: vec![JSItem::Ex {
                 expression: Box::new(Expression::CallExpression {
                     callee: Box::new(Expression::MemberExpression {
-                        object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                        property: Box::new(Expression::Identifier { name: "log".to_string() }),
+                        object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                        property: Box::new(Expression::Identifier { name: "log".to_string(), span: Span::default() }),
+                        span: Span::default()
                     }),
                     arguments: vec![JSItem::Ex {
-                        expression: Box::new(Expression::String { value: "hi".to_string() })
+                        expression: Box::new(Expression::String { value: "hi".to_string(), span: Span::default() }),
+                        span: Span::default()
                     }],
-                })
+                    span: Span::default()
+                }),
+                span: Span::default()
             }],
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -42,8 +48,8 @@ fn test_let_arrow_function_declaration() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
@@ -56,16 +62,21 @@ fn test_let_arrow_function_declaration() {
                 body: vec![JSItem::Ex {
                     expression: Box::new(Expression::CallExpression {
                         callee: Box::new(Expression::MemberExpression {
-                            object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                            property: Box::new(Expression::Identifier { name: "log".to_string() }),
+                            object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                            property: Box::new(Expression::Identifier { name: "log".to_string(), span: Span::default() }),
+                            span: Span::default()
                         }),
                         arguments: vec![JSItem::Ex {
-                            expression: Box::new(Expression::String { value: "hi".to_string() })
+                            expression: Box::new(Expression::String { value: "hi".to_string(), span: Span::default() }),
+                            span: Span::default()
                         }],
-                    })
+                        span: Span::default()
+                    }),
+                    span: Span::default()
                 }],
             }),
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -75,8 +86,8 @@ fn test_let_function_declaration() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
@@ -89,16 +100,21 @@ fn test_let_function_declaration() {
                 body: vec![JSItem::Ex {
                     expression: Box::new(Expression::CallExpression {
                         callee: Box::new(Expression::MemberExpression {
-                            object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                            property: Box::new(Expression::Identifier { name: "log".to_string() }),
+                            object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                            property: Box::new(Expression::Identifier { name: "log".to_string(), span: Span::default() }),
+                            span: Span::default()
                         }),
                         arguments: vec![JSItem::Ex {
-                            expression: Box::new(Expression::String { value: "hi".to_string() })
+                            expression: Box::new(Expression::String { value: "hi".to_string(), span: Span::default() }),
+                            span: Span::default()
                         }],
-                    })
+                        span: Span::default()
+                    }),
+                    span: Span::default()
                 }],
             }),
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -108,8 +124,8 @@ fn test_const_arrow_function_declaration() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
@@ -122,16 +138,21 @@ fn test_const_arrow_function_declaration() {
                 body: vec![JSItem::Ex {
                     expression: Box::new(Expression::CallExpression {
                         callee: Box::new(Expression::MemberExpression {
-                            object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                            property: Box::new(Expression::Identifier { name: "log".to_string() }),
+                            object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                            property: Box::new(Expression::Identifier { name: "log".to_string(), span: Span::default() }),
+                            span: Span::default()
                         }),
                         arguments: vec![JSItem::Ex {
-                            expression: Box::new(Expression::String { value: "hi".to_string() })
+                            expression: Box::new(Expression::String { value: "hi".to_string(), span: Span::default() }),
+                            span: Span::default()
                         }],
-                    })
+                        span: Span::default()
+                    }),
+                    span: Span::default()
                 }],
             }),
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -141,8 +162,8 @@ fn test_const_function_declaration() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
@@ -155,16 +176,21 @@ fn test_const_function_declaration() {
                 body: vec![JSItem::Ex {
                     expression: Box::new(Expression::CallExpression {
                         callee: Box::new(Expression::MemberExpression {
-                            object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                            property: Box::new(Expression::Identifier { name: "log".to_string() }),
+                            object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                            property: Box::new(Expression::Identifier { name: "log".to_string(), span: Span::default() }),
+                            span: Span::default()
                         }),
                         arguments: vec![JSItem::Ex {
-                            expression: Box::new(Expression::String { value: "hi".to_string() })
+                            expression: Box::new(Expression::String { value: "hi".to_string(), span: Span::default() }),
+                            span: Span::default()
                         }],
-                    })
+                        span: Span::default()
+                    }),
+                    span: Span::default()
                 }],
             }),
-        })
+        }),
+        span: Span::default()
     }))
 }
 
@@ -174,30 +200,38 @@ fn test_map_function_expression() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
     assert!(function.eq(&JSItem::Ex {
         expression: Box::new(Expression::CallExpression {
-            callee: Box::new(Expression::Identifier {name: "map".to_string()}),
+            callee: Box::new(Expression::Identifier {name: "map".to_string(), span: Span::default()}),
             arguments: vec![JSItem::Ex {
                 expression: Box::new(Expression::FuncEx {
                     params: vec![Tok::Name {name: "i".to_string()}],
                     body: vec![JSItem::Ex {
                         expression: Box::new(Expression::CallExpression {
                             callee: Box::new(Expression::MemberExpression {
-                                object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                                property: Box::new(Expression::Identifier { name: "log".to_string() }),
+                                object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                                property: Box::new(Expression::Identifier { name: "log".to_string(), span: Span::default() }),
+                                span: Span::default()
                             }),
                             arguments: vec![JSItem::Ex {
-                                expression: Box::new(Expression::Identifier { name: "i".to_string() })
-                            }]
-                        })
-                    }]
-                })
-            }]
-        })
+                                expression: Box::new(Expression::Identifier { name: "i".to_string(), span: Span::default() }),
+                                span: Span::default()
+                            }],
+                            span: Span::default()
+                        }),
+                        span: Span::default()
+                    }],
+                    span: Span::default()
+                }),
+                span: Span::default()
+            }],
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
 }
\ No newline at end of file
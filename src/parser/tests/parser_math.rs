@@ -1,4 +1,5 @@
 use crate::lexer::lexer::Lexer;
+use crate::lexer::span::Span;
 use std::fs;
 use crate::parser::parser::Parser;
 use crate::parser::symbols::{Expression, Operator, Statement, AssignOp};
@@ -10,21 +11,24 @@ fn test_simple_add() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let expressions = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let expressions = parser.parse(tokens).unwrap();
 
     assert_eq!(expressions.len(), 1);
     let expression = expressions.get(0).unwrap();
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::Binop {
             a: Box::new(Expression::Binop {
-                a: Box::new(Expression::Number {value: 1.}),
+                a: Box::new(Expression::Number {value: 1., span: Span::default()}),
                 op: Operator::Add,
-                b: Box::new(Expression::Number {value: 2.})
+                b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                span: Span::default()
             }),
             op: Operator::Add,
-            b: Box::new(Expression::Number {value: 3.})
-        })
+            b: Box::new(Expression::Number {value: 3., span: Span::default()}),
+            span: Span::default()
+        }),
+        span: Span::default()
     }));
 }
 
@@ -34,17 +38,19 @@ fn test_div1() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let expressions = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let expressions = parser.parse(tokens).unwrap();
 
     assert_eq!(expressions.len(), 1);
     let expression = expressions.get(0).unwrap();
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::Binop {
-            a: Box::new(Expression::Number {value: 1.}),
+            a: Box::new(Expression::Number {value: 1., span: Span::default()}),
             op: Operator::Div,
-            b: Box::new(Expression::Number {value: 5.})
-        })
+            b: Box::new(Expression::Number {value: 5., span: Span::default()}),
+            span: Span::default()
+        }),
+        span: Span::default()
     }));
 }
 
@@ -54,21 +60,24 @@ fn test_div2() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let expressions = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let expressions = parser.parse(tokens).unwrap();
 
     assert_eq!(expressions.len(), 1);
     let expression = expressions.get(0).unwrap();
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::Binop {
-            a: Box::new(Expression::Number {value: 1.}),
+            a: Box::new(Expression::Number {value: 1., span: Span::default()}),
             op: Operator::Add,
             b: Box::new(Expression::Binop {
-                a: Box::new(Expression::Number {value: 2.}),
+                a: Box::new(Expression::Number {value: 2., span: Span::default()}),
                 op: Operator::Div,
-                b: Box::new(Expression::Number {value: 5.})
-            })
-        })
+                b: Box::new(Expression::Number {value: 5., span: Span::default()}),
+                span: Span::default()
+            }),
+            span: Span::default()
+        }),
+        span: Span::default()
     }));
 }
 
@@ -78,34 +87,40 @@ fn test_div4() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let expressions = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let expressions = parser.parse(tokens).unwrap();
 
     assert_eq!(expressions.len(), 2);
     let expression = expressions.get(0).unwrap();
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::Binop {
-            a: Box::new(Expression::Number {value: 1.}),
+            a: Box::new(Expression::Number {value: 1., span: Span::default()}),
             op: Operator::Div,
-            b: Box::new(Expression::Number {value: 2.})
-        })
+            b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+            span: Span::default()
+        }),
+        span: Span::default()
     }));
 
     let expression = expressions.get(1).unwrap();
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::Binop {
             a: Box::new(Expression::Binop {
-                a: Box::new(Expression::Number {value: 5.}),
+                a: Box::new(Expression::Number {value: 5., span: Span::default()}),
                 op: Operator::Mult,
-                b: Box::new(Expression::Number {value: 3.})
+                b: Box::new(Expression::Number {value: 3., span: Span::default()}),
+                span: Span::default()
             }),
             op: Operator::Add,
             b: Box::new(Expression::Binop {
-                a: Box::new(Expression::Number {value: 70.}),
+                a: Box::new(Expression::Number {value: 70., span: Span::default()}),
                 op: Operator::Div,
-                b: Box::new(Expression::Number {value: 10.})
-            })
-        })
+                b: Box::new(Expression::Number {value: 10., span: Span::default()}),
+                span: Span::default()
+            }),
+            span: Span::default()
+        }),
+        span: Span::default()
     }));
 }
 
@@ -115,27 +130,32 @@ fn test_div5() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let expressions = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let expressions = parser.parse(tokens).unwrap();
 
     assert_eq!(expressions.len(), 1);
     let expression = expressions.get(0).unwrap();
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::Binop {
-            a: Box::new(Expression::Number {value: 1.}),
+            a: Box::new(Expression::Number {value: 1., span: Span::default()}),
             op: Operator::Add,
             b: Box::new(Expression::Binop {
                 a: Box::new(Expression::SubExpression {
                     expression: Box::new(Expression::Binop {
-                        a: Box::new(Expression::Number {value: 3.}),
+                        a: Box::new(Expression::Number {value: 3., span: Span::default()}),
                         op: Operator::Add,
-                        b: Box::new(Expression::Number {value: 2.})
-                    })
+                        b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                        span: Span::default()
+                    }),
+                    span: Span::default()
                 }),
                 op: Operator::Div,
-                b: Box::new(Expression::Number {value: 5.})
-            })
-        })
+                b: Box::new(Expression::Number {value: 5., span: Span::default()}),
+                span: Span::default()
+            }),
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
 }
 
@@ -143,8 +163,8 @@ fn test_div5() {
 fn test_add_sub() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 + 2 - 1"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 + 2 - 1")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let expression = js_items.get(0).unwrap();
@@ -152,13 +172,16 @@ fn test_add_sub() {
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::Binop {
             a: Box::new(Expression::Binop {
-                a: Box::new(Expression::Number {value: 3.}),
+                a: Box::new(Expression::Number {value: 3., span: Span::default()}),
                 op: Operator::Add,
-                b: Box::new(Expression::Number {value: 2.})
+                b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                span: Span::default()
             }),
             op: Operator::Sub,
-            b: Box::new(Expression::Number {value: 1. })
-        })
+            b: Box::new(Expression::Number {value: 1., span: Span::default() }),
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
 }
 
@@ -166,18 +189,20 @@ fn test_add_sub() {
 fn test_identifier_less_number() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("a < 1"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("a < 1")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let expression = js_items.get(0).unwrap();
 
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::Binop {
-            a: Box::new(Expression::Identifier {name: "a".to_string()}),
+            a: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
             op: Operator::Less,
-            b: Box::new(Expression::Number {value: 1. })
-        })
+            b: Box::new(Expression::Number {value: 1., span: Span::default() }),
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
 }
 
@@ -185,18 +210,20 @@ fn test_identifier_less_number() {
 fn test_number_less_number() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("2 < 1"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("2 < 1")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let expression = js_items.get(0).unwrap();
 
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::Binop {
-            a: Box::new(Expression::Number {value: 2.}),
+            a: Box::new(Expression::Number {value: 2., span: Span::default()}),
             op: Operator::Less,
-            b: Box::new(Expression::Number {value: 1. })
-        })
+            b: Box::new(Expression::Number {value: 1., span: Span::default() }),
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
 }
 
@@ -204,18 +231,20 @@ fn test_number_less_number() {
 fn test_number_less_identifier() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("2 < a"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("2 < a")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let expression = js_items.get(0).unwrap();
 
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::Binop {
-            a: Box::new(Expression::Number {value: 2.}),
+            a: Box::new(Expression::Number {value: 2., span: Span::default()}),
             op: Operator::Less,
-            b: Box::new(Expression::Identifier {name: "a".to_string()})
-        })
+            b: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
 }
 
@@ -223,16 +252,20 @@ fn test_number_less_identifier() {
 fn test_identifier_plus_plus() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("a++"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("a++")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let expression = js_items.get(0).unwrap();
 
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::UpdateExpression {
-            expression: Box::new(Expression::Identifier {name: "a".to_string()})
-        })
+            operator: Operator::Add,
+            prefix: false,
+            expression: Box::new(Expression::Identifier {name: "a".to_string(), span: Span::default()}),
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
 }
 
@@ -240,8 +273,8 @@ fn test_identifier_plus_plus() {
 fn test_and1() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("x == 5 && x < 10"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("x == 5 && x < 10")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let expression = js_items.get(0).unwrap();
@@ -249,17 +282,21 @@ fn test_and1() {
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::Binop {
             a: Box::new(Expression::Binop {
-                a: Box::new(Expression::Identifier {name: "x".to_string()}),
+                a: Box::new(Expression::Identifier {name: "x".to_string(), span: Span::default()}),
                 op: Operator::EqEq,
-                b: Box::new(Expression::Number {value: 5.})
+                b: Box::new(Expression::Number {value: 5., span: Span::default()}),
+                span: Span::default()
             }),
             op: Operator::And,
             b: Box::new(Expression::Binop {
-                a: Box::new(Expression::Identifier {name: "x".to_string()}),
+                a: Box::new(Expression::Identifier {name: "x".to_string(), span: Span::default()}),
                 op: Operator::Less,
-                b: Box::new(Expression::Number {value: 10.})
-            })
-        })
+                b: Box::new(Expression::Number {value: 10., span: Span::default()}),
+                span: Span::default()
+            }),
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
 }
 
@@ -268,18 +305,20 @@ fn test_triple_equal() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("x === 5"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("x === 5")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let expression = js_items.get(0).unwrap();
 
     assert!(expression.eq(&JSItem::Ex {
         expression: Box::new(Expression::Binop {
-            a: Box::new(Expression::Identifier {name: "x".to_string()}),
+            a: Box::new(Expression::Identifier {name: "x".to_string(), span: Span::default()}),
             op: Operator::EqEqEq,
-            b: Box::new(Expression::Number {value: 5.})
-        })
+            b: Box::new(Expression::Number {value: 5., span: Span::default()}),
+            span: Span::default()
+        }),
+        span: Span::default()
     }))
 }
 
@@ -288,30 +327,36 @@ fn test_div_log() {
     let file = fs::read_to_string("js/math/div/div_log.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 2);
 
     assert!(js_items.eq(&vec![JSItem::St {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Let,
-            left: JSItem::Ex { expression: Box::new(Expression::Literal { value: "b".to_string() }) },
-            right: JSItem::Ex {expression: Box::new(Expression::Number {value: 5.})}
-        })
+            left: JSItem::Ex { expression: Box::new(Expression::Literal { value: "b".to_string(), span: Span::default() }), span: Span::default() },
+            right: JSItem::Ex {expression: Box::new(Expression::Number {value: 5., span: Span::default()}), span: Span::default()}
+        }),
+        span: Span::default()
     }, JSItem::Ex {
         expression: Box::new(Expression::CallExpression {
             callee: Box::new(Expression::MemberExpression {
-                object: Box::new(Expression::Identifier { name: "console".to_string() }),
-                property: Box::new(Expression::Identifier {name: "log".to_string()})
+                object: Box::new(Expression::Identifier { name: "console".to_string(), span: Span::default() }),
+                property: Box::new(Expression::Identifier {name: "log".to_string(), span: Span::default()}),
+                span: Span::default()
             }),
             arguments: vec![JSItem::Ex {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Identifier {name: "b".to_string()}),
+                    a: Box::new(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
                     op: Operator::Div,
-                    b: Box::new(Expression::Number {value: 2.})
-                })
-            }]
-        })
+                    b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                    span: Span::default()
+                }),
+                span: Span::default()
+            }],
+            span: Span::default()
+        }),
+        span: Span::default()
     }]));
 }
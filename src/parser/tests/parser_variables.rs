@@ -4,6 +4,7 @@ use crate::parser::parser::Parser;
 use crate::parser::symbols::{Expression, Statement, Operator, AssignOp};
 use crate::parser::symbols::JSItem;
 use crate::lexer::js_token::Tok;
+use crate::lexer::span::Span;
 
 #[test]
 fn test_let_variable_declaration() {
@@ -11,17 +12,18 @@ fn test_let_variable_declaration() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
     assert!(function.eq(&JSItem::St {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Let,
-            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string()})},
-            right: JSItem::Ex {expression: Box::from(Expression::Literal { value: "hi".to_string() })}
-        })
+            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string(), span: Span::default()}), span: Span::default()},
+            right: JSItem::Ex {expression: Box::from(Expression::Literal { value: "hi".to_string(), span: Span::default() }), span: Span::default()}
+        }),
+        span: Span::default()
     }))
 }
 
@@ -31,17 +33,18 @@ fn test_let_variable_declaration_number() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
     assert!(function.eq(&JSItem::St {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Let,
-            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string()})},
-            right: JSItem::Ex {expression: Box::from(Expression::Number { value: 123. })}
-        })
+            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string(), span: Span::default()}), span: Span::default()},
+            right: JSItem::Ex {expression: Box::from(Expression::Number { value: 123., span: Span::default() }), span: Span::default()}
+        }),
+        span: Span::default()
     }))
 }
 
@@ -51,23 +54,26 @@ fn test_let_math_declaration() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
     assert!(function.eq(&JSItem::St {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Let,
-            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string()})},
+            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string(), span: Span::default()}), span: Span::default()},
             right: JSItem::Ex {expression: Box::new(Expression::SubExpression {
                 expression: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Number { value: 1. }),
+                    a: Box::new(Expression::Number { value: 1., span: Span::default() }),
                     op: Operator::Add,
-                    b: Box::new(Expression::Number { value: 2. }),
-                })
-            })}
-        })
+                    b: Box::new(Expression::Number { value: 2., span: Span::default() }),
+                    span: Span::default()
+                }),
+                span: Span::default()
+            }), span: Span::default()}
+        }),
+        span: Span::default()
     }))
 }
 
@@ -77,31 +83,36 @@ fn test_let_math_declaration1() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
     assert!(function.eq(&JSItem::St {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Let,
-            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string()})},
+            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "a".to_string(), span: Span::default()}), span: Span::default()},
             right: JSItem::Ex {expression: Box::new(Expression::Binop {
-                a: Box::new(Expression::Number { value: 1. }),
+                a: Box::new(Expression::Number { value: 1., span: Span::default() }),
                 op: Operator::Add,
                 b: Box::new(Expression::Binop {
-                    a: Box::new(Expression::Number {value: 2.}),
+                    a: Box::new(Expression::Number {value: 2., span: Span::default()}),
                     op: Operator::Mult,
                     b: Box::new(Expression::SubExpression {
                         expression: Box::new(Expression::Binop {
-                            a: Box::new(Expression::Number {value: 3.}),
+                            a: Box::new(Expression::Number {value: 3., span: Span::default()}),
                             op: Operator::Add,
-                            b: Box::new(Expression::Number {value: 2.})
-                        })
-                    })
+                            b: Box::new(Expression::Number {value: 2., span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
+                    }),
+                    span: Span::default()
                 }),
-            })}
-        })
+                span: Span::default()
+            }), span: Span::default()}
+        }),
+        span: Span::default()
     }))
 }
 
@@ -111,20 +122,51 @@ fn test_let_var_plus_var() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 1);
     let function = js_items.get(0).unwrap();
     assert!(function.eq(&JSItem::St {
         statement: Box::new(Statement::AssignmentExpression {
             operator: AssignOp::Let,
-            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "c".to_string()})},
+            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "c".to_string(), span: Span::default()}), span: Span::default()},
             right: JSItem::Ex {expression: Box::new(Expression::Binop {
-                a: Box::new(Expression::Identifier { name: "a".to_string() }),
+                a: Box::new(Expression::Identifier { name: "a".to_string(), span: Span::default() }),
                 op: Operator::Add,
-                b: Box::new(Expression::Identifier { name: "b".to_string() }),
-            })}
-        })
+                b: Box::new(Expression::Identifier { name: "b".to_string(), span: Span::default() }),
+                span: Span::default()
+            }), span: Span::default()}
+        }),
+        span: Span::default()
     }))
-}
\ No newline at end of file
+}
+#[test]
+fn test_let_regex_declaration() {
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex(String::from("let re = /ab+c/gi;")).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    assert_eq!(js_items.len(), 1);
+    assert!(js_items.get(0).unwrap().eq(&JSItem::St {
+        statement: Box::new(Statement::AssignmentExpression {
+            operator: AssignOp::Let,
+            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "re".to_string(), span: Span::default()}), span: Span::default()},
+            right: JSItem::Ex {
+                expression: Box::from(Expression::RegExp { pattern: "ab+c".to_string(), flags: "gi".to_string(), span: Span::default() }),
+                span: Span::default()
+            }
+        }),
+        span: Span::default()
+    }))
+}
+
+#[test]
+fn test_unfinished_expression_is_syntax_error() {
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex(String::from("let x = (1 + ;\n")).unwrap();
+
+    assert!(parser.parse(tokens).is_err());
+}
@@ -0,0 +1,157 @@
+use crate::lexer::lexer::Lexer;
+use crate::parser::parser::SyntaxError;
+use crate::parser::pratt::parse_expression;
+use crate::parser::symbols::{Expression, Operator, UnaryOperator};
+
+#[cfg(test)]
+fn unary_text(op: &UnaryOperator) -> &'static str {
+    match op {
+        UnaryOperator::Not => "!",
+        UnaryOperator::Minus => "-",
+        UnaryOperator::Plus => "+",
+        UnaryOperator::BitNot => "~",
+        UnaryOperator::TypeOf => "typeof",
+        UnaryOperator::Void => "void",
+        UnaryOperator::Delete => "delete"
+    }
+}
+
+#[cfg(test)]
+fn operator_text(op: &Operator) -> &'static str {
+    match op {
+        Operator::Add => "+",
+        Operator::Sub => "-",
+        Operator::Mult => "*",
+        Operator::Div => "/",
+        Operator::Mod => "%",
+        Operator::Exp => "**",
+        Operator::LShift => "<<",
+        Operator::RShift => ">>",
+        Operator::URShift => ">>>",
+        Operator::BitOr => "|",
+        Operator::BitXor => "^",
+        Operator::BitAnd => "&",
+        Operator::Less => "<",
+        Operator::Greater => ">",
+        Operator::LessEqual => "<=",
+        Operator::GreaterEqual => ">=",
+        Operator::In => "in",
+        Operator::InstanceOf => "instanceof",
+        Operator::And => "&&",
+        Operator::Or => "||",
+        Operator::Nullish => "??",
+        Operator::EqEq => "==",
+        Operator::EqEqEq => "===",
+        Operator::NotEq => "!=",
+        Operator::NotEqEq => "!==",
+        Operator::None | Operator::FloorDiv => "?"
+    }
+}
+
+/// The tree as an s-expression, `1 + 2 * 3` is `(+ 1 (* 2 3))`.
+#[cfg(test)]
+fn tree(ex: &Expression) -> String {
+    match ex {
+        Expression::Binop { a, op, b, .. } => format!("({} {} {})", operator_text(op), tree(a), tree(b)),
+        Expression::Assign { operator: Operator::None, left, right, .. } => format!("(= {} {})", tree(left), tree(right)),
+        Expression::Assign { operator, left, right, .. } => format!("({}= {} {})", operator_text(operator), tree(left), tree(right)),
+        Expression::Number { value, .. } => value.to_string(),
+        Expression::Identifier { name, .. } => name.clone(),
+        Expression::Literal { value, .. } => format!("{:?}", value),
+        Expression::SubExpression { expression, .. } => format!("[{}]", tree(expression)),
+        Expression::MemberExpression { object, property, .. } => format!("{}.{}", tree(object), tree(property)),
+        Expression::ComputedMemberExpression { object, property, .. } => format!("{}[{}]", tree(object), tree(property)),
+        Expression::CallExpression { callee, arguments, .. } => format!("{}({})", tree(callee), arguments.len()),
+        Expression::UpdateExpression { operator, prefix: true, expression, .. } => format!("({}{} {})", operator_text(operator), operator_text(operator), tree(expression)),
+        Expression::UpdateExpression { operator, prefix: false, expression, .. } => format!("({} {}{})", tree(expression), operator_text(operator), operator_text(operator)),
+        Expression::Unary { op, argument, .. } => format!("({} {})", unary_text(op), tree(argument)),
+        Expression::Conditional { test, consequent, alternate, .. } => format!("(? {} {} {})", tree(test), tree(consequent), tree(alternate)),
+        ex => format!("{:?}", ex)
+    }
+}
+
+#[cfg(test)]
+fn parse(source: &str) -> Result<Expression, SyntaxError> {
+    let tokens = Lexer::new().lex(String::from(source)).unwrap();
+    parse_expression(&tokens)
+}
+
+#[test]
+fn test_precedence_table() {
+    let cases = vec![
+        ("1 + 2 * 3", "(+ 1 (* 2 3))"),
+        ("1 * 2 + 3", "(+ (* 1 2) 3)"),
+        ("1 - 2 - 3", "(- (- 1 2) 3)"),
+        ("8 / 4 / 2", "(/ (/ 8 4) 2)"),
+        ("7 % 4 * 2", "(* (% 7 4) 2)"),
+        ("2 ** 3 ** 2", "(** 2 (** 3 2))"),
+        ("2 * 3 ** 2", "(* 2 (** 3 2))"),
+        ("1 + 2 << 3", "(<< (+ 1 2) 3)"),
+        ("a >> b >>> c", "(>>> (>> a b) c)"),
+        ("a < b + 1", "(< a (+ b 1))"),
+        ("a << 1 < b", "(< (<< a 1) b)"),
+        ("a <= b >= c", "(>= (<= a b) c)"),
+        ("a in b instanceof c", "(instanceof (in a b) c)"),
+        ("a < b == c > d", "(== (< a b) (> c d))"),
+        ("a === b !== c != d", "(!= (!== (=== a b) c) d)"),
+        ("a & b == c", "(& a (== b c))"),
+        ("a | b ^ c & d", "(| a (^ b (& c d)))"),
+        ("a && b | c", "(&& a (| b c))"),
+        ("a || b && c", "(|| a (&& b c))"),
+        ("a && b || c && d", "(|| (&& a b) (&& c d))"),
+        ("a ?? b ?? c", "(?? (?? a b) c)"),
+        ("a ?? (b || c)", "(?? a [(|| b c)])"),
+        ("(1 + 2) * 3", "(* [(+ 1 2)] 3)"),
+        ("a = b = c", "(= a (= b c))"),
+        ("a = b + 1", "(= a (+ b 1))"),
+        ("a.b = c || d", "(= a.b (|| c d))"),
+        ("f(1, 2) + a.b.c", "(+ f(2) a.b.c)"),
+        ("a.b(1) * 2", "(* a.b(1) 2)"),
+        ("x++ + 1", "(+ (x ++) 1)"),
+        ("++x * 2", "(* (++ x) 2)"),
+        ("a - --b", "(- a (-- b))"),
+        ("a.b-- - 1", "(- (a.b --) 1)"),
+        ("-x++", "(- (x ++))"),
+        ("++a.b", "(++ a.b)"),
+        ("a += b * 2", "(+= a (* b 2))"),
+        ("a -= b -= 1", "(-= a (-= b 1))"),
+        ("a.b **= 2", "(**= a.b 2)"),
+        ("a >>>= b | c", "(>>>= a (| b c))"),
+        ("a ^= b ? c : d", "(^= a (? b c d))"),
+        ("a[i + 1] * 2", "(* a[(+ i 1)] 2)"),
+        ("a.b[c].d", "a.b[c].d"),
+        ("a[b[c]](1)", "a[b[c]](1)"),
+        ("a[i] = b[j] || c", "(= a[i] (|| b[j] c))"),
+        ("a[i]++ + ++b[j]", "(+ (a[i] ++) (++ b[j]))"),
+        ("'a' + 'b' + 1", "(+ (+ \"a\" \"b\") 1)"),
+        ("a ? b : c", "(? a b c)"),
+        ("a ? b : c ? d : e", "(? a b (? c d e))"),
+        ("a ? b ? c : d : e", "(? a (? b c d) e)"),
+        ("a || b ? c + 1 : d && e", "(? (|| a b) (+ c 1) (&& d e))"),
+        ("x = a ? b : c", "(= x (? a b c))"),
+        ("a ? x = b : x = c", "(? a (= x b) (= x c))"),
+        ("a ||= b && c", "(||= a (&& b c))"),
+        ("a.b ??= c ?? d", "(??= a.b (?? c d))"),
+        ("a &&= b ||= c", "(&&= a (||= b c))"),
+        ("-a * b", "(* (- a) b)"),
+        ("!a && b", "(&& (! a) b)"),
+        ("- -a", "(- (- a))"),
+        ("typeof a.b === 'x'", "(=== (typeof a.b) \"x\")"),
+        ("typeof f(1)", "(typeof f(1))"),
+        ("delete a.b", "(delete a.b)"),
+        ("void 0 + 1", "(+ (void 0) 1)"),
+        ("2 ** -a", "(** 2 (- a))"),
+        ("+a ? -b : !c", "(? (+ a) (- b) (! c))"),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(tree(&parse(source).unwrap()), expected, "{}", source);
+    }
+}
+
+#[test]
+fn test_precedence_errors() {
+    let cases = vec!["a[1", "a[]", "a[1 2]", "-a[0] ** 2", "a + b += c", "1++", "++1", "++(a + b)", "a++ ++", "x ++ = 1", "-a ** 2", "!a = 1", "typeof", "a ? b", "a ? b c", "a ? : c", "a ?? b || c", "a || b ?? c", "a && b ?? c", "a ?? b && c", "1 = 2", "a + b = c", "1 +", "(1 + 2", "1 2"];
+    for source in cases {
+        assert!(parse(source).is_err(), "{}", source);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::parser::property_map::PropertyMap;
+use crate::parser::symbols::JSItem;
+
+#[cfg(test)]
+fn keys(map: &PropertyMap) -> Vec<String> {
+    map.keys().cloned().collect()
+}
+
+#[test]
+fn test_integer_keys_before_strings() {
+    let mut map = PropertyMap::new();
+    for key in vec!["b", "10", "a", "2", "0", "c"] {
+        map.insert(key.to_string(), JSItem::Undefined);
+    }
+    assert_eq!(keys(&map), vec!["0", "2", "10", "b", "a", "c"]);
+}
+
+#[test]
+fn test_non_index_keys_keep_insertion_order() {
+    let map = PropertyMap::from(vec![
+        ("01".to_string(), JSItem::Undefined),
+        ("4294967295".to_string(), JSItem::Undefined),
+        ("-1".to_string(), JSItem::Undefined),
+        ("1.5".to_string(), JSItem::Undefined),
+        ("7".to_string(), JSItem::Undefined)
+    ]);
+    assert_eq!(keys(&map), vec!["7", "01", "4294967295", "-1", "1.5"]);
+}
+
+#[test]
+fn test_overwrite_keeps_position() {
+    let mut map = PropertyMap::new();
+    map.insert("x".to_string(), JSItem::Number {value: 1.});
+    map.insert("y".to_string(), JSItem::Number {value: 2.});
+    let old = map.insert("x".to_string(), JSItem::Number {value: 3.});
+
+    assert_eq!(old, Some(JSItem::Number {value: 1.}));
+    assert_eq!(keys(&map), vec!["x", "y"]);
+    assert_eq!(map.get("x"), Some(&JSItem::Number {value: 3.}));
+}
+
+#[test]
+fn test_remove_and_reinsert_goes_last() {
+    let mut map = PropertyMap::new();
+    for key in vec!["x", "y", "z"] {
+        map.insert(key.to_string(), JSItem::Undefined);
+    }
+    assert_eq!(map.remove("x"), Some(JSItem::Undefined));
+    assert_eq!(map.remove("x"), None);
+    map.insert("x".to_string(), JSItem::Null);
+
+    assert_eq!(keys(&map), vec!["y", "z", "x"]);
+}
+
+#[test]
+fn test_lookups_follow_shifted_entries() {
+    let mut map = PropertyMap::new();
+    for (i, key) in vec!["a", "5", "b", "1", "c", "3"].into_iter().enumerate() {
+        map.insert(key.to_string(), JSItem::Number {value: i as f64});
+    }
+    assert_eq!(map.remove("1"), Some(JSItem::Number {value: 3.}));
+    assert_eq!(map.remove("b"), Some(JSItem::Number {value: 2.}));
+    map.insert("0".to_string(), JSItem::Number {value: 6.});
+
+    assert_eq!(keys(&map), vec!["0", "3", "5", "a", "c"]);
+    for (key, value) in vec![("0", 6.), ("3", 5.), ("5", 1.), ("a", 0.), ("c", 4.)] {
+        assert_eq!(map.get(key), Some(&JSItem::Number {value}));
+    }
+    assert!(!map.contains_key("b"));
+}
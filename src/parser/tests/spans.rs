@@ -0,0 +1,110 @@
+use crate::lexer::lexer::Lexer;
+use crate::parser::parser::Parser;
+use crate::lexer::span::Span;
+use crate::parser::symbols::{Expression, JSItem, Statement};
+
+#[test]
+fn test_statement_spans() {
+    let mut lex = Lexer::with_file("spans.js");
+    let mut parser = Parser::new();
+    let tokens = lex.lex(String::from("let a = 5;\nconsole.log(a);")).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    assert_eq!(js_items.len(), 2);
+    if let JSItem::St { statement, span } = js_items.get(0).unwrap() {
+        assert_eq!(span.to_string(), "spans.js:1:1");
+        assert_eq!((span.start, span.end), (0, 10));
+        if let Statement::AssignmentExpression { right: JSItem::Ex { span, .. }, .. } = statement.as_ref() {
+            assert_eq!((span.line, span.column), (1, 9));
+        } else {
+            panic!("expected an assignment");
+        }
+    } else {
+        panic!("expected a statement");
+    }
+
+    if let JSItem::Ex { span, .. } = js_items.get(1).unwrap() {
+        assert_eq!(span.to_string(), "spans.js:2:1");
+        assert_eq!((span.start, span.end), (11, 26));
+    } else {
+        panic!("expected an expression");
+    }
+}
+
+/// Span of a node as `(line, column, start, end)`, spans compare equal under `==` so the
+/// fields are checked one by one.
+#[allow(dead_code)]
+fn position(span: &Span) -> (usize, usize, usize, usize) {
+    (span.line, span.column, span.start, span.end)
+}
+
+#[test]
+fn test_operand_spans() {
+    let mut lex = Lexer::with_file("spans.js");
+    let mut parser = Parser::new();
+    let tokens = lex.lex(String::from("x = a + f(bc)\n  * -d;")).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    let right = match js_items.get(0).unwrap() {
+        JSItem::St { statement, .. } => match statement.as_ref() {
+            Statement::AssignmentExpression { right: JSItem::Ex { expression, .. }, .. } => expression.as_ref(),
+            _ => panic!("expected an assignment")
+        },
+        _ => panic!("expected a statement")
+    };
+    assert_eq!(position(&right.span()), (1, 5, 4, 20));
+    let (a, product) = match right {
+        Expression::Binop { a, b, .. } => (a.as_ref(), b.as_ref()),
+        _ => panic!("expected a + ...")
+    };
+    assert_eq!(position(&a.span()), (1, 5, 4, 5));
+    assert_eq!(a.span().to_string(), "spans.js:1:5");
+    assert_eq!(position(&product.span()), (1, 9, 8, 20));
+    let (call, negated) = match product {
+        Expression::Binop { a, b, .. } => (a.as_ref(), b.as_ref()),
+        _ => panic!("expected f(bc) * -d")
+    };
+    assert_eq!(position(&call.span()), (1, 9, 8, 13));
+    match call {
+        Expression::CallExpression { callee, .. } => assert_eq!(position(&callee.span()), (1, 9, 8, 9)),
+        _ => panic!("expected a call")
+    }
+    assert_eq!(position(&negated.span()), (2, 5, 18, 20));
+    match negated {
+        Expression::Unary { argument, .. } => assert_eq!(position(&argument.span()), (2, 6, 19, 20)),
+        _ => panic!("expected -d")
+    }
+}
+
+#[test]
+fn test_member_spans() {
+    let mut lex = Lexer::with_file("spans.js");
+    let mut parser = Parser::new();
+    let tokens = lex.lex(String::from("o.p[k](1);")).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    let call = match js_items.get(0).unwrap() {
+        JSItem::Ex { expression, .. } => expression.as_ref(),
+        _ => panic!("expected an expression")
+    };
+    assert_eq!(position(&call.span()), (1, 1, 0, 9));
+    let member = match call {
+        Expression::CallExpression { callee, .. } => callee.as_ref(),
+        _ => panic!("expected a call")
+    };
+    assert_eq!(position(&member.span()), (1, 1, 0, 6));
+    match member {
+        Expression::ComputedMemberExpression { object, property, .. } => {
+            assert_eq!(position(&property.span()), (1, 5, 4, 5));
+            match object.as_ref() {
+                Expression::MemberExpression { object, property, span } => {
+                    assert_eq!(position(span), (1, 1, 0, 3));
+                    assert_eq!(position(&object.span()), (1, 1, 0, 1));
+                    assert_eq!(position(&property.span()), (1, 3, 2, 3));
+                }
+                _ => panic!("expected o.p")
+            }
+        }
+        _ => panic!("expected o.p[k]")
+    }
+}
@@ -0,0 +1,53 @@
+use std::fs;
+use std::io::BufReader;
+use crate::lexer::lexer::Lexer;
+use crate::lexer::js_token::Tok;
+use crate::parser::parser::Parser;
+use crate::parser::asi::SemicolonInserter;
+use crate::parser::statements::Statements;
+
+#[cfg(test)]
+fn statements(source: &str) -> Vec<Vec<Tok>> {
+    let tokens = Lexer::new().lex(String::from(source)).unwrap();
+    Statements::new(SemicolonInserter::new(tokens.into_iter()))
+        .map(|statement| statement.into_iter().map(|token| token.tok).filter(|tok| *tok != Tok::EndOfLine).collect())
+        .collect()
+}
+
+#[test]
+fn test_split_at_statement_ends() {
+    assert_eq!(statements("a = 1\nb(c,\n  d)\nif (a) {\n}\nf()").len(), 4);
+    assert_eq!(statements("for (let i = 0; i < 2; i++) {\n}\n").len(), 1);
+    assert_eq!(statements("let o = {\n  a: 1\n}\no").len(), 2);
+}
+
+#[test]
+fn test_keep_else_catch_finally_and_do_while() {
+    assert_eq!(statements("if (a) {\n}\nelse {\n}\nb").len(), 2);
+    assert_eq!(statements("if (a) b\nelse c\nd").len(), 2);
+    assert_eq!(statements("try {\n}\ncatch (e) {\n}\nfinally {\n}\nb").len(), 2);
+    assert_eq!(statements("do {\n  a++\n}\nwhile (a < 3)\nb").len(), 2);
+}
+
+#[test]
+fn test_streamed_scripts_parse_like_whole_ones() {
+    let mut files = vec![];
+    for dir in fs::read_dir("js").unwrap() {
+        let dir = dir.unwrap().path();
+        if dir.is_dir() {
+            for file in fs::read_dir(dir).unwrap() {
+                files.push(file.unwrap().path());
+            }
+        }
+    }
+    for path in files.into_iter().filter(|path| path.extension().map_or(false, |ext| ext == "js")) {
+        let source = fs::read_to_string(&path).unwrap();
+        let whole = match Lexer::new().lex(source) {
+            Ok(tokens) => Parser::new().parse(tokens),
+            Err(_) => continue
+        };
+        let file = fs::File::open(&path).unwrap();
+        let tokens = Lexer::from_reader("<anonymous>", BufReader::new(file)).map(|token| token.unwrap());
+        assert_eq!(Parser::new().parse_tokens(tokens), whole, "{}", path.display());
+    }
+}
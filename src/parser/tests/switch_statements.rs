@@ -0,0 +1,46 @@
+use crate::lexer::span::Span;
+use crate::parser::symbols::{Expression, JSItem, Statement, SwitchCase};
+use crate::parser::tests::parse;
+
+#[cfg(test)]
+fn ex(expression: Expression) -> JSItem {
+    JSItem::Ex { expression: Box::new(expression), span: Span::default() }
+}
+
+#[test]
+fn test_switch_cases() {
+    let js_items = parse("switch (x) {\n    case 1:\n    case 2:\n        y\n        break\n    default:\n        z\n}\n");
+    assert_eq!(js_items, vec![JSItem::St {
+        statement: Box::new(Statement::Switch {
+            discriminant: ex(Expression::Identifier { name: "x".to_string(), span: Span::default() }),
+            cases: vec![
+                SwitchCase { test: Some(ex(Expression::Number { value: 1., span: Span::default() })), consequent: vec![] },
+                SwitchCase {
+                    test: Some(ex(Expression::Number { value: 2., span: Span::default() })),
+                    consequent: vec![
+                        ex(Expression::Identifier { name: "y".to_string(), span: Span::default() }),
+                        JSItem::St { statement: Box::new(Statement::Break { label: None }), span: Span::default() }
+                    ]
+                },
+                SwitchCase { test: None, consequent: vec![ex(Expression::Identifier { name: "z".to_string(), span: Span::default() })] }
+            ]
+        }),
+        span: Span::default()
+    }]);
+}
+
+#[test]
+fn test_switch_followed_by_statement() {
+    let js_items = parse("switch (x) { case a + 1: y(1, 2) }\nz\n");
+    assert_eq!(js_items.len(), 2);
+    match &js_items[0] {
+        JSItem::St { statement, .. } => match &**statement {
+            Statement::Switch { cases, .. } => {
+                assert_eq!(cases.len(), 1);
+                assert_eq!(cases[0].consequent.len(), 1);
+            }
+            statement => panic!("{:?}", statement)
+        },
+        item => panic!("{:?}", item)
+    }
+}
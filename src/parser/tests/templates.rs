@@ -0,0 +1,99 @@
+use crate::lexer::lexer::Lexer;
+use std::fs;
+use crate::parser::parser::Parser;
+use crate::parser::symbols::{Expression, Statement, Operator, AssignOp};
+use crate::parser::symbols::JSItem;
+use crate::lexer::span::Span;
+
+#[test]
+fn test_multi_line_template() {
+    let file = fs::read_to_string("js/templates/multi_line.js");
+
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    assert_eq!(js_items.len(), 2);
+    let assignment = js_items.get(0).unwrap();
+    assert!(assignment.eq(&JSItem::St {
+        statement: Box::new(Statement::AssignmentExpression {
+            operator: AssignOp::Let,
+            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "first".to_string(), span: Span::default()}), span: Span::default()},
+            right: JSItem::Ex {
+                expression: Box::from(Expression::TemplateLiteral {
+                    cooked: vec!["line one\nline two".to_string()],
+                    raw: vec!["line one\nline two".to_string()],
+                    expressions: vec![],
+                    span: Span::default()
+                }),
+                span: Span::default()
+            }
+        }),
+        span: Span::default()
+    }))
+}
+
+#[test]
+fn test_template_assignment() {
+    let file = fs::read_to_string("js/templates/assignment.js");
+
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    assert_eq!(js_items.len(), 3);
+    let assignment = js_items.get(1).unwrap();
+    assert!(assignment.eq(&JSItem::St {
+        statement: Box::new(Statement::AssignmentExpression {
+            operator: AssignOp::Let,
+            left: JSItem::Ex {expression: Box::from(Expression::Literal {value: "s".to_string(), span: Span::default()}), span: Span::default()},
+            right: JSItem::Ex {
+                expression: Box::from(Expression::TemplateLiteral {
+                    cooked: vec!["n is ".to_string(), "".to_string()],
+                    raw: vec!["n is ".to_string(), "".to_string()],
+                    expressions: vec![JSItem::Ex {
+                        expression: Box::from(Expression::Binop {
+                            a: Box::from(Expression::Identifier {name: "n".to_string(), span: Span::default()}),
+                            op: Operator::Mult,
+                            b: Box::from(Expression::Number {value: 2., span: Span::default()}),
+                            span: Span::default()
+                        }),
+                        span: Span::default()
+                    }],
+                    span: Span::default()
+                }),
+                span: Span::default()
+            }
+        }),
+        span: Span::default()
+    }))
+}
+
+#[test]
+fn test_template_binop() {
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex(String::from("`a${b}` + \"c\";")).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    assert_eq!(js_items.len(), 1);
+    assert!(js_items.get(0).unwrap().eq(&JSItem::Ex {
+        expression: Box::from(Expression::Binop {
+            a: Box::from(Expression::TemplateLiteral {
+                cooked: vec!["a".to_string(), "".to_string()],
+                raw: vec!["a".to_string(), "".to_string()],
+                expressions: vec![JSItem::Ex {
+                    expression: Box::from(Expression::Identifier {name: "b".to_string(), span: Span::default()}),
+                    span: Span::default()
+                }],
+                span: Span::default()
+            }),
+            op: Operator::Add,
+            b: Box::from(Expression::Literal {value: "c".to_string(), span: Span::default()}),
+            span: Span::default()
+        }),
+        span: Span::default()
+    }))
+}
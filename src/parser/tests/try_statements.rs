@@ -0,0 +1,60 @@
+use crate::lexer::span::Span;
+use crate::parser::symbols::{Expression, JSItem, Operator, Statement, CatchClause};
+use crate::parser::tests::{parse, st};
+
+#[cfg(test)]
+fn ident(name: &str) -> JSItem {
+    JSItem::Ex { expression: Box::new(Expression::Identifier { name: name.to_string(), span: Span::default() }), span: Span::default() }
+}
+
+#[test]
+fn test_try_catch_finally() {
+    let js_items = parse("try {\n    a\n} catch (e) {\n    b\n}\nfinally {\n    c\n}\nd\n");
+    assert_eq!(js_items, vec![
+        st(Statement::Try {
+            block: vec![ident("a")],
+            handler: Some(CatchClause { param: Some("e".to_string()), body: vec![ident("b")] }),
+            finalizer: Some(vec![ident("c")])
+        }),
+        ident("d")
+    ]);
+}
+
+#[test]
+fn test_optional_catch_binding() {
+    let js_items = parse("try { a } catch { b }");
+    assert_eq!(js_items, vec![st(Statement::Try {
+        block: vec![ident("a")],
+        handler: Some(CatchClause { param: None, body: vec![ident("b")] }),
+        finalizer: None
+    })]);
+}
+
+#[test]
+fn test_try_finally() {
+    let js_items = parse("try { a } finally { b }");
+    assert_eq!(js_items, vec![st(Statement::Try {
+        block: vec![ident("a")],
+        handler: None,
+        finalizer: Some(vec![ident("b")])
+    })]);
+}
+
+#[test]
+fn test_throw() {
+    let js_items = parse("throw a + 1;\nb\n");
+    assert_eq!(js_items, vec![
+        st(Statement::Throw {
+            value: Box::new(JSItem::Ex {
+                expression: Box::new(Expression::Binop {
+                    a: Box::new(Expression::Identifier { name: "a".to_string(), span: Span::default() }),
+                    op: Operator::Add,
+                    b: Box::new(Expression::Number { value: 1., span: Span::default() }),
+                    span: Span::default()
+                }),
+                span: Span::default()
+            })
+        }),
+        ident("b")
+    ]);
+}
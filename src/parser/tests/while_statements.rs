@@ -0,0 +1,81 @@
+use crate::lexer::span::Span;
+use crate::parser::symbols::{Expression, JSItem, Operator, Statement, AssignOp};
+use crate::parser::tests::parse;
+
+#[cfg(test)]
+fn decrement(name: &str) -> JSItem {
+    JSItem::St {
+        statement: Box::new(Statement::AssignmentExpression {
+            operator: AssignOp::None,
+            left: JSItem::Ex { expression: Box::new(Expression::Literal { value: name.to_string(), span: Span::default() }), span: Span::default() },
+            right: JSItem::Ex {
+                expression: Box::new(Expression::Binop {
+                    a: Box::new(Expression::Identifier { name: name.to_string(), span: Span::default() }),
+                    op: Operator::Sub,
+                    b: Box::new(Expression::Number { value: 1., span: Span::default() }),
+                    span: Span::default()
+                }),
+                span: Span::default()
+            }
+        }),
+        span: Span::default()
+    }
+}
+
+#[test]
+fn test_while_block() {
+    let js_items = parse("while (n > 0) {\n    n = n - 1\n}\n");
+    assert_eq!(js_items, vec![JSItem::St {
+        statement: Box::new(Statement::While {
+            test: JSItem::Ex {
+                expression: Box::new(Expression::Binop {
+                    a: Box::new(Expression::Identifier { name: "n".to_string(), span: Span::default() }),
+                    op: Operator::Greater,
+                    b: Box::new(Expression::Number { value: 0., span: Span::default() }),
+                    span: Span::default()
+                }),
+                span: Span::default()
+            },
+            body: vec![decrement("n")]
+        }),
+        span: Span::default()
+    }]);
+}
+
+#[test]
+fn test_while_single_statement() {
+    let js_items = parse("while (n) n = n - 1\nn\n");
+    assert_eq!(js_items.len(), 2);
+    assert_eq!(js_items[0], JSItem::St {
+        statement: Box::new(Statement::While {
+            test: JSItem::Ex { expression: Box::new(Expression::Identifier { name: "n".to_string(), span: Span::default() }), span: Span::default() },
+            body: vec![decrement("n")]
+        }),
+        span: Span::default()
+    });
+}
+
+#[test]
+fn test_while_empty_body() {
+    let js_items = parse("while (false) {}\n");
+    assert_eq!(js_items, vec![JSItem::St {
+        statement: Box::new(Statement::While {
+            test: JSItem::Ex { expression: Box::new(Expression::False { span: Span::default() }), span: Span::default() },
+            body: vec![]
+        }),
+        span: Span::default()
+    }]);
+}
+
+#[test]
+fn test_do_while() {
+    let expected = JSItem::St {
+        statement: Box::new(Statement::DoWhile {
+            body: vec![decrement("n")],
+            test: JSItem::Ex { expression: Box::new(Expression::Identifier { name: "n".to_string(), span: Span::default() }), span: Span::default() }
+        }),
+        span: Span::default()
+    };
+    assert_eq!(parse("do {\n    n = n - 1\n} while (n)\n"), vec![expected.clone()]);
+    assert_eq!(parse("do n = n - 1; while (n);"), vec![expected]);
+}
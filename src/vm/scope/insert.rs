@@ -1,4 +1,5 @@
-use crate::parser::symbols::{JSItem};
+use crate::parser::symbols::{JSItem, regexp_property, array_property};
+use crate::parser::property_map::PropertyMap;
 use crate::vm::vm::Vm;
 
 pub(crate) enum ObjecResult {
@@ -28,38 +29,35 @@ pub(crate) fn add_to_located_obj(vm: &mut Vm, scope_num: usize, location: String
     return path;
 }
 
-pub(crate) fn locate_obj_props(vm: &mut Vm, name: String, obj: JSItem) -> JSItem {
+/// Moves the values written into an object literal out to `vm.objects` under `path`, the scope
+/// and name the object is being stored as, leaving references to them in its properties.
+/// Nested literals are moved under their property's path, and properties that already refer to
+/// a stored value keep sharing it.
+pub(crate) fn locate_obj_props(vm: &mut Vm, path: Vec<String>, obj: JSItem) -> JSItem {
     match obj {
         JSItem::Object { mutable, mut properties } => {
-            let mut keys = vec![];
-
-            for key in properties.keys() {
-                keys.push(key.clone());
-            }
-
+            let keys = properties.keys().cloned().collect::<Vec<String>>();
+            let scope = path[0].parse::<usize>().unwrap_or(vm.scopes.len() - 1);
             for key in keys {
-                if let JSItem::ObjectReference { mut path } = properties.remove(&key.clone()).unwrap() {
-                    let path_key = path.join(":");
-                    let scope_end = vm.scopes.len() - 1;
-                    if let Some(real_path) = vm.scopes.get_mut(scope_end).unwrap().remove(&path_key) {
-                        if let JSItem::Located { scope, location:_, object } = vm.objects.remove(&real_path).unwrap() {
-                            path.remove(0);
-                            path.insert(0, name.clone());
-                            path.insert(0, scope_end.to_string());
-                            vm.objects.insert(path.join(":"), JSItem::Located {
-                                scope,
-                                location: path.join(":"),
-                                object
-                            });
-
-                            properties.insert(key, JSItem::ObjectReference { path });
-                        }
-                    }
+                let value = properties.get(&key).unwrap().clone();
+                if let JSItem::ObjectReference { .. } = value {
+                    continue;
                 }
+                let mut item_path = path.clone();
+                item_path.push(key.clone());
+                let object = locate_obj_props(vm, item_path.clone(), value);
+                let object_key = item_path.join(":");
+                vm.scopes.get_mut(scope).unwrap().insert(item_path[1..].join(":"), object_key.clone());
+                vm.objects.insert(object_key.clone(), JSItem::Located {
+                    scope,
+                    location: object_key,
+                    object: Box::new(object)
+                });
+                properties.insert(key, JSItem::ObjectReference { path: item_path });
             }
-            JSItem::Object {mutable, properties}
+            JSItem::Object { mutable, properties }
         }
-        _ => JSItem::Null
+        obj => obj
     }
 }
 
@@ -82,19 +80,63 @@ pub(crate) fn set_object(vm: &mut Vm, mut path: Vec<String>, obj: JSItem, overwr
     return Ok(ObjecResult::Success);
 }
 
+/// Replaces the value of `path` in the nearest scope that declares it, false if none does.
+pub(crate) fn assign_object(vm: &mut Vm, path: Vec<String>, obj: JSItem) -> bool {
+    let path_key = path.join(":");
+    for i in (0..vm.scopes.len()).rev() {
+        if let Some(object_key) = vm.scopes.get(i).unwrap().get(&path_key).cloned() {
+            vm.objects.insert(object_key.clone(), JSItem::Located {
+                scope: i,
+                location: object_key,
+                object: Box::new(obj),
+            });
+            return true;
+        }
+    }
+    return false;
+}
+
+/// Takes the object stored at `object_key`. One that is already out on the stack, loaded by an
+/// expression whose value nothing used, is copied from there. None once its scope has ended.
+fn take_object(vm: &mut Vm, object_key: &String) -> Option<JSItem> {
+    match vm.objects.remove(object_key) {
+        Some(item) => Some(item),
+        None => vm.stack.iter().rev()
+            .find(|item| match item {
+                JSItem::Located { location, .. } => location == object_key,
+                _ => false
+            })
+            .cloned()
+    }
+}
+
 pub(crate) fn load_object(vm: &mut Vm, mut path: Vec<String>) -> Result<ObjecResult, ObjecResult> {
     let path_key = path.join(":");
     for i in (0..vm.scopes.len()).rev() {
         if vm.scopes.get(i).unwrap().contains_key(&path_key) {
-            let object_key = vm.scopes.get(i).unwrap().get(&path_key).unwrap();
-            let item = vm.objects.remove(object_key).unwrap();
-            vm.stack.push(item);
-            return Ok(ObjecResult::Success);
+            let object_key = vm.scopes.get(i).unwrap().get(&path_key).unwrap().clone();
+            return match take_object(vm, &object_key) {
+                Some(item) => {
+                    vm.stack.push(item);
+                    Ok(ObjecResult::Success)
+                }
+                None => Err(ObjecResult::Error)
+            };
         }
     }
     return Err(ObjecResult::Error);
 }
 
+/// The value of `prop` in `properties`: what a reference points to, or a value written into an
+/// object literal that hasn't been stored yet. Undefined when there is neither.
+fn property_value(vm: &mut Vm, properties: &PropertyMap, prop: &str) -> JSItem {
+    match properties.get(prop) {
+        Some(JSItem::ObjectReference { path }) => take_object(vm, &path.join(":")).unwrap_or(JSItem::Undefined),
+        Some(value) => value.clone(),
+        None => JSItem::Undefined
+    }
+}
+
 pub(crate) fn load_prop(vm: &mut Vm, prop: String) -> Result<ObjecResult, ObjecResult> {
     if vm.stack.len() == 0 {
         return Err(ObjecResult::Error);
@@ -102,31 +144,44 @@ pub(crate) fn load_prop(vm: &mut Vm, prop: String) -> Result<ObjecResult, ObjecR
     let item = vm.stack.pop().unwrap();
     match item {
         JSItem::Located { scope, location, object } => {
-            match *object {
+            let object = match *object {
                 JSItem::Object { mutable, properties } => {
-                    if let Some(item) = properties.get(&prop) {
-                        match item {
-                            JSItem::ObjectReference { path } => {
-                                let new_item_key = path.join(":");
-                                let item1 = vm.objects.remove(&new_item_key).unwrap();
-                                vm.stack.push(item1);
-                                vm.objects.insert(location.clone(), JSItem::Located {
-                                    scope,
-                                    location,
-                                    object: Box::from(JSItem::Object { mutable, properties })
-                                });
-                                return Ok(ObjecResult::Success);
-                            }
-                            _ => {
-                                return Err(ObjecResult::Error);
-                            }
-                        }
-                    }
+                    let value = property_value(vm, &properties, &prop);
+                    vm.stack.push(value);
+                    JSItem::Object { mutable, properties }
                 }
-                _ => {return Err(ObjecResult::Error);}
-            }
+                JSItem::RegExp { pattern, flags } => {
+                    vm.stack.push(regexp_property(&pattern, &flags, &prop));
+                    JSItem::RegExp { pattern, flags }
+                }
+                JSItem::Array { items, properties } => {
+                    vm.stack.push(array_property(&items, &properties, &prop));
+                    JSItem::Array { items, properties }
+                }
+                object => {
+                    vm.stack.push(JSItem::Undefined);
+                    object
+                }
+            };
+            vm.objects.insert(location.clone(), JSItem::Located {
+                scope,
+                location,
+                object: Box::from(object)
+            });
+        }
+        JSItem::Object { properties, .. } => {
+            let value = property_value(vm, &properties, &prop);
+            vm.stack.push(value);
+        }
+        JSItem::RegExp { pattern, flags } => {
+            vm.stack.push(regexp_property(&pattern, &flags, &prop));
+        }
+        JSItem::Array { items, properties } => {
+            vm.stack.push(array_property(&items, &properties, &prop));
+        }
+        _ => {
+            vm.stack.push(JSItem::Undefined);
         }
-        _ => {return Err(ObjecResult::Error);}
     }
     return Ok(ObjecResult::Success);
-}
\ No newline at end of file
+}
@@ -1,3 +1,4 @@
+use crate::parser::property_map::PropertyMap;
 use crate::parser::symbols::{JSItem, StdFun, Expression};
 use crate::lexer::js_token::Tok;
 use crate::ast_interpreter::interpreter::Interpreter;
@@ -26,9 +27,7 @@ pub(crate) fn array_constructor(mut arguments: Vec<JSItem>) -> Result<JSItem, ()
 
                                     Ok(JSItem::Array {
                                         items,
-                                        properties: hashmap!{
-                                            "length".to_string() => JSItem::Number {value: len as f64}
-                                        }
+                                        properties: PropertyMap::from(vec![("length".to_string(), JSItem::Number {value: len as f64})])
                                     })
                                 }
                                 _ => {
@@ -1,3 +1,4 @@
+use crate::parser::property_map::PropertyMap;
 use crate::parser::symbols::{JSItem, StdFun};
 use std::fmt::{Display, Formatter, Result};
 use crate::lexer::js_token::Tok;
@@ -29,9 +30,7 @@ pub(crate) fn create_console(mut vm: Vm) -> Vm {
     }, true) {
         if let Ok(..) = set_object(&mut vm, vec!["console".to_string()], JSItem::Object {
             mutable: false,
-            properties: hashmap![
-                "log".to_string() => JSItem::ObjectReference {path: vec!["0:console:log".to_string()]}
-            ]
+            properties: PropertyMap::from(vec![("log".to_string(), JSItem::ObjectReference {path: vec!["0:console:log".to_string()]})])
         }, true) {
             return vm;
         }
@@ -1,5 +1,5 @@
 use crate::parser::symbols::JSItem;
-use std::collections::HashMap;
+use crate::parser::property_map::PropertyMap;
 use crate::vm::vm::Vm;
 
 
@@ -23,14 +23,14 @@ pub(crate) fn inherit(vm: &Vm, from: JSItem, to: JSItem) -> JSItem {
     };
 
     if let Some(from_item) = find_vm_object(vm, from_key_temp) {
-        let mut new_prototype = HashMap::new();
+        let mut new_prototype = PropertyMap::new();
         new_prototype.insert("constructor".to_string(), to.clone());
 
 
     }
 
 
-    let mut new_prototype = HashMap::new();
+    let mut new_prototype = PropertyMap::new();
     new_prototype.insert("constructor".to_string(), to.clone());
 
     let mut from_vec: Vec<String> = vec![];
@@ -70,7 +70,7 @@ pub(crate) fn inherit(vm: &Vm, from: JSItem, to: JSItem) -> JSItem {
             }
             _ => {}
         }
-        let mut new_properties = HashMap::new();
+        let mut new_properties = PropertyMap::new();
         new_properties.insert("prototype".to_string(), JSItem::Object {
             mutable: false,
             properties: new_prototype
@@ -1,25 +1,39 @@
-use std::collections::HashMap;
-use crate::parser::symbols::{JSItem};
+use crate::parser::property_map::PropertyMap;
+use crate::parser::symbols::{JSItem, StdFun};
+use crate::lexer::js_token::Tok;
 use crate::vm::vm::Vm;
 use crate::vm::scope::insert::set_object;
 
+/// The global `undefined`, a name like any other as far as `Load` is concerned.
+#[allow(unused_must_use)]
+pub(crate) fn create_undefined(mut vm: Vm) -> Vm {
+    set_object(&mut vm, vec!["undefined".to_string()], JSItem::Undefined, true);
+    vm
+}
+
 pub(crate) fn create_object(mut vm: Vm) -> Vm {
-    let mut object_prototype = HashMap::new();
+    let mut object_prototype = PropertyMap::new();
     object_prototype.insert("constructor".to_string(), JSItem::ObjectReference {path: vec!["0:Object".to_string()]});
 
-    let mut object_properties = HashMap::new();
+    let mut object_properties = PropertyMap::new();
     object_properties.insert("prototype".to_string(), JSItem::Object {
         mutable: false,
         properties: object_prototype
     });
 
     object_properties.insert("__proto__".to_string(), JSItem::ObjectReference {path: vec!["0:Object".to_string()]});
+    object_properties.insert("keys".to_string(), JSItem::ObjectReference {path: vec!["0:Object:keys".to_string()]});
 
-    if let Ok(..) = set_object(&mut vm, vec!["Object".to_string()], JSItem::Object {
-        mutable: false,
-        properties: object_properties
+    if let Ok(..) = set_object(&mut vm, vec!["Object".to_string(), "keys".to_string()], JSItem::Std {
+        params: vec![Tok::Name {name: "obj".to_string()}],
+        func: StdFun::ObjectKeys
     }, true) {
-        return vm;
+        if let Ok(..) = set_object(&mut vm, vec!["Object".to_string()], JSItem::Object {
+            mutable: false,
+            properties: object_properties
+        }, true) {
+            return vm;
+        }
     }
 
     return vm;
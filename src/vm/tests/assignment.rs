@@ -0,0 +1,10 @@
+
+use crate::parser::symbols::JSItem;
+use crate::vm::tests::run_file;
+
+#[test]
+fn test_chained_assignment() {
+    assert_eq!(run_file("js/variables/chained_assignment.js").captured_output, vec![
+        vec![JSItem::Number { value: 5. }, JSItem::Number { value: 5. }]
+    ]);
+}
@@ -0,0 +1,68 @@
+use crate::lexer::lexer::Lexer;
+use crate::parser::parser::Parser;
+use crate::compiler::compiler::Compiler;
+use crate::compiler::op_codes::Op;
+use crate::parser::symbols::JSItem;
+use crate::vm::tests::{run_file, string};
+
+#[test]
+fn test_try_catch_finally() {
+    let vm = run_file("js/exceptions/try_catch.js");
+    assert_eq!(vm.captured_output, vec![
+        vec![string("caught"), string("deep")],
+        vec![string("no binding")],
+        vec![string("finally")],
+        vec![string("inner finally")],
+        vec![string("outer"), string("inner")],
+        vec![string("cleanup"), JSItem::Number { value: 1. }],
+        vec![string("cleanup"), JSItem::Number { value: 2. }],
+        vec![string("ReferenceError"), string("missing is not defined")],
+        vec![string("done")]
+    ]);
+    assert!(vm.captured_errors.is_empty());
+}
+
+#[test]
+fn test_uncaught_exception() {
+    let vm = run_file("js/exceptions/uncaught.js");
+    assert_eq!(vm.captured_output, vec![vec![string("before")]]);
+    assert_eq!(vm.captured_errors, vec!["Uncaught oops\n    at <anonymous>:2:1".to_string()]);
+}
+
+#[test]
+fn test_uncaught_in_function() {
+    let vm = run_file("js/exceptions/uncaught_in_function.js");
+    assert_eq!(vm.captured_output, vec![vec![string("before")]]);
+    assert_eq!(vm.captured_errors, vec!["Uncaught bad\n    at <anonymous>:4:5".to_string()]);
+}
+
+#[test]
+fn test_try_catch_bytecode() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("try { throw x } catch (e) { y }")).unwrap();
+    let mut parser = Parser::new();
+    let mut js_items = parser.parse(tokens).unwrap();
+
+    let mut com = Compiler::new();
+    com.compile(js_items.pop().unwrap());
+    assert_eq!(com.bc_ins, vec![
+        Op::SetupTry { handler: 5 },
+        Op::Load { name: "x".to_string() },
+        Op::Throw,
+        Op::PopTry,
+        Op::JumpAbsolute { to: 11 },
+        Op::SetupLoop,
+        Op::Store { name: "e".to_string() },
+        Op::Load { name: "y".to_string() },
+        Op::PopTop,
+        Op::PopBlock,
+        Op::JumpAbsolute { to: 11 }
+    ]);
+}
+
+#[test]
+fn test_finally_return_overrides() {
+    let vm = run_file("js/exceptions/finally_return.js");
+    assert_eq!(vm.captured_output, vec![vec![JSItem::Number { value: 2. }], vec![string("finally")]]);
+    assert!(vm.captured_errors.is_empty());
+}
@@ -12,8 +12,8 @@ fn test_simple_for() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -38,14 +38,35 @@ fn test_simple_for() {
     ]))
 }
 
+#[test]
+fn test_for_loop_through_array() {
+    let file = fs::read_to_string("js/if_while_for/for_loop_through_array.js");
+
+    let mut lex = Lexer::new();
+    let mut parser = Parser::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let js_items = parser.parse(tokens).unwrap();
+
+    let mut compiler = Compiler::new();
+    for item in js_items {
+        compiler.compile(item);
+    }
+
+    let mut vm = Vm::new();
+    let out = vm.run(compiler.bc_ins);
+
+    assert_eq!(out, JSItem::Undefined);
+    assert_eq!(vm.captured_output, (1..=7).map(|value| vec![JSItem::Number {value: value as f64}]).collect::<Vec<_>>());
+}
+
 #[test]
 fn test_nested_for() {
     let file = fs::read_to_string("js/if_while_for/nested_for.js");
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -91,8 +112,8 @@ fn test_function_nested_for() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     for item in js_items {
@@ -140,8 +161,8 @@ fn test_function_for_scope() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     for item in js_items {
@@ -189,8 +210,8 @@ fn test_for_if() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     for item in js_items {
@@ -233,8 +254,8 @@ fn test_nested_for_mul() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     for item in js_items {
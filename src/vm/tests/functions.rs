@@ -1,6 +1,7 @@
 use crate::lexer::lexer::Lexer;
 use crate::parser::parser::Parser;
 use crate::compiler::compiler::Compiler;
+use crate::compiler::op_codes::Op;
 use crate::vm::vm::Vm;
 use crate::parser::symbols::JSItem;
 use std::fs;
@@ -8,9 +9,9 @@ use std::fs;
 #[test]
 fn test_console_dot_log() {
     let mut lex = Lexer::new();
-    let tokens = lex.lex(String::from("console.log(\"hi\");"));
+    let tokens = lex.lex(String::from("console.log(\"hi\");")).unwrap();
     let mut parser = Parser::new();
-    let mut js_items = parser.parse(tokens);
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     com.compile(js_items.pop().unwrap());
@@ -30,9 +31,9 @@ fn test_function_call() {
     let file = fs::read_to_string("js/functions/function_call.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
     let mut parser = Parser::new();
-    let mut js_items = parser.parse(tokens);
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     for item in js_items {
@@ -54,9 +55,9 @@ fn test_function_call_with_arg() {
     let file = fs::read_to_string("js/functions/function_call_with_arg.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
     let mut parser = Parser::new();
-    let mut js_items = parser.parse(tokens);
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     for item in js_items {
@@ -78,9 +79,9 @@ fn test_function_function_call_with_args() {
     let file = fs::read_to_string("js/functions/function_function_call_with_args.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
     let mut parser = Parser::new();
-    let mut js_items = parser.parse(tokens);
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     for item in js_items {
@@ -102,9 +103,9 @@ fn test_let_function_call() {
     let file = fs::read_to_string("js/functions/let_function_call.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
     let mut parser = Parser::new();
-    let mut js_items = parser.parse(tokens);
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     for item in js_items {
@@ -119,4 +120,47 @@ fn test_let_function_call() {
     assert_eq!(vm.captured_output, vec![
         vec![JSItem::String {value: "hi".to_string()}]
     ]);
-}
\ No newline at end of file
+}
+#[test]
+fn test_return() {
+    let file = fs::read_to_string("js/functions/return.js");
+
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut parser = Parser::new();
+    let js_items = parser.parse(tokens).unwrap();
+
+    let mut com = Compiler::new();
+    for item in js_items {
+        com.compile(item);
+    }
+
+    let mut vm = Vm::new();
+    let out = vm.run(com.bc_ins);
+
+    assert_eq!(out, JSItem::Undefined);
+    assert!(vm.stack.is_empty());
+    assert_eq!(vm.captured_output, vec![
+        vec![JSItem::Number {value: 1.}],
+        vec![JSItem::String {value: "finally".to_string()}],
+        vec![JSItem::String {value: "try".to_string()}],
+        vec![JSItem::Number {value: 10.}],
+        vec![JSItem::Number {value: 2.}],
+        vec![JSItem::Undefined],
+        vec![JSItem::Number {value: 2.}]
+    ]);
+}
+
+#[test]
+fn test_stack_underflow_is_thrown() {
+    let mut vm = Vm::new();
+    vm.run(vec![
+        Op::SetupTry { handler: 3 },
+        Op::Add,
+        Op::PopTry,
+        Op::Store { name: "e".to_string() },
+        Op::Add
+    ]);
+
+    assert_eq!(vm.captured_errors, vec!["Uncaught InternalError: stack underflow running Add".to_string()]);
+}
@@ -0,0 +1,54 @@
+use crate::compiler::op_codes::Op;
+use crate::compiler::to_bytes::{to_bytes, from_bytes};
+use crate::parser::symbols::JSItem;
+use crate::vm::tests::run_file;
+
+#[test]
+fn test_template_interpolation() {
+    assert_eq!(run_file("js/templates/simple.js").captured_output, vec![
+        vec![JSItem::String {value: String::from("hello world!")}]
+    ]);
+}
+
+#[test]
+fn test_template_assignment() {
+    assert_eq!(run_file("js/templates/assignment.js").captured_output, vec![
+        vec![JSItem::String {value: String::from("n is 8")}]
+    ]);
+}
+
+#[test]
+fn test_nested_template() {
+    assert_eq!(run_file("js/templates/nested.js").captured_output, vec![
+        vec![JSItem::String {value: String::from("out and in done")}]
+    ]);
+}
+
+#[test]
+fn test_build_string_bytes() {
+    let ops = vec![
+        Op::LoadStrConst {value: String::from("a")},
+        Op::LoadNumConst {value: 1.},
+        Op::BuildString {count: 2}
+    ];
+    assert_eq!(from_bytes(to_bytes(ops.clone())), ops);
+}
+
+#[test]
+fn test_regex_literals() {
+    assert_eq!(run_file("js/strings/regex.js").captured_output, vec![
+        vec![JSItem::RegExp {pattern: String::from("ab+c"), flags: String::from("gi")}],
+        vec![JSItem::String {value: String::from("ab+c")}],
+        vec![JSItem::Number {value: 1.}],
+        vec![JSItem::String {value: String::from("m")}]
+    ]);
+}
+
+#[test]
+fn test_load_regexp_bytes() {
+    let ops = vec![
+        Op::LoadRegExp {pattern: String::from("a+"), flags: String::from("g")},
+        Op::LoadStrConst {value: String::from("b")}
+    ];
+    assert_eq!(from_bytes(to_bytes(ops.clone())), ops);
+}
@@ -10,8 +10,8 @@ use std::f64::{INFINITY};
 fn test_simple_add() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("1 + 2 + 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("1 + 2 + 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -25,8 +25,8 @@ fn test_simple_add() {
 fn test_simple_sub() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 - 2"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 - 2")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -40,8 +40,8 @@ fn test_simple_sub() {
 fn test_simple_mul() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 * 2"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 * 2")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -55,8 +55,8 @@ fn test_simple_mul() {
 fn test_simple_div() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 / 2"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 / 2")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -70,8 +70,8 @@ fn test_simple_div() {
 fn test_add_sub() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 + 2 - 1"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 + 2 - 1")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -85,8 +85,8 @@ fn test_add_sub() {
 fn test_add_sub_expression() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("(3 + 2) - 1"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("(3 + 2) - 1")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -100,8 +100,8 @@ fn test_add_sub_expression() {
 fn test_sub_add_expression() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 - (2 + 1)"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 - (2 + 1)")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -115,8 +115,8 @@ fn test_sub_add_expression() {
 fn test_add_mul() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 + 2 * 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 + 2 * 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -130,8 +130,8 @@ fn test_add_mul() {
 fn test_mul_add() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 * 2 + 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 * 2 + 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -145,8 +145,8 @@ fn test_mul_add() {
 fn test_mul_add_expression() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("3 * (2 + 3)"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("3 * (2 + 3)")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -160,8 +160,8 @@ fn test_mul_add_expression() {
 fn test_mul_expression_add() {
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(String::from("(3 * 2) + 3"));
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(String::from("(3 * 2) + 3")).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     compiler.compile(js_items.pop().unwrap());
@@ -177,8 +177,8 @@ fn test_and1() {
 
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 2);
 
@@ -198,8 +198,8 @@ fn test_triple_equal() {
     let file = fs::read_to_string("js/math/triple_eq.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     assert_eq!(js_items.len(), 2);
 
@@ -219,8 +219,8 @@ fn test_div_nan1() {
     let file = fs::read_to_string("js/math/div/div_nan1.js");
     let mut lex = Lexer::new();
     let mut parser = Parser::new();
-    let tokens = lex.lex(file.unwrap());
-    let mut js_items = parser.parse(tokens);
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut compiler = Compiler::new();
     for item in js_items {
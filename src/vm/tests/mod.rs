@@ -1,5 +1,47 @@
+#![allow(unused_imports)]
 mod math;
 mod for_loop;
 mod functions;
 mod objects;
-mod if_statements;
\ No newline at end of file
+mod if_statements;
+mod literals;
+mod assignment;
+mod while_loop;
+mod switch_statement;
+mod exceptions;
+mod expressions;
+
+
+use std::fs;
+use crate::lexer::lexer::Lexer;
+use crate::parser::parser::Parser;
+use crate::compiler::compiler::Compiler;
+use crate::vm::vm::Vm;
+use crate::parser::symbols::JSItem;
+
+/// Compiles and runs a script, the VM is handed back for what it logged and threw.
+#[allow(dead_code)]
+pub(crate) fn run_file(file_name: &str) -> Vm {
+    let file = fs::read_to_string(file_name);
+
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(file.unwrap()).unwrap();
+    let mut parser = Parser::new();
+    let js_items = parser.parse(tokens).unwrap();
+
+    let mut com = Compiler::new();
+    for item in js_items {
+        com.compile(item);
+    }
+
+    let mut vm = Vm::new();
+    vm.locations = com.locations;
+    vm.run(com.bc_ins);
+    vm
+}
+
+/// A string value, for comparing against what a script logged.
+#[allow(dead_code)]
+pub(crate) fn string(value: &str) -> JSItem {
+    JSItem::String { value: value.to_string() }
+}
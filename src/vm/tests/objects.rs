@@ -1,18 +1,20 @@
+use crate::parser::property_map::PropertyMap;
 use std::fs;
 use crate::lexer::lexer::Lexer;
 use crate::parser::parser::Parser;
 use crate::compiler::compiler::Compiler;
 use crate::vm::vm::Vm;
 use crate::parser::symbols::JSItem;
+use crate::vm::tests::run_file;
 
 #[test]
 fn test_object_new_property() {
     let file = fs::read_to_string("js/objects/object_new_property.js");
 
     let mut lex = Lexer::new();
-    let tokens = lex.lex(file.unwrap());
+    let tokens = lex.lex(file.unwrap()).unwrap();
     let mut parser = Parser::new();
-    let mut js_items = parser.parse(tokens);
+    let mut js_items = parser.parse(tokens).unwrap();
 
     let mut com = Compiler::new();
     for item in js_items {
@@ -25,10 +27,44 @@ fn test_object_new_property() {
     assert_eq!(out, JSItem::Undefined);
 
     assert_eq!(vm.captured_output, vec![
-        vec![JSItem::Object { mutable: true, properties: hashmap!{
-            "a".to_string() =>  JSItem::ObjectReference { path: vec![String::from("0"), String::from("a"), String::from("a")] },
-            "b".to_string() => JSItem::ObjectReference { path: vec![String::from("0"), String::from("a"), String::from("b")] },
-            "d".to_string() => JSItem::ObjectReference { path: vec![String::from("0"), String::from("a"), String::from("d")] }
-        } }]
+        vec![JSItem::Object { mutable: true, properties: PropertyMap::from(vec![
+            ("a".to_string(), JSItem::ObjectReference { path: vec![String::from("0"), String::from("a"), String::from("a")] }),
+            ("b".to_string(), JSItem::ObjectReference { path: vec![String::from("0"), String::from("a"), String::from("b")] }),
+            ("d".to_string(), JSItem::ObjectReference { path: vec![String::from("0"), String::from("a"), String::from("d")] })
+        ]) }]
     ]);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_computed_method_call() {
+    let vm = run_file("js/objects/computed_method_call.js");
+    assert_eq!(vm.captured_errors, Vec::<String>::new());
+    assert_eq!(vm.captured_output, vec![
+        vec![JSItem::String { value: "o".to_string() }, JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::String { value: "o".to_string() }, JSItem::Number { value: 2. }]
+    ]);
+}
+
+
+#[test]
+fn test_object_keys() {
+    let captured = run_file("js/objects/object_keys.js").captured_output;
+
+    // integer keys ascending, then the others in the order they were added
+    let keys = vec!["1", "2", "b", "a", "c"].into_iter()
+        .map(|key| JSItem::String { value: key.to_string() })
+        .collect();
+    assert_eq!(captured, vec![vec![JSItem::Array { items: keys, properties: PropertyMap::new() }]]);
+}
+
+#[test]
+fn test_object_literal_argument_and_property() {
+    let vm = run_file("js/objects/object_values.js");
+    assert_eq!(vm.captured_errors, Vec::<String>::new());
+    assert_eq!(vm.captured_output, vec![
+        vec![JSItem::Number { value: 4. }],
+        vec![JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 5. }]
+    ]);
+}
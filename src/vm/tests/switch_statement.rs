@@ -0,0 +1,46 @@
+use crate::lexer::lexer::Lexer;
+use crate::parser::parser::Parser;
+use crate::compiler::compiler::Compiler;
+use crate::compiler::op_codes::Op;
+use crate::parser::symbols::JSItem;
+use crate::vm::tests::run_file;
+
+#[test]
+fn test_switch() {
+    assert_eq!(run_file("js/if_while_for/switch1.js").captured_output, vec![
+        vec![JSItem::String { value: "two".to_string() }],
+        vec![JSItem::String { value: "three".to_string() }],
+        vec![JSItem::String { value: "b".to_string() }],
+        vec![JSItem::String { value: "none".to_string() }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 2. }]
+    ]);
+}
+
+#[test]
+fn test_switch_bytecode() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("switch (x) { default: y; case 1: break }")).unwrap();
+    let mut parser = Parser::new();
+    let mut js_items = parser.parse(tokens).unwrap();
+
+    let mut com = Compiler::new();
+    com.compile(js_items.pop().unwrap());
+    assert_eq!(com.bc_ins, vec![
+        Op::SetupLoop,
+        Op::Load { name: "x".to_string() },
+        Op::DupTop,
+        Op::LoadNumConst { value: 1. },
+        Op::EqEqEq,
+        Op::PopJumpIfFalse { to: 8 },
+        Op::PopTop,
+        Op::JumpAbsolute { to: 12 },
+        Op::PopTop,
+        Op::JumpAbsolute { to: 10 },
+        Op::Load { name: "y".to_string() },
+        Op::PopTop,
+        Op::JumpAbsolute { to: 13 },
+        Op::PopBlock
+    ]);
+}
@@ -0,0 +1,115 @@
+use crate::lexer::lexer::Lexer;
+use crate::parser::parser::Parser;
+use crate::compiler::compiler::Compiler;
+use crate::compiler::op_codes::Op;
+use crate::parser::symbols::JSItem;
+use crate::vm::tests::run_file;
+
+#[test]
+fn test_while() {
+    assert_eq!(run_file("js/if_while_for/while1.js").captured_output, vec![
+        vec![JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 0. }]
+    ]);
+}
+
+#[test]
+fn test_do_while() {
+    assert_eq!(run_file("js/if_while_for/do_while1.js").captured_output, vec![
+        vec![JSItem::Number { value: 10. }],
+        vec![JSItem::Number { value: 4. }]
+    ]);
+}
+
+#[test]
+fn test_while_bytecode() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("while (i < 3) i = i + 1")).unwrap();
+    let mut parser = Parser::new();
+    let mut js_items = parser.parse(tokens).unwrap();
+
+    let mut com = Compiler::new();
+    com.compile(js_items.pop().unwrap());
+    assert_eq!(com.bc_ins, vec![
+        Op::SetupLoop,
+        Op::Load { name: "i".to_string() },
+        Op::LoadNumConst { value: 3. },
+        Op::Less,
+        Op::PopJumpIfFalse { to: 10 },
+        Op::Load { name: "i".to_string() },
+        Op::LoadNumConst { value: 1. },
+        Op::Add,
+        Op::Assign { name: "i".to_string() },
+        Op::JumpAbsolute { to: 1 },
+        Op::PopBlock
+    ]);
+}
+
+#[test]
+fn test_break_continue() {
+    assert_eq!(run_file("js/if_while_for/break_continue.js").captured_output, vec![
+        vec![JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 0. }, JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 1. }, JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 1. }, JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::String { value: "in".to_string() }],
+        vec![JSItem::String { value: "out".to_string() }]
+    ]);
+}
+
+#[test]
+fn test_braceless_if() {
+    assert_eq!(run_file("js/if_while_for/braceless_if.js").captured_output, vec![
+        vec![JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::String { value: "two".to_string() }],
+        vec![JSItem::String { value: "done".to_string() }]
+    ]);
+}
+
+#[test]
+fn test_loop_comparisons() {
+    assert_eq!(run_file("js/if_while_for/loop_comparisons.js").captured_output, vec![
+        vec![JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 3. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 1. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 2. }],
+        vec![JSItem::Number { value: 0. }],
+        vec![JSItem::Number { value: 1. }]
+    ]);
+}
+
+#[test]
+fn test_break_out_of_nested_loop_bytecode() {
+    let mut lex = Lexer::new();
+    let tokens = lex.lex(String::from("a: while (x) { while (y) { break a } }")).unwrap();
+    let mut parser = Parser::new();
+    let mut js_items = parser.parse(tokens).unwrap();
+
+    let mut com = Compiler::new();
+    com.compile(js_items.pop().unwrap());
+    assert_eq!(com.bc_ins, vec![
+        Op::SetupLoop,
+        Op::Load { name: "x".to_string() },
+        Op::PopJumpIfFalse { to: 11 },
+        Op::SetupLoop,
+        Op::Load { name: "y".to_string() },
+        Op::PopJumpIfFalse { to: 9 },
+        Op::PopBlock,
+        Op::JumpAbsolute { to: 11 },
+        Op::JumpAbsolute { to: 4 },
+        Op::PopBlock,
+        Op::JumpAbsolute { to: 1 },
+        Op::PopBlock
+    ]);
+}
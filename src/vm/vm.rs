@@ -1,18 +1,51 @@
 use std::collections::HashMap;
-use crate::parser::symbols::{JSItem, StdFun};
+use crate::parser::property_map::PropertyMap;
+use crate::parser::symbols::{JSItem, StdFun, Operator, to_int32, number_binop, array_index, compare_values};
 use crate::compiler::op_codes::Op;
 use crate::vm::std::create_std_objects;
-use crate::vm::scope::insert::{set_object, load_object, load_prop, locate_obj_props, add_to_located_obj};
+use crate::vm::scope::insert::{set_object, load_object, load_prop, locate_obj_props, add_to_located_obj, assign_object};
 use crate::lexer::js_token::Tok;
 use crate::vm::std::console::std_log;
+use crate::lexer::span::Span;
+
+/// An active `try`, where to go when something is thrown inside it and how deep the stack,
+/// scopes and calls were when it was entered.
+struct Handler {
+    target: usize,
+    stack_depth: usize,
+    scope_depth: usize,
+    frame_depth: usize
+}
+
+/// A call being run, where to carry on once it returns and how deep the stack, scopes and
+/// handlers were when it was made.
+struct Frame {
+    return_to: usize,
+    stack_depth: usize,
+    scope_depth: usize,
+    handler_depth: usize
+}
+
+/// What is being thrown. Engine errors only become objects once a handler takes them, so they
+/// are made in the scope of the catch.
+enum Thrown {
+    Value(JSItem),
+    Error { name: &'static str, message: String }
+}
 
 pub(crate) struct Vm {
     ip: usize, // instruction pointer
     pub(crate) stack: Vec<JSItem>, // current instruction stack
     pub(crate) objects: HashMap<String, JSItem>,
     pub(crate) scopes: Vec<HashMap<String, String>>, // objects container
+    handlers: Vec<Handler>, // tries entered and not yet left, pushed by SetupTry, innermost last
+    frames: Vec<Frame>, // calls being run, innermost last
+    halted: bool, // an exception went uncaught
+    pub(crate) locations: Vec<(usize, Span)>, // statement each run of instructions came from
     #[cfg(test)]
-    pub(crate) captured_output: Vec<Vec<JSItem>>
+    pub(crate) captured_output: Vec<Vec<JSItem>>,
+    #[cfg(test)]
+    pub(crate) captured_errors: Vec<String>
 }
 
 impl Vm {
@@ -23,26 +56,38 @@ impl Vm {
             stack: vec![],
             objects: HashMap::new(),
             scopes: vec![HashMap::new()],
+            handlers: vec![],
+            frames: vec![],
+            halted: false,
+            locations: vec![],
+            #[cfg(test)]
+            captured_output: vec![],
             #[cfg(test)]
-            captured_output: vec![]
+            captured_errors: vec![]
         };
         create_std_objects(vm)
     }
 
     pub(crate) fn run(&mut self, ops: Vec<Op>) -> JSItem {
         loop {
-            if self.ip >= ops.len() {
+            if self.halted || self.ip >= ops.len() {
                 break;
             }
             let op = ops.get(self.ip).unwrap();
+            // a function only sees what it pushed itself
+            let base = self.frames.last().map_or(0, |frame| frame.stack_depth);
+            if self.stack.len() < base + op.operands() {
+                self.throw_error("InternalError", format!("stack underflow running {:?}", op));
+                continue;
+            }
             match op {
                 Op::DeclareFunc { start, end, mutable:_, params, name } => self.declare_func(start.clone(), end.clone(), params.clone(), name.clone()),
                 Op::Return => self.return_to(),
                 Op::Add => self.add(),
-                Op::Sub => self.sub(),
-                Op::Div => self.div(),
-                Op::Mul => self.mul(),
-                Op::Less => self.less(),
+                Op::Sub => self.binary_number(Operator::Sub),
+                Op::Div => self.binary_number(Operator::Div),
+                Op::Mul => self.binary_number(Operator::Mult),
+                Op::Less => self.compare(Operator::Less),
                 Op::LoadNumConst { value } => self.load_num_const(value.clone()),
                 Op::LoadStrConst { value } => self.load_str_const(value.clone()),
                 Op::Store { name } => self.store(name.clone()),
@@ -53,22 +98,119 @@ impl Vm {
                 Op::SetupLoop => self.setup_loop(),
                 Op::PopJumpIfFalse { to } => self.pop_jump_if_false(to.clone()),
                 Op::JumpAbsolute { to } => self.ip = *to,
-                Op::PopBlock => self.pop_scope(),
+                Op::PopBlock => self.pop_block(),
                 Op::InplaceAdd => self.in_place_add(),
                 Op::LoadProp { name } => self.load_prop(name.clone()),
                 Op::CreateObj => self.create_obj(),
                 Op::StoreProp { name } => self.store_prop(name.clone()),
-                Op::Greater => self.greater(),
+                Op::Greater => self.compare(Operator::Greater),
                 Op::And => self.and(),
-                Op::EqEq => self.eqeq(),
-                Op::EqEqEq => self.eqeqeq()
+                Op::EqEq => self.equality(|a, b| a.loosely_equals(b)),
+                Op::EqEqEq => self.equality(|a, b| a.strictly_equals(b)),
+                Op::BuildString { count } => self.build_string(count.clone()),
+                Op::LoadRegExp { pattern, flags } => self.load_regexp(pattern.clone(), flags.clone()),
+                Op::LoadBool { value } => self.load_bool(*value),
+                Op::Assign { name } => self.assign(name.clone()),
+                Op::DupTop => self.dup_top(),
+                Op::SetupTry { handler } => self.setup_try(*handler),
+                Op::PopTry => self.pop_try(),
+                Op::Throw => {
+                    let value = self.get();
+                    self.throw(Thrown::Value(value))
+                }
+                Op::UnaryNot => self.unary_not(),
+                Op::UnaryNegative => self.unary_number(|value| -value),
+                Op::UnaryPositive => self.unary_number(|value| value),
+                Op::UnaryInvert => self.unary_number(|value| !to_int32(value) as f64),
+                Op::TypeOf => self.type_of(),
+                Op::TypeOfName { name } => self.type_of_name(name.clone()),
+                Op::LoadUndefined => self.load_undefined(),
+                Op::LoadNull => self.load_null(),
+                Op::JumpIfFalseOrPop { to } => self.jump_or_pop(*to, |value| !value.is_truthy()),
+                Op::JumpIfTrueOrPop { to } => self.jump_or_pop(*to, |value| value.is_truthy()),
+                Op::JumpIfNotNullishOrPop { to } => self.jump_or_pop(*to, |value| !value.is_nullish()),
+                Op::RotTwo => self.rot_two(),
+                Op::DeleteProp { name } => self.delete_prop(name.clone()),
+                Op::Mod => self.binary_number(Operator::Mod),
+                Op::Exp => self.binary_number(Operator::Exp),
+                Op::LShift => self.binary_number(Operator::LShift),
+                Op::RShift => self.binary_number(Operator::RShift),
+                Op::URShift => self.binary_number(Operator::URShift),
+                Op::BitAnd => self.binary_number(Operator::BitAnd),
+                Op::BitOr => self.binary_number(Operator::BitOr),
+                Op::BitXor => self.binary_number(Operator::BitXor),
+                Op::RotThree => self.rot_three(),
+                Op::LoadIndex => self.load_index(),
+                Op::StoreIndex => self.store_index(),
+                Op::DeleteIndex => self.delete_index(),
+                Op::DupTopTwo => self.dup_top_two(),
+                Op::RotFour => self.rot_four(),
+                Op::BuildArray { count } => self.build_array(*count),
+                Op::NotEq => self.equality(|a, b| !a.loosely_equals(b)),
+                Op::NotEqEq => self.equality(|a, b| !a.strictly_equals(b)),
+                Op::LessEqual => self.compare(Operator::LessEqual),
+                Op::GreaterEqual => self.compare(Operator::GreaterEqual),
+                Op::In => self.in_object(),
+                Op::InstanceOf => self.instance_of(),
+                Op::LoadFunc { start, end, params } => {
+                    self.stack.push(JSItem::BcFunction { start: *start, params: params.clone() });
+                    self.ip = end + 1;
+                }
+                Op::CallMethod { args } => self.call_method(*args),
             }
         }
+        if self.halted {
+            return JSItem::Undefined;
+        }
         return self.stack.pop().unwrap_or(JSItem::Undefined);
     }
 
+    fn dup_top(&mut self) {
+        let item = self.stack.last().unwrap().clone();
+        self.stack.push(item);
+        self.ip += 1;
+    }
+
+    /// Copies the two values on top of the stack, keeping their order.
+    fn dup_top_two(&mut self) {
+        let len = self.stack.len();
+        let top = self.stack[len - 2..].to_vec();
+        self.stack.extend(top);
+        self.ip += 1;
+    }
+
+    /// Swaps the two values on top of the stack.
+    fn rot_two(&mut self) {
+        let len = self.stack.len();
+        self.stack.swap(len - 1, len - 2);
+        self.ip += 1;
+    }
+
+    /// Moves the top of the stack down under the two values below it.
+    fn rot_three(&mut self) {
+        let top = self.stack.pop().unwrap();
+        let len = self.stack.len();
+        self.stack.insert(len - 2, top);
+        self.ip += 1;
+    }
+
+    /// Moves the top of the stack down under the three values below it.
+    fn rot_four(&mut self) {
+        let top = self.stack.pop().unwrap();
+        let len = self.stack.len();
+        self.stack.insert(len - 3, top);
+        self.ip += 1;
+    }
+
     fn pop_top(&mut self) {
-        let item = self.stack.pop().unwrap();
+        if let Some(item) = self.stack.pop() {
+            self.discard(item);
+        }
+        self.ip += 1;
+    }
+
+    /// Drops a value taken off the stack, a loaded variable goes back to where it lives.
+    fn discard(&mut self, item: JSItem) {
         match item {
             JSItem::Located { scope, location, object } => {
                 self.objects.insert(location.clone(), JSItem::Located {
@@ -79,9 +221,73 @@ impl Vm {
             }
             _ => {}
         }
+    }
+
+    fn setup_try(&mut self, target: usize) {
+        self.handlers.push(Handler {
+            target,
+            stack_depth: self.stack.len(),
+            scope_depth: self.scopes.len(),
+            frame_depth: self.frames.len()
+        });
         self.ip += 1;
     }
 
+    fn pop_try(&mut self) {
+        self.handlers.pop();
+        self.ip += 1;
+    }
+
+    fn throw_error(&mut self, name: &'static str, message: String) {
+        self.throw(Thrown::Error { name, message });
+    }
+
+    /// Unwinds to the innermost handler, dropping every call made, everything pushed and every
+    /// scope opened since its `try` began, and jumps to it with the thrown value on the stack.
+    /// With no handler left the exception is reported and the program stops.
+    fn throw(&mut self, thrown: Thrown) {
+        let handler = match self.handlers.pop() {
+            Some(handler) => handler,
+            None => return self.report_uncaught(thrown)
+        };
+        self.frames.truncate(handler.frame_depth);
+        self.unwind(handler.stack_depth, handler.scope_depth);
+
+        let value = match thrown {
+            Thrown::Value(value) => value,
+            Thrown::Error { name, message } => self.error_object(name, message)
+        };
+        self.stack.push(value);
+        self.ip = handler.target;
+    }
+
+    /// `{name, message}` like the built in error objects.
+    fn error_object(&mut self, name: &str, message: String) -> JSItem {
+        self.stack.push(JSItem::Object { mutable: true, properties: Default::default() });
+        self.stack.push(JSItem::String { value: name.to_string() });
+        self.set_prop("name".to_string());
+        self.stack.push(JSItem::String { value: message });
+        self.set_prop("message".to_string());
+        self.stack.pop().unwrap()
+    }
+
+    #[allow(unreachable_code)]
+    fn report_uncaught(&mut self, thrown: Thrown) {
+        self.halted = true;
+        let mut text = match thrown {
+            Thrown::Value(value) => format!("Uncaught {}", value.to_js_string()),
+            Thrown::Error { name, message } => format!("Uncaught {}: {}", name, message)
+        };
+        if let Some((_, span)) = self.locations.iter().rev().find(|(start, _)| *start <= self.ip) {
+            text = format!("{}\n    at {}", text, span);
+        }
+        #[cfg(test)]{
+            self.captured_errors.push(text);
+            return;
+        }
+        eprintln!("{}", text);
+    }
+
     fn add_to_object(&mut self, name: String, item: JSItem, reference: JSItem) {
         match item {
             JSItem::Object {mutable, mut properties } => {
@@ -102,13 +308,26 @@ impl Vm {
         }
     }
 
-    #[allow(unused_must_use)]
     fn store_prop(&mut self, name: String) {
+        self.set_prop(name);
+        self.ip += 1;
+    }
+
+    /// Sets a property of the object under the value on the stack, leaving the object.
+    #[allow(unused_must_use)]
+    fn set_prop(&mut self, name: String) {
         let value = self.get();
         let object = self.stack.pop().unwrap();
 
         match object {
             JSItem::Located { scope, location, object } => {
+                if let JSItem::Array { .. } = *object {
+                    let object = Box::new(set_element(*object, name, value));
+                    return self.stack.push(JSItem::Located { scope, location, object });
+                }
+                let mut value_path = location.split(":").map(String::from).collect::<Vec<String>>();
+                value_path.push(name.clone());
+                let value = locate_obj_props(self, value_path, value);
                 let path = add_to_located_obj(self, scope, location.clone(), value, name.to_string());
                 let reference = JSItem::ObjectReference { path };
                 self.add_to_object(name, JSItem::Located {
@@ -117,15 +336,15 @@ impl Vm {
                     object
                 }, reference);
             }
+            array @ JSItem::Array { .. } => {
+                self.stack.push(set_element(array, name, value));
+            }
+            // an object literal being built keeps its values until it is stored
             JSItem::Object { mutable, properties } => {
-                let loc = vec!["0".to_string(), name.clone()];
-                set_object(self, loc.clone(), value, true);
-                let reference = JSItem::ObjectReference { path: loc};
-                self.add_to_object(name, JSItem::Object {mutable, properties}, reference)
+                self.add_to_object(name, JSItem::Object {mutable, properties}, value)
             }
             _ => {}
         }
-        self.ip += 1;
     }
 
     fn create_obj(&mut self) {
@@ -133,13 +352,33 @@ impl Vm {
         self.ip += 1;
     }
 
+    /// Drops what was pushed and the scopes opened above the given depths.
+    fn unwind(&mut self, stack_depth: usize, scope_depth: usize) {
+        while self.stack.len() > stack_depth {
+            let item = self.stack.pop().unwrap();
+            self.discard(item);
+        }
+        while self.scopes.len() > scope_depth {
+            self.pop_scope();
+        }
+    }
+
+    /// Leaves the function being run with the value on top of the stack, along with anything
+    /// it left under it and its scopes and handlers. Outside a function it ends the program.
     fn return_to(&mut self) {
-        match self.stack.pop().unwrap() {
-            JSItem::ReturnJump { to } => {
-                self.ip = to;
+        let value = self.get();
+        let frame = match self.frames.pop() {
+            Some(frame) => frame,
+            None => {
+                self.stack.push(value);
+                self.ip = usize::MAX;
+                return;
             }
-            _ => {}
-        }
+        };
+        self.unwind(frame.stack_depth, frame.scope_depth);
+        self.handlers.truncate(frame.handler_depth);
+        self.stack.push(value);
+        self.ip = frame.return_to;
     }
 
     #[allow(unused_must_use)]
@@ -152,13 +391,17 @@ impl Vm {
         self.ip = end + 1;
     }
 
+    fn pop_block(&mut self) {
+        self.pop_scope();
+        self.ip += 1;
+    }
+
     fn pop_scope(&mut self) {
         let scope = self.scopes.pop().unwrap();
 
         for key in scope.values() {
             self.objects.remove(key);
         }
-        self.ip += 1;
     }
 
     fn in_place_add(&mut self) {
@@ -180,28 +423,55 @@ impl Vm {
         self.ip += 1;
     }
 
-    fn call(&mut self, args: i8) {
+    fn pop_arguments(&mut self, args: i8) -> Vec<JSItem> {
         let mut arguments = vec![];
         for _ in 0..args {
             arguments.push(self.get());
         }
         arguments.reverse();
+        arguments
+    }
 
+    fn call(&mut self, args: i8) {
+        let arguments = self.pop_arguments(args);
         let func = self.get();
         match func {
-            JSItem::Std { params, func } => {
-                self.call_std(params, arguments, func);
-                self.ip += 1;
-            }
+            JSItem::Std { params, func } => self.call_std(params, arguments, func),
             JSItem::BcFunction { start, params} => {
                 self.call_bcfunc(start, params, arguments)
             }
-            _ => {}
+            func => {
+                self.throw_error("TypeError", format!("{} is not a function", func.to_js_string()))
+            }
+        }
+    }
+
+    /// Calls the function under the arguments with the object under it as `this`.
+    #[allow(unused_must_use)]
+    fn call_method(&mut self, args: i8) {
+        let arguments = self.pop_arguments(args);
+        let func = self.get();
+        let this = self.get();
+        match func {
+            JSItem::Std { params, func } => self.call_std(params, arguments, func),
+            JSItem::BcFunction { start, params } => {
+                self.call_bcfunc(start, params, arguments);
+                self.store_value("this".to_string(), this);
+            }
+            func => {
+                self.throw_error("TypeError", format!("{} is not a function", func.to_js_string()))
+            }
         }
     }
 
     #[allow(unused_must_use)]
     fn call_bcfunc(&mut self, start: usize, mut params: Vec<String>, mut arguments: Vec<JSItem>) {
+        self.frames.push(Frame {
+            return_to: self.ip + 1,
+            stack_depth: self.stack.len(),
+            scope_depth: self.scopes.len(),
+            handler_depth: self.handlers.len()
+        });
         self.scopes.push(HashMap::new());
 
         params.reverse();
@@ -211,15 +481,13 @@ impl Vm {
         while !arguments.is_empty() {
             let arg = arguments.pop().unwrap();
             if let Some(param) = params.pop() {
-                set_object(self, vec![param], arg, true);
+                self.store_value(param, arg);
             } else {
-                set_object(self, vec![format!("{}{}", "extra.".to_string(), extra.to_string())], arg, true);
+                self.store_value(format!("{}{}", "extra.".to_string(), extra.to_string()), arg);
                 extra += 1;
             }
         }
 
-        self.stack.push(JSItem::ReturnJump {to: self.ip + 1});
-
         self.ip = start;
     }
 
@@ -249,32 +517,33 @@ impl Vm {
         return (names, items);
     }
 
+    #[allow(unreachable_code)]
+    fn log(&mut self, items: Vec<JSItem>) {
+        #[cfg(test)]{
+            self.captured_output.push(items);
+            return
+        }
+        std_log(items);
+    }
+
+    /// Runs a built in function and pushes what it returns, or throws what it throws.
     fn call_std(&mut self, params: Vec<Tok>, arguments: Vec<JSItem>, func: StdFun) {
         //create a new scope
         self.scopes.push(HashMap::new());
 
-        match func {
-            #[allow(unreachable_code)]
+        let out = match func {
             StdFun::ConsoleLog => {
-                let mut log = || {
-                    let args = self.make_params(params, arguments);
-                    #[cfg(test)]{
-                        self.captured_output.push(args.1 );
-                        self.scopes.pop();
-                        return
-                    }
-                    std_log(args.1);
-                    self.scopes.pop();
-                    return
-                };
-                log();
+                let args = self.make_params(params, arguments);
+                self.log(args.1);
+                Ok(JSItem::Undefined)
             }
-            _ => {}
-            // StdFun::ObjectKeys => {
-            //     self.function_scope.pop();
-            //     self.remove_current_scope();
-            //     return Err(());
-            // }
+            StdFun::ObjectKeys => {
+                arguments.first().unwrap_or(&JSItem::Undefined).own_keys().ok_or(Thrown::Error {
+                    name: "TypeError",
+                    message: "Cannot convert undefined or null to object".to_string()
+                })
+            }
+            _ => Ok(JSItem::Undefined)
             // StdFun::FunctionApply => {
             //     let args = self.make_params(params, arguments);
             //     let out = std_fun_apply(self, this_path, args);
@@ -299,27 +568,50 @@ impl Vm {
             //     }
             //     return Err(())
             // }
+        };
+        self.scopes.pop();
+
+        match out {
+            Ok(value) => {
+                self.stack.push(value);
+                self.ip += 1;
+            }
+            Err(thrown) => self.throw(thrown)
         }
     }
 
     #[allow(unused_must_use)]
     fn load_prop(&mut self, name: String) {
+        match self.stack.last() {
+            Some(JSItem::Undefined) | Some(JSItem::Null) | None => {
+                let object = self.stack.pop().unwrap_or(JSItem::Undefined);
+                let message = format!("Cannot read properties of {} (reading '{}')", object.to_js_string(), name);
+                return self.throw_error("TypeError", message);
+            }
+            _ => {}
+        }
         load_prop(self,name);
         self.ip += 1;
     }
 
     fn pop_jump_if_false(&mut self, to: usize) {
-        let value = match self.stack.pop().unwrap() {
-            JSItem::Bool {value} => value,
-            _ => false
-        };
-        if !value {
+        if !self.get().is_truthy() {
             self.ip = to;
         } else {
             self.ip += 1;
         }
     }
 
+    /// Jumps leaving the top of the stack where it is when `test` holds for it, otherwise
+    /// drops it and carries on. `&&`, `||` and `??` give back whichever operand decided them.
+    fn jump_or_pop(&mut self, to: usize, test: fn(&JSItem) -> bool) {
+        if test(self.stack.last().unwrap_or(&JSItem::Undefined)) {
+            self.ip = to;
+        } else {
+            self.pop_top();
+        }
+    }
+
     fn get(&mut self) -> JSItem {
         let item = self.stack.pop().unwrap();
         return match item {
@@ -357,18 +649,13 @@ impl Vm {
                         });
                         JSItem::Object { mutable, properties }
                     }
-                    JSItem::BcFunction { start, params } => {
-                        self.objects.insert(location.clone(), JSItem::BcFunction {
-                            start: start.clone(),
-                            params: params.clone()
+                    object => {
+                        self.objects.insert(location.clone(), JSItem::Located {
+                            scope,
+                            location,
+                            object: Box::from(object.clone())
                         });
-                        JSItem::BcFunction {
-                            start,
-                            params
-                        }
-                    }
-                    _ => {
-                        JSItem::Null
+                        object
                     }
                 }
             }
@@ -378,19 +665,49 @@ impl Vm {
         }
     }
 
-    fn less(&mut self) {
-        let v2 = match self.get() {
-            JSItem::Number {value} => value,
-            _ => 0.
-        };
-        let v1 = match self.get() {
-            JSItem::Number {value} => value,
-            _ => 0.
-        };
-        self.stack.push(JSItem::Bool {value: v1 < v2});
+    /// Replaces the two values on top of the stack with `<`, `>`, `<=` or `>=` of them.
+    fn compare(&mut self, op: Operator) {
+        let v2 = self.get();
+        let v1 = self.get();
+        self.stack.push(JSItem::Bool {value: compare_values(&op, &v1, &v2)});
         self.ip += 1;
     }
 
+    fn equality(&mut self, test: fn(&JSItem, &JSItem) -> bool) {
+        let v2 = self.get();
+        let v1 = self.get();
+        self.stack.push(JSItem::Bool {value: test(&v1, &v2)});
+        self.ip += 1;
+    }
+
+    /// `key in object`, throws when the right side isn't an object.
+    fn in_object(&mut self) {
+        let object = self.get();
+        let key = self.get().to_property_key();
+        match object.has_property(&key) {
+            Some(value) => {
+                self.stack.push(JSItem::Bool {value});
+                self.ip += 1;
+            }
+            None => {
+                let message = format!("Cannot use 'in' operator to search for '{}' in {}", key, object.to_js_string());
+                self.throw_error("TypeError", message)
+            }
+        }
+    }
+
+    fn instance_of(&mut self) {
+        let target = self.get();
+        let value = self.get();
+        match value.instance_of(&target) {
+            Some(value) => {
+                self.stack.push(JSItem::Bool {value});
+                self.ip += 1;
+            }
+            None => self.throw_error("TypeError", "Right-hand side of 'instanceof' is not callable".to_string())
+        }
+    }
+
     fn and(&mut self) {
         let v2 = match self.get() {
             JSItem::Bool {value} => value,
@@ -404,202 +721,196 @@ impl Vm {
         self.ip += 1;
     }
 
-    fn eqeq(&mut self){
-        let v2 = self.get();
-        let v1 = self.get();
-        let eq = match v1 {
-            JSItem::String {value: value_1} => {
-                match v2 {
-                    JSItem::String {value: value_2} => {
-                        value_1 == value_2
-                    }
-                    JSItem::Bool {value: value_2} => {
-                        if value_2 {
-                            value_1 == "1".to_string()
-                        } else {
-                            if value_1.trim() == "" || value_1.trim() == "0"{
-                                true
-                            } else {
-                                false
-                            }
-                        }
-                    }
-                    JSItem::Number {value: value_2} => {
-                        if let Ok(value_1_parsed) = value_1.parse::<f64>() {
-                            value_1_parsed == value_2
-                        } else if let Ok(value_1_parsed) = value_1.parse::<i64>() {
-                            value_1_parsed as f64 == value_2
-                        } else {
-                            false
-                        }
-                    }
-                    _ => {
-                        panic!("Runtime Error")
-                    }
-                }
-            }
-            JSItem::Number {value: value_1} => {
-                match v2 {
-                    JSItem::String {value: value_2} => {
-                        if let Ok(value_2_parsed) = value_2.parse::<f64>() {
-                            value_2_parsed == value_1
-                        } else if let Ok(value_2_parsed) = value_2.parse::<i64>() {
-                            value_2_parsed as f64 == value_1
-                        } else {
-                            false
-                        }
-                    }
-                    JSItem::Bool {value: value_2} => {
-                        if value_2 {
-                            value_1 == 1.
-                        } else {
-                            value_1 == 0.
-                        }
-                    }
-                    JSItem::Number {value: value_2} => {
-                        value_1 == value_2
-                    }
-                    _ => {
-                        panic!("Runtime Error")
-                    }
-                }
-            }
-            JSItem::Bool {value: value_1} => {
-                match v2 {
-                    JSItem::String {value: value_2} => {
-                        if value_1{
-                            value_2 == "1".to_string()
-                        } else {
-                            if value_2.trim() == "" || value_2.trim() == "0"{
-                                true
-                            } else {
-                                false
-                            }
-                        }
-                    }
-                    JSItem::Bool {value: value_2} => {
-                        value_1 == value_2
-                    }
-                    JSItem::Number {value: value_2} => {
-                        if value_1 {
-                            value_2 == 1.
-                        } else {
-                            value_2 == 0.
-                        }
-                    }
-                    _ => {
-                        panic!("Runtime Error")
-                    }
-                }
-            }
-            _ => {
-                panic!("Runtime Error")
-            }
-        };
-        self.stack.push(JSItem::Bool {value: eq});
+
+
+
+    #[allow(unused_must_use)]
+    fn load(&mut self, name: String) {
+        if let Err(_) = load_object(self, vec![name.clone()]) {
+            return self.throw_error("ReferenceError", format!("{} is not defined", name));
+        }
         self.ip += 1;
     }
 
-    fn eqeqeq(&mut self) {
-        let v2 = self.get();
-        let v1 = self.get();
-        self.stack.push(JSItem::Bool {value: v2.eq(&v1)});
+    fn store(&mut self, name: String) {
+        let item = self.get();
+        self.store_value(name, item);
         self.ip += 1;
     }
 
-    fn greater(&mut self) {
-        let v2 = match self.get() {
-            JSItem::Number {value} => value,
-            _ => 0.
-        };
-        let v1 = match self.get() {
-            JSItem::Number {value} => value,
-            _ => 0.
+    /// Declares `name` in the innermost scope, an object literal's values are stored with it.
+    #[allow(unused_must_use)]
+    fn store_value(&mut self, name: String, item: JSItem) {
+        let path = vec![(self.scopes.len() - 1).to_string(), name.clone()];
+        let item = locate_obj_props(self, path, item);
+        set_object(self, vec![name], item, true);
+    }
+
+    /// `name = value` without a declaration, writes to the scope `name` was declared in.
+    #[allow(unused_must_use)]
+    fn assign(&mut self, name: String) {
+        if let Some(JSItem::Object { .. }) = self.stack.last() {
+            return self.store(name);
+        }
+        let item = self.get();
+        if !assign_object(self, vec![name.clone()], item.clone()) {
+            set_object(self, vec![name], item, true);
+        }
+        self.ip += 1;
+    }
+
+    fn setup_loop(&mut self) {
+        self.scopes.push(HashMap::new());
+        self.ip += 1;
+    }
+
+    /// `+`, joins the operands as strings when either one is a string once objects are turned
+    /// into primitives, otherwise adds them as numbers.
+    fn add(&mut self) {
+        let v2 = self.get().to_primitive();
+        let v1 = self.get().to_primitive();
+        let value = match (&v1, &v2) {
+            (JSItem::String { .. }, _) | (_, JSItem::String { .. }) => {
+                JSItem::String { value: v1.to_js_string() + &v2.to_js_string() }
+            }
+            _ => JSItem::Number { value: v1.to_number() + v2.to_number() }
         };
-        self.stack.push(JSItem::Bool {value: v1 > v2});
+        self.stack.push(value);
         self.ip += 1;
     }
 
-    #[allow(unused_must_use)]
-    fn load(&mut self, name: String) {
-        load_object(self, vec![name]);
+    /// Replaces the two values on top of the stack with `op` applied to them as numbers.
+    fn binary_number(&mut self, op: Operator) {
+        let v2 = self.get().to_number();
+        let v1 = self.get().to_number();
+        self.stack.push(JSItem::Number { value: number_binop(&op, v1, v2) });
         self.ip += 1;
     }
 
-    #[allow(unused_must_use)]
-    fn store(&mut self, name: String) {
-        let mut is_new_object = false;
-        if let JSItem::Object { mutable: _, properties: _ } = self.stack.get(self.stack.len() - 1).unwrap() {
-            is_new_object = true;
+    /// `object[key]`, the key is turned into a property name and read like `LoadProp`.
+    fn load_index(&mut self) {
+        let key = self.get().to_property_key();
+        self.load_prop(key);
+    }
+
+    /// `object[key] = value` with all three on the stack, leaves the value. The key isn't part
+    /// of the instruction so, unlike `StoreProp`, nothing is left to load the property back from.
+    fn store_index(&mut self) {
+        let value = self.get();
+        let key = self.get().to_property_key();
+        self.stack.push(value.clone());
+        self.set_prop(key);
+        if let Some(object) = self.stack.pop() {
+            self.discard(object);
         }
+        self.stack.push(value);
+        self.ip += 1;
+    }
 
-        let item;
-        if is_new_object {
-            let tmp = self.get();
-            item = locate_obj_props(self, name.clone(), tmp)
-        } else {
-            item = self.get();
+    fn delete_index(&mut self) {
+        let key = self.get().to_property_key();
+        self.delete_prop(key);
+    }
+
+    fn build_array(&mut self, count: usize) {
+        let mut items = vec![];
+        for _ in 0..count {
+            items.push(self.get());
         }
+        items.reverse();
+        self.stack.push(JSItem::Array { items, properties: PropertyMap::new() });
+        self.ip += 1;
+    }
 
+    fn build_string(&mut self, count: usize) {
+        let mut parts = vec![];
+        for _ in 0..count {
+            parts.push(self.get().to_js_string());
+        }
+        parts.reverse();
+        self.stack.push(JSItem::String { value: parts.concat() });
+        self.ip += 1;
+    }
 
-        set_object(self, vec![name], item, true);
+    fn load_regexp(&mut self, pattern: String, flags: String) {
+        self.stack.push(JSItem::RegExp { pattern, flags });
         self.ip += 1;
     }
 
-    fn setup_loop(&mut self) {
-        self.scopes.push(HashMap::new());
+    fn unary_not(&mut self) {
+        let value = self.get();
+        self.stack.push(JSItem::Bool { value: !value.is_truthy() });
         self.ip += 1;
     }
 
-    fn mul(&mut self) {
-        let v2 = match self.get() {
-            JSItem::Number {value} => value,
-            _ => 0.
-        };
-        let v1 = match self.get() {
-            JSItem::Number {value} => value,
-            _ => 0.
-        };
-        self.stack.push(JSItem::Number {value: v1 * v2});
+    /// Replaces the top of the stack with `op` applied to it as a number.
+    fn unary_number(&mut self, op: fn(f64) -> f64) {
+        let value = self.get().to_number();
+        self.stack.push(JSItem::Number { value: op(value) });
         self.ip += 1;
     }
 
-    fn div(&mut self) {
-        let v2 = match self.get() {
-            JSItem::Number {value} => value,
-            _ => 0.
-        };
-        let v1 = match self.get() {
-            JSItem::Number {value} => value,
-            _ => 0.
-        };
-        self.stack.push(JSItem::Number { value: v1 / v2 });
+    fn type_of(&mut self) {
+        let value = self.get();
+        self.stack.push(JSItem::String { value: value.type_of().to_string() });
         self.ip += 1;
     }
 
-    fn sub(&mut self) {
-        let v2 = match self.get() {
-            JSItem::Number {value} => value,
-            _ => 0.
-        };
-        let v1 = match self.get() {
-            JSItem::Number {value} => value,
-            _ => 0.
-        };
-        self.stack.push(JSItem::Number {value: v1 - v2});
+    /// `typeof name`, a name that was never declared is "undefined" instead of an error.
+    fn type_of_name(&mut self, name: String) {
+        if let Ok(_) = load_object(self, vec![name]) {
+            return self.type_of();
+        }
+        self.stack.push(JSItem::String { value: "undefined".to_string() });
         self.ip += 1;
     }
 
-    fn add(&mut self) {
-        let v2 = match self.get() {
-            JSItem::Number {value} => value,
-            _ => 0.
-        };
-        let v1 = match self.get() {
-            JSItem::Number {value} => value,
-            _ => 0.
-        };
-        self.stack.push(JSItem::Number {value: v1 + v2});
+    fn load_undefined(&mut self) {
+        self.stack.push(JSItem::Undefined);
+        self.ip += 1;
+    }
+
+    fn load_null(&mut self) {
+        self.stack.push(JSItem::Null);
+        self.ip += 1;
+    }
+
+    /// Takes `name` off the object on top of the stack, along with the value it pointed to.
+    fn delete_prop(&mut self, name: String) {
+        match self.stack.pop() {
+            Some(JSItem::Located { scope, location, object }) => {
+                let object = match *object {
+                    JSItem::Object { mutable, mut properties } => {
+                        if let Some(JSItem::ObjectReference { path }) = properties.remove(&name) {
+                            self.objects.remove(&path.join(":"));
+                        }
+                        JSItem::Object { mutable, properties }
+                    }
+                    // leaves a hole, the length stays the same
+                    JSItem::Array { mut items, mut properties } => {
+                        match array_index(&name) {
+                            Some(index) if index < items.len() => items[index] = JSItem::Undefined,
+                            _ => {
+                                properties.remove(&name);
+                            }
+                        }
+                        JSItem::Array { items, properties }
+                    }
+                    object => object
+                };
+                self.discard(JSItem::Located { scope, location, object: Box::new(object) });
+            }
+            Some(JSItem::Undefined) | Some(JSItem::Null) | None => {
+                return self.throw_error("TypeError", "Cannot convert undefined or null to object".to_string());
+            }
+            _ => {}
+        }
+        self.stack.push(JSItem::Bool { value: true });
+        self.ip += 1;
+    }
+
+    fn load_bool(&mut self, value: bool) {
+        self.stack.push(JSItem::Bool { value });
         self.ip += 1;
     }
 
@@ -612,4 +923,26 @@ impl Vm {
         self.stack.push(JSItem::Number { value });
         self.ip += 1;
     }
-}
\ No newline at end of file
+}
+
+/// `array` with the element or property `name` set to `value`, writing past the end fills the
+/// gap with undefined.
+fn set_element(array: JSItem, name: String, value: JSItem) -> JSItem {
+    match array {
+        JSItem::Array { mut items, mut properties } => {
+            match array_index(&name) {
+                Some(index) => {
+                    if index >= items.len() {
+                        items.resize(index + 1, JSItem::Undefined);
+                    }
+                    items[index] = value;
+                }
+                None => {
+                    properties.insert(name, value);
+                }
+            }
+            JSItem::Array { items, properties }
+        }
+        item => item
+    }
+}